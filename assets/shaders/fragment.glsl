@@ -7,7 +7,7 @@ uniform sampler3D      u_Lightmap;     // The 3D RGB + SkyAccess texture for thi
 // --- Scene Globals (Updated once per frame) ---
 uniform vec3  u_EnvSkyColor;      // Current tint of the sunlight (Time Manager)
 uniform float u_EnvSkyIntensity;  // Current brightness of the sun (Time Manager)
-uniform float u_EnvAmbient;       // Base minimum light level (so nights aren't pitch black)
+uniform vec3  u_EnvAmbientColor;  // Base minimum light level (so nights aren't pitch black)
 
 // --- Inputs from Vertex Shader ---
 in vec2 vTexCoords;
@@ -44,7 +44,7 @@ void main() {
 
     // 5. Apply Ambient Floor
     // Ensures we don't multiply the texture by [0, 0, 0]
-    vec3 finalLight = max(combinedLight, vec3(u_EnvAmbient));
+    vec3 finalLight = max(combinedLight, u_EnvAmbientColor);
 
     // 6. Final Pixel Output
     fragColor = vec4(texColor.rgb * finalLight, texColor.a);
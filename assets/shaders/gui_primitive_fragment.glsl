@@ -0,0 +1,11 @@
+#version 450 core
+
+uniform sampler2D uTexture;
+uniform vec4 uColor;
+
+in vec2 vUV;
+out vec4 fragColor;
+
+void main() {
+    fragColor = texture(uTexture, vUV) * uColor;
+}
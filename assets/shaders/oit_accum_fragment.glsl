@@ -0,0 +1,45 @@
+#version 450 core
+
+// Weighted blended OIT accumulation (McGuire & Bavoil 2013).
+// Pairs with vertex.glsl; lighting matches fragment.glsl.
+
+uniform sampler2DArray u_TextureAtlas;
+uniform sampler3D      u_Lightmap;
+
+uniform vec3  u_EnvSkyColor;
+uniform float u_EnvSkyIntensity;
+uniform vec3  u_EnvAmbientColor;
+
+in vec2 vTexCoords;
+flat in float vLayer;
+in vec3 vLightmapUV;
+
+// Blended with (ONE, ONE): sum of premultiplied color * weight, and alpha * weight
+layout (location = 0) out vec4 accum;
+// Blended with (ZERO, ONE_MINUS_SRC_COLOR): product of (1 - alpha)
+layout (location = 1) out float revealage;
+
+// Keep in sync with render::oit::oit_weight
+float oitWeight(float viewDepth, float alpha) {
+    float z = abs(viewDepth);
+    return alpha * clamp(10.0 / (1e-5 + pow(z / 5.0, 2.0) + pow(z / 200.0, 6.0)), 1e-2, 3e3);
+}
+
+void main() {
+    vec4 texColor = texture(u_TextureAtlas, vec3(vTexCoords, vLayer));
+    if (texColor.a < 0.1) {
+        discard;
+    }
+
+    vec4 lightSample = texture(u_Lightmap, vLightmapUV);
+    vec3 dynamicSunlight = lightSample.a * u_EnvSkyColor * u_EnvSkyIntensity;
+    vec3 finalLight = max(max(lightSample.rgb, dynamicSunlight), u_EnvAmbientColor);
+
+    vec3 color = texColor.rgb * finalLight;
+    float alpha = texColor.a;
+    // gl_FragCoord.w is 1 / clip.w, i.e. 1 / view depth for a perspective projection
+    float w = oitWeight(1.0 / gl_FragCoord.w, alpha);
+
+    accum = vec4(color * alpha, alpha) * w;
+    revealage = alpha;
+}
@@ -0,0 +1,24 @@
+#version 450 core
+
+// Resolves the OIT accumulation buffers over the opaque scene.
+// Blended with (SRC_ALPHA, ONE_MINUS_SRC_ALPHA) into the default framebuffer.
+
+uniform sampler2D u_Accum;
+uniform sampler2D u_Revealage;
+
+out vec4 fragColor;
+
+void main() {
+    ivec2 coord = ivec2(gl_FragCoord.xy);
+    float revealage = texelFetch(u_Revealage, coord, 0).r;
+
+    // Nothing transparent covered this pixel
+    if (revealage >= 1.0) {
+        discard;
+    }
+
+    vec4 accum = texelFetch(u_Accum, coord, 0);
+    vec3 averageColor = accum.rgb / max(accum.a, 1e-5);
+
+    fragColor = vec4(averageColor, 1.0 - revealage);
+}
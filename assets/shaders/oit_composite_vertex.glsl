@@ -0,0 +1,7 @@
+#version 450 core
+
+// Full-screen triangle generated from gl_VertexID; draw 3 vertices with no buffers bound.
+void main() {
+    vec2 pos = vec2(float((gl_VertexID << 1) & 2), float(gl_VertexID & 2));
+    gl_Position = vec4(pos * 2.0 - 1.0, 0.0, 1.0);
+}
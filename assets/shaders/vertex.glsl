@@ -7,6 +7,9 @@ uniform mat4 model;
 uniform mat4 view;
 uniform mat4 projection;
 uniform vec3 uUVOffset;
+// Slot of this chunk inside a shared lightmap atlas (defaults cover a per-chunk texture)
+uniform vec3 u_LightmapOffset = vec3(0.0);
+uniform vec3 u_LightmapScale = vec3(1.0);
 
 out vec2 vTexCoords;
 flat out float vLayer;
@@ -36,7 +39,8 @@ void main() {
     // Move the sampling point 0.1 units away from the face so we
     // definitely sample the light in the AIR, not inside the block.
     vec3 normal = getNormal(face);
-    vLightmapUV = (vec3(float(x), float(y), float(z)) + 0.5 + (normal * 0.1)) / 32.0;
+    vec3 localLightmapUV = (vec3(float(x), float(y), float(z)) + 0.5 + (normal * 0.1)) / 32.0;
+    vLightmapUV = u_LightmapOffset + localLightmapUV * u_LightmapScale;
 
     gl_Position = projection * view * model * vec4(float(x), float(y), float(z), 1.0);
 
@@ -8,7 +8,21 @@ pub enum Projection {
     Orthographic { left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32 },
 }
 
+/// A snapshot of camera placement used as an interpolation target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraState {
+    /// World-space position.
+    pub position: glm::Vec3,
+    /// Yaw in degrees.
+    pub yaw: f32,
+    /// Pitch in degrees.
+    pub pitch: f32,
+}
+
 /// First-person camera with configurable projection.
+///
+/// Orientation is stored as a quaternion; yaw/pitch/roll (degrees) are kept alongside for
+/// convenience. Yaw 0 looks down +X and yaw -90 down -Z; positive roll banks `up` toward the right.
 pub struct Camera {
     /// World-space position.
     pub position: glm::Vec3,
@@ -18,24 +32,32 @@ pub struct Camera {
     pub up: glm::Vec3,
     yaw: f32,
     pitch: f32,
+    roll: f32,
+    pitch_limits: (f32, f32),
+    orientation: glm::Quat,
     projection: Projection,
 }
 
 impl Camera {
     /// Creates a camera at `position` with default perspective projection (45deg FOV, 0.1-100 clip range).
     pub fn new(position: glm::Vec3) -> Self {
-        Self {
+        let mut camera = Self {
             position,
             front: glm::vec3(0.0, 0.0, -1.0),
             up: glm::vec3(0.0, 1.0, 0.0),
             yaw: -90.0,
             pitch: 0.0,
+            roll: 0.0,
+            pitch_limits: (-89.0, 89.0),
+            orientation: glm::quat_identity(),
             projection: Projection::Perspective {
                 fov: 45.0f32.to_radians(),
                 near: 0.1,
                 far: 100.0,
             },
-        }
+        };
+        camera.update_front_from_angles();
+        camera
     }
 
     /// Replaces the current projection mode.
@@ -66,18 +88,126 @@ impl Camera {
         }
     }
 
+    /// Returns the normalized right vector (`front` x `up`).
+    pub fn right(&self) -> glm::Vec3 {
+        glm::normalize(&glm::cross(&self.front, &self.up))
+    }
+
+    /// Returns `front` flattened onto the XZ plane and normalized, for ground movement.
+    /// Stays stable when looking straight up or down by folding in the up vector,
+    /// which points horizontally there.
+    pub fn horizontal_front(&self) -> glm::Vec3 {
+        let side = if self.front.y > 0.0 { -1.0 } else { 1.0 };
+        let flat = glm::vec3(
+            self.front.x + side * self.up.x,
+            0.0,
+            self.front.z + side * self.up.z,
+        );
+        if glm::length(&flat) > 1e-6 {
+            glm::normalize(&flat)
+        } else {
+            glm::vec3(1.0, 0.0, 0.0)
+        }
+    }
+
+    /// Builds a picking ray through the screen pixel (`screen_x`, `screen_y`), origin top-left.
+    /// Returns (origin, normalized direction). For perspective the origin is the camera position;
+    /// for orthographic it is the pixel's point on the near plane, since rays are parallel.
+    pub fn screen_ray(&self, screen_x: f32, screen_y: f32, width: f32, height: f32, aspect: f32) -> (glm::Vec3, glm::Vec3) {
+        let ndc_x = 2.0 * screen_x / width - 1.0;
+        let ndc_y = 1.0 - 2.0 * screen_y / height;
+        let inverse = glm::inverse(&(self.projection_matrix(aspect) * self.view_matrix()));
+
+        let unproject = |z: f32| {
+            let p = inverse * glm::vec4(ndc_x, ndc_y, z, 1.0);
+            p.xyz() / p.w
+        };
+        let near = unproject(-1.0);
+        let far = unproject(1.0);
+        let direction = glm::normalize(&(far - near));
+
+        match self.projection {
+            Projection::Perspective { .. } => (self.position, direction),
+            Projection::Orthographic { .. } => (near, direction),
+        }
+    }
+
+    /// Returns the current position, yaw, and pitch.
+    pub fn state(&self) -> CameraState {
+        CameraState { position: self.position, yaw: self.yaw, pitch: self.pitch }
+    }
+
+    /// Moves a fraction `t` (0..=1) of the way toward `target`: position linearly, angles along
+    /// the shortest arc (yaw 350 to 10 passes through 0). Roll is kept.
+    pub fn lerp_to(&mut self, target: &CameraState, t: f32) {
+        let shortest = |from: f32, to: f32| from + ((to - from + 180.0).rem_euclid(360.0) - 180.0) * t;
+
+        self.position = glm::lerp(&self.position, &target.position, t);
+        self.yaw = shortest(self.yaw, target.yaw);
+        self.pitch = shortest(self.pitch, target.pitch).clamp(self.pitch_limits.0, self.pitch_limits.1);
+        self.update_front_from_angles();
+    }
+
     /// Translates the camera by an offset in world space.
     pub fn translate(&mut self, offset: glm::Vec3) {
         self.position += offset;
     }
 
-    /// Sets yaw and pitch (degrees) and recalculates the front vector.
+    /// Sets yaw and pitch (degrees) and recalculates the front and up vectors, keeping the
+    /// current roll.
     pub fn set_yaw_and_pitch(&mut self, yaw: f32, pitch: f32) {
+        self.set_yaw_pitch_roll(yaw, pitch, self.roll)
+    }
+
+    /// Sets yaw, pitch, and roll (degrees) and recalculates the front and up vectors.
+    /// Pitch is clamped to the pitch limits.
+    pub fn set_yaw_pitch_roll(&mut self, yaw: f32, pitch: f32, roll: f32) {
         self.yaw = yaw;
-        self.pitch = pitch;
+        self.pitch = pitch.clamp(self.pitch_limits.0, self.pitch_limits.1);
+        self.roll = roll;
         self.update_front_from_angles()
     }
 
+    /// Sets the allowed pitch range in degrees (default -89 to 89) and re-clamps the current pitch.
+    pub fn set_pitch_limits(&mut self, min_deg: f32, max_deg: f32) {
+        self.pitch_limits = (min_deg.min(max_deg), max_deg.max(min_deg));
+        self.set_yaw_pitch_roll(self.yaw, self.pitch, self.roll);
+    }
+
+    /// Returns the (min, max) pitch limits in degrees.
+    pub fn pitch_limits(&self) -> (f32, f32) {
+        self.pitch_limits
+    }
+
+    /// Free-look rotation (degrees) about the camera's own right, up, and forward axes.
+    /// Composes onto the quaternion directly, so there is no gimbal lock at vertical pitch.
+    /// Pitch limits do not apply here.
+    pub fn rotate_local(&mut self, pitch: f32, yaw: f32, roll: f32) {
+        let local = glm::quat_angle_axis(-yaw.to_radians(), &glm::vec3(0.0, 1.0, 0.0))
+            * glm::quat_angle_axis(pitch.to_radians(), &glm::vec3(0.0, 0.0, 1.0))
+            * glm::quat_angle_axis(roll.to_radians(), &glm::vec3(1.0, 0.0, 0.0));
+        self.set_orientation(self.orientation * local);
+    }
+
+    /// Returns the orientation quaternion (rotates +X forward / +Y up into camera space).
+    pub fn orientation(&self) -> glm::Quat {
+        self.orientation
+    }
+
+    /// Sets the orientation directly and re-derives front, up, and the yaw/pitch/roll angles.
+    pub fn set_orientation(&mut self, orientation: glm::Quat) {
+        self.orientation = glm::quat_normalize(&orientation);
+        self.apply_orientation();
+
+        self.pitch = self.front.y.clamp(-1.0, 1.0).asin().to_degrees();
+        self.yaw = self.front.z.atan2(self.front.x).to_degrees();
+        // Roll is the angle from the unbanked up to the actual up, around front
+        let unbanked = glm::quat_rotate_vec3(&Self::yaw_pitch_quat(self.yaw, self.pitch), &glm::vec3(0.0, 1.0, 0.0));
+        self.roll = glm::dot(&glm::cross(&unbanked, &self.up), &self.front)
+            .atan2(glm::dot(&unbanked, &self.up))
+            .to_degrees();
+    }
+
     /// Returns the current yaw in degrees.
     pub fn yaw(&self) -> f32 {
         self.yaw
@@ -88,18 +218,28 @@ impl Camera {
         self.pitch
     }
 
+    /// Returns the current roll in degrees.
+    pub fn roll(&self) -> f32 {
+        self.roll
+    }
+
     fn update_front_from_angles(&mut self) {
-        let yaw_r = self.yaw.to_radians();
-        let pitch_r = self.pitch.to_radians();
+        let roll = glm::quat_angle_axis(self.roll.to_radians(), &glm::vec3(1.0, 0.0, 0.0));
+        self.orientation = Self::yaw_pitch_quat(self.yaw, self.pitch) * roll;
+        self.apply_orientation();
+    }
 
-        let direction = glm::vec3(
-            yaw_r.cos() * pitch_r.cos(),
-            pitch_r.sin(),
-            yaw_r.sin() * pitch_r.cos(),
-        );
+    /// Yaw about world Y, then pitch about the local Z axis (+X is forward at zero angles).
+    fn yaw_pitch_quat(yaw: f32, pitch: f32) -> glm::Quat {
+        glm::quat_angle_axis(-yaw.to_radians(), &glm::vec3(0.0, 1.0, 0.0))
+            * glm::quat_angle_axis(pitch.to_radians(), &glm::vec3(0.0, 0.0, 1.0))
+    }
 
-        if glm::length(&direction) > 0.0 {
-            self.front = glm::normalize(&direction);
-        }
+    fn apply_orientation(&mut self) {
+        self.front = glm::normalize(&glm::quat_rotate_vec3(&self.orientation, &glm::vec3(1.0, 0.0, 0.0)));
+        self.up = glm::normalize(&glm::quat_rotate_vec3(&self.orientation, &glm::vec3(0.0, 1.0, 0.0)));
     }
 }
+
+#[cfg(test)]
+mod tests;
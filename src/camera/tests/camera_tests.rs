@@ -0,0 +1,205 @@
+use nalgebra_glm as glm;
+use crate::camera::{Camera, CameraState, Projection};
+
+fn approx_vec(a: glm::Vec3, b: glm::Vec3) -> bool {
+    (a - b).abs().max() < 1e-4
+}
+
+fn camera() -> Camera {
+    Camera::new(glm::vec3(0.0, 0.0, 0.0))
+}
+
+#[test]
+fn test_default_orientation_looks_down_negative_z() {
+    let camera = camera();
+    assert!(approx_vec(camera.front, glm::vec3(0.0, 0.0, -1.0)));
+    assert!(approx_vec(camera.up, glm::vec3(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn test_yaw_and_pitch_match_spherical_front() {
+    let mut camera = camera();
+    camera.set_yaw_and_pitch(30.0, 20.0);
+
+    let (y, p) = (30.0f32.to_radians(), 20.0f32.to_radians());
+    let expected = glm::vec3(y.cos() * p.cos(), p.sin(), y.sin() * p.cos());
+    assert!(approx_vec(camera.front, expected));
+    assert!(glm::dot(&camera.front, &camera.up).abs() < 1e-5);
+}
+
+#[test]
+fn test_roll_banks_up_vector() {
+    let mut camera = camera();
+    camera.set_yaw_pitch_roll(0.0, 0.0, 90.0);
+
+    // Looking down +X, rolled 90 degrees: up points to the right (+Z)
+    assert!(approx_vec(camera.front, glm::vec3(1.0, 0.0, 0.0)));
+    assert!(approx_vec(camera.up, glm::vec3(0.0, 0.0, 1.0)));
+    assert!((camera.roll() - 90.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_set_yaw_and_pitch_keeps_roll() {
+    let mut camera = camera();
+    camera.set_yaw_pitch_roll(0.0, 0.0, 90.0);
+    camera.set_yaw_and_pitch(90.0, 0.0);
+
+    // Now looking down +Z, still rolled 90 degrees: up points to -X
+    assert!((camera.roll() - 90.0).abs() < 1e-3);
+    assert!(approx_vec(camera.front, glm::vec3(0.0, 0.0, 1.0)));
+    assert!(approx_vec(camera.up, glm::vec3(-1.0, 0.0, 0.0)));
+}
+
+#[test]
+fn test_vertical_pitch_keeps_up_valid() {
+    let mut camera = camera();
+    camera.set_pitch_limits(-90.0, 90.0);
+    camera.set_yaw_and_pitch(-90.0, 90.0);
+
+    assert!(approx_vec(camera.front, glm::vec3(0.0, 1.0, 0.0)));
+    assert!((glm::length(&camera.up) - 1.0).abs() < 1e-4);
+    assert!(glm::dot(&camera.front, &camera.up).abs() < 1e-5);
+    assert!(camera.view_matrix().iter().all(|v| v.is_finite()));
+}
+
+#[test]
+fn test_rotate_local_past_vertical_does_not_flip() {
+    let mut camera = camera();
+    for _ in 0..10 {
+        camera.rotate_local(10.0, 0.0, 0.0);
+    }
+    // Pitched 100 degrees: now looking up and slightly backwards, upside down
+    assert!(camera.front.y > 0.9);
+    assert!(camera.front.z > 0.0);
+    assert!(camera.up.y < 0.0);
+}
+
+#[test]
+fn test_set_orientation_round_trips_angles() {
+    let mut camera = camera();
+    camera.set_yaw_pitch_roll(45.0, -30.0, 15.0);
+    let orientation = camera.orientation();
+
+    let mut other = Camera::new(glm::vec3(0.0, 0.0, 0.0));
+    other.set_orientation(orientation);
+
+    assert!((other.yaw() - 45.0).abs() < 1e-3);
+    assert!((other.pitch() + 30.0).abs() < 1e-3);
+    assert!((other.roll() - 15.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_pitch_clamped_to_default_limit() {
+    let mut camera = camera();
+    camera.set_yaw_and_pitch(-90.0, 120.0);
+
+    assert_eq!(camera.pitch(), 89.0);
+    assert!(camera.front.y < 1.0);
+    assert!(camera.front.z < 0.0);
+}
+
+#[test]
+fn test_custom_pitch_limits() {
+    let mut camera = camera();
+    camera.set_pitch_limits(-45.0, 60.0);
+
+    camera.set_yaw_and_pitch(0.0, 120.0);
+    assert_eq!(camera.pitch(), 60.0);
+    camera.set_yaw_and_pitch(0.0, -120.0);
+    assert_eq!(camera.pitch(), -45.0);
+}
+
+#[test]
+fn test_right_is_orthogonal() {
+    let mut camera = camera();
+    camera.set_yaw_and_pitch(35.0, -40.0);
+    let right = camera.right();
+
+    assert!((glm::length(&right) - 1.0).abs() < 1e-4);
+    assert!(glm::dot(&right, &camera.front).abs() < 1e-5);
+    assert!(glm::dot(&right, &camera.up).abs() < 1e-5);
+    // Default camera looks down -Z, so right is +X
+    assert!(approx_vec(Camera::new(glm::Vec3::zeros()).right(), glm::vec3(1.0, 0.0, 0.0)));
+}
+
+#[test]
+fn test_horizontal_front_is_flat_and_orthogonal_to_right() {
+    let mut camera = camera();
+    camera.set_yaw_and_pitch(-90.0, -60.0);
+    let forward = camera.horizontal_front();
+
+    assert_eq!(forward.y, 0.0);
+    assert!(approx_vec(forward, glm::vec3(0.0, 0.0, -1.0)));
+    assert!(glm::dot(&forward, &camera.right()).abs() < 1e-5);
+}
+
+#[test]
+fn test_horizontal_front_stable_near_vertical() {
+    let mut camera = camera();
+    camera.set_pitch_limits(-90.0, 90.0);
+    for pitch in [89.99, 90.0, -90.0] {
+        camera.set_yaw_and_pitch(0.0, pitch);
+        assert!(approx_vec(camera.horizontal_front(), glm::vec3(1.0, 0.0, 0.0)));
+    }
+}
+
+#[test]
+fn test_screen_ray_center_follows_front() {
+    let mut camera = Camera::new(glm::vec3(1.0, 2.0, 3.0));
+    camera.set_yaw_and_pitch(20.0, -10.0);
+
+    let (origin, direction) = camera.screen_ray(400.0, 300.0, 800.0, 600.0, 800.0 / 600.0);
+    assert!(approx_vec(origin, camera.position));
+    assert!(approx_vec(direction, camera.front));
+}
+
+#[test]
+fn test_screen_ray_corners_diverge() {
+    let camera = camera();
+    let (_, top_left) = camera.screen_ray(0.0, 0.0, 800.0, 600.0, 800.0 / 600.0);
+    let (_, bottom_right) = camera.screen_ray(800.0, 600.0, 800.0, 600.0, 800.0 / 600.0);
+
+    assert!(top_left.x < 0.0 && top_left.y > 0.0);
+    assert!(bottom_right.x > 0.0 && bottom_right.y < 0.0);
+    assert!((glm::length(&top_left) - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_screen_ray_orthographic_is_parallel() {
+    let mut camera = camera();
+    camera.set_projection(Projection::Orthographic {
+        left: -10.0, right: 10.0, bottom: -5.0, top: 5.0, near: 0.1, far: 100.0,
+    });
+
+    let (origin_a, dir_a) = camera.screen_ray(0.0, 0.0, 800.0, 400.0, 2.0);
+    let (origin_b, dir_b) = camera.screen_ray(800.0, 400.0, 800.0, 400.0, 2.0);
+
+    assert!(approx_vec(dir_a, camera.front));
+    assert!(approx_vec(dir_b, camera.front));
+    assert!(approx_vec(origin_a, glm::vec3(-10.0, 5.0, -0.1)));
+    assert!(approx_vec(origin_b, glm::vec3(10.0, -5.0, -0.1)));
+}
+
+#[test]
+fn test_lerp_to_interpolates_position() {
+    let mut camera = camera();
+    let target = CameraState { position: glm::vec3(10.0, 0.0, -4.0), yaw: -90.0, pitch: 0.0 };
+
+    camera.lerp_to(&target, 0.5);
+    assert!(approx_vec(camera.position, glm::vec3(5.0, 0.0, -2.0)));
+
+    camera.lerp_to(&target, 1.0);
+    assert_eq!(camera.state().position, target.position);
+}
+
+#[test]
+fn test_lerp_to_takes_shortest_yaw_arc() {
+    let mut camera = camera();
+    camera.set_yaw_and_pitch(350.0, 0.0);
+    let target = CameraState { position: camera.position, yaw: 10.0, pitch: 0.0 };
+
+    camera.lerp_to(&target, 0.5);
+
+    assert!((camera.yaw() - 360.0).abs() < 1e-3);
+    assert!(approx_vec(camera.front, glm::vec3(1.0, 0.0, 0.0)));
+}
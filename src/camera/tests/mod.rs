@@ -0,0 +1 @@
+pub mod camera_tests;
@@ -1,15 +1,26 @@
 use std::marker::PhantomData;
 
 /// Lightweight typed identifier for a resource stored in a [`ResourceManager`].
+///
+/// A handle names a storage slot plus the slot's generation at insertion time. Removing the
+/// resource bumps the slot's generation, so a handle kept past `remove` stays invalid (`get`
+/// returns `None`, `is_stale` returns `true`) even after the slot is reused.
 #[derive(Debug)]
 pub struct Handle<T> {
     pub(crate) id: u32,
+    pub(crate) generation: u32,
     _marker: PhantomData<T>,
 }
 
 impl<T> Handle<T> {
+    /// A first-generation handle, for tests that build handles by hand.
+    #[cfg(test)]
     pub(crate) fn new(id: u32) -> Self {
-        Self { id, _marker: PhantomData }
+        Self::with_generation(id, 0)
+    }
+
+    pub(crate) fn with_generation(id: u32, generation: u32) -> Self {
+        Self { id, generation, _marker: PhantomData }
     }
 }
 
@@ -23,11 +34,14 @@ impl<T> Clone for Handle<T> {
 }
 
 impl<T> PartialEq for Handle<T> {
-    fn eq(&self, other: &Self) -> bool { self.id == other.id }
+    fn eq(&self, other: &Self) -> bool { self.id == other.id && self.generation == other.generation }
 }
 
 impl<T> Eq for Handle<T> {}
 
 impl<T> std::hash::Hash for Handle<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.id.hash(state); }
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.generation.hash(state);
+    }
 }
@@ -1,8 +1,10 @@
 use crate::core::handle::Handle;
 use crate::graphics::font::Font;
 use crate::graphics::shader::Shader;
+use crate::resource::resource_manager::ResourceStore;
 
 /// Handles to built-in resources auto-registered by the engine at startup.
+#[derive(Clone, Copy)]
 pub struct BuiltinResources {
     /// The default voxel shader (vertex.glsl + fragment.glsl).
     pub voxel_shader: Handle<Shader>,
@@ -12,6 +14,68 @@ pub struct BuiltinResources {
     pub ui_shader: Handle<Shader>,
     /// The wireframe/debug line shader (wireframe_vertex.glsl + wireframe_fragment.glsl).
     pub wireframe_shader: Handle<Shader>,
+    /// Weighted blended OIT accumulation shader (vertex.glsl + oit_accum_fragment.glsl).
+    pub oit_accum_shader: Handle<Shader>,
+    /// Weighted blended OIT full-screen composite shader (oit_composite_vertex.glsl + oit_composite_fragment.glsl).
+    pub oit_composite_shader: Handle<Shader>,
     /// The default font (Pix32, 24px).
     pub default_font: Handle<Font>,
 }
+
+impl BuiltinResources {
+    /// Compiles the built-in shaders and rasterizes the default font into `resources`.
+    /// Requires a current GL context.
+    pub(crate) fn register(resources: &mut impl ResourceStore) -> Self {
+        // Compile built-in shaders from embedded source
+        let voxel_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/vertex.glsl"),
+            include_str!("../../assets/shaders/fragment.glsl"),
+        ));
+        let text_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/text_vertex.glsl"),
+            include_str!("../../assets/shaders/text_fragment.glsl"),
+        ));
+        let ui_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/ui_vertex.glsl"),
+            include_str!("../../assets/shaders/ui_fragment.glsl"),
+        ));
+        let wireframe_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/wireframe_vertex.glsl"),
+            include_str!("../../assets/shaders/wireframe_fragment.glsl"),
+        ));
+        let oit_accum_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/vertex.glsl"),
+            include_str!("../../assets/shaders/oit_accum_fragment.glsl"),
+        ));
+        let oit_composite_shader = resources.insert(Shader::from_source(
+            include_str!("../../assets/shaders/oit_composite_vertex.glsl"),
+            include_str!("../../assets/shaders/oit_composite_fragment.glsl"),
+        ));
+
+        // Rasterize default font from embedded TTF
+        let default_font = resources.insert(
+            Font::from_ttf_bytes(include_bytes!("../../assets/fonts/Pix32.ttf"), 24.0),
+        );
+
+        Self {
+            voxel_shader,
+            text_shader,
+            ui_shader,
+            wireframe_shader,
+            oit_accum_shader,
+            oit_composite_shader,
+            default_font,
+        }
+    }
+
+    /// Removes the built-in resources from `resources`, dropping their GPU objects.
+    pub(crate) fn unregister(&self, resources: &mut impl ResourceStore) {
+        resources.remove(self.voxel_shader);
+        resources.remove(self.text_shader);
+        resources.remove(self.ui_shader);
+        resources.remove(self.wireframe_shader);
+        resources.remove(self.oit_accum_shader);
+        resources.remove(self.oit_composite_shader);
+        resources.remove(self.default_font);
+    }
+}
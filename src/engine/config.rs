@@ -0,0 +1,79 @@
+use sdl2::video::SwapInterval;
+
+/// Swap-interval policy for the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VsyncMode {
+    /// Adaptive VSync: syncs when possible, doesn't stall when behind. Falls back to `Off`.
+    #[default]
+    Adaptive,
+    /// Classic VSync, always waits for the vertical blank. Falls back to `Off`.
+    On,
+    /// No VSync.
+    Off,
+}
+
+impl VsyncMode {
+    /// Swap intervals to try in order; the first one the driver accepts wins.
+    pub fn swap_intervals(self) -> &'static [SwapInterval] {
+        match self {
+            VsyncMode::Adaptive => &[SwapInterval::LateSwapTearing, SwapInterval::Immediate],
+            VsyncMode::On => &[SwapInterval::VSync, SwapInterval::Immediate],
+            VsyncMode::Off => &[SwapInterval::Immediate],
+        }
+    }
+}
+
+/// Window and presentation settings for [`VoxxelEngine::with_config`](crate::engine::engine::VoxxelEngine::with_config).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineConfig {
+    /// Window title.
+    pub title: String,
+    /// Window width in pixels (ignored in fullscreen).
+    pub width: u32,
+    /// Window height in pixels (ignored in fullscreen).
+    pub height: u32,
+    /// Swap-interval policy.
+    pub vsync: VsyncMode,
+    /// Borderless fullscreen at desktop resolution.
+    pub fullscreen: bool,
+}
+
+impl EngineConfig {
+    /// Sets the window title (builder style).
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the window size (builder style).
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets the VSync mode (builder style).
+    pub fn with_vsync(mut self, vsync: VsyncMode) -> Self {
+        self.vsync = vsync;
+        self
+    }
+
+    /// Sets fullscreen mode (builder style).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+}
+
+impl Default for EngineConfig {
+    /// "Voxxel Engine", 1280x720, adaptive VSync, windowed.
+    fn default() -> Self {
+        Self {
+            title: "Voxxel Engine".to_string(),
+            width: 1280,
+            height: 720,
+            vsync: VsyncMode::Adaptive,
+            fullscreen: false,
+        }
+    }
+}
@@ -1,16 +1,56 @@
+use std::path::PathBuf;
 use crate::camera::Camera;
 use crate::input::input::Input;
+use crate::render::render_context::RenderStats;
 
 /// Per-frame context passed to the game during the update phase.
 pub struct EngineContext<'a> {
     /// Current input state (keyboard and mouse).
     pub input: &'a Input,
-    /// Seconds elapsed since the previous frame.
+    /// Seconds elapsed since the previous frame (the fixed step inside `fixed_update`).
     pub delta_time: f32,
+    /// How far (0..1) the frame is between the last fixed update and the next, for interpolation.
+    pub fixed_alpha: f32,
+    /// Number of frames so far, counting the current one.
+    pub frame_count: u64,
+    /// Smoothed frames per second (exponential moving average).
+    pub fps: f32,
+    /// Seconds since the first frame, e.g. for shader animation.
+    pub time_since_start: f32,
+    /// Command, draw-call and frustum-culled counts from the previous frame.
+    pub render_stats: RenderStats,
     /// Mutable reference to the engine-owned camera.
     pub camera: &'a mut Camera,
     /// Current window width in pixels.
     pub screen_width: f32,
     /// Current window height in pixels.
     pub screen_height: f32,
+    pub(crate) cursor_captured: bool,
+    pub(crate) screenshot: Option<PathBuf>,
+    pub(crate) screenshot_result: Option<&'a image::ImageResult<()>>,
+}
+
+impl EngineContext<'_> {
+    /// Captures (hides and locks, relative mouse mode) or releases the cursor, e.g. for a pause menu.
+    /// Applied by the engine after `update`. While released, mouse delta is not accumulated.
+    pub fn set_cursor_captured(&mut self, captured: bool) {
+        self.cursor_captured = captured;
+    }
+
+    /// Returns whether the cursor is captured (as of the latest `set_cursor_captured` call).
+    pub fn is_cursor_captured(&self) -> bool {
+        self.cursor_captured
+    }
+
+    /// Saves this frame as a PNG at `path` once it's rendered (see `VoxxelEngine::capture_screenshot`),
+    /// e.g. from a screenshot hotkey. The outcome is reported by [`Self::last_screenshot_result`].
+    pub fn request_screenshot(&mut self, path: impl Into<PathBuf>) {
+        self.screenshot = Some(path.into());
+    }
+
+    /// Returns whether the screenshot requested last frame was saved. Only `Some` during the
+    /// updates right after the frame it was captured in.
+    pub fn last_screenshot_result(&self) -> Option<&image::ImageResult<()>> {
+        self.screenshot_result
+    }
 }
@@ -1,67 +1,124 @@
 use nalgebra_glm as glm;
-use sdl2::event::Event;
+use sdl2::event::{Event, WindowEvent};
 use crate::camera::Camera;
 use crate::engine::builtins::BuiltinResources;
+use crate::engine::config::EngineConfig;
 use crate::engine::context::EngineContext;
-use crate::engine::gui_context::GuiContext;
-use crate::graphics::font::Font;
-use crate::graphics::shader::Shader;
-use crate::render::render_context::RenderContext;
+use crate::engine::fixed_timestep::FixedTimestep;
+use crate::engine::frame_stats::FrameStats;
+use crate::engine::gui_context::{GuiBatch, GuiContext};
+use crate::engine::screenshot;
+use crate::render::render_context::{RenderContext, RenderStats};
+use crate::render::render_environment::RenderEnvironment;
 use crate::render::renderer::Renderer;
 use crate::game::VoxxelGame;
 use crate::input::input::Input;
-use crate::resource::resource_manager::ResourceStore;
 
 /// The main engine that owns the window, input, camera, and render loop.
 pub struct VoxxelEngine {
     window: sdl2::video::Window,
-    _gl_context: sdl2::video::GLContext,
+    video: sdl2::VideoSubsystem,
+    gl_context: sdl2::video::GLContext,
+    /// Whether `gl_context` was created robust, i.e. reports resets.
+    robust_context: bool,
+    mouse: sdl2::mouse::MouseUtil,
+    controller_subsystem: sdl2::GameControllerSubsystem,
+    /// The first connected gamepad, if any.
+    controller: Option<sdl2::controller::GameController>,
     event_pump: sdl2::EventPump,
     input: Input,
     renderer: Renderer,
+    fixed_timestep: FixedTimestep,
+    frame_stats: FrameStats,
+    /// What the renderer did last frame, handed to the game through `EngineContext`.
+    render_stats: RenderStats,
+    /// Carried across frames so changes the game makes to `RenderContext::environment` persist.
+    environment: RenderEnvironment,
+    /// Shared mesh and shader behind the `GuiContext` primitives; `None` only while the
+    /// GL context is being recreated.
+    gui_batch: Option<GuiBatch>,
+    /// Registered by `init`; re-registered after a context loss.
+    builtins: Option<BuiltinResources>,
+    /// Start of the previous frame, for `frame_delta`.
+    last_frame: Option<std::time::Instant>,
+    /// Set when SDL reports a quit event.
+    quit_requested: bool,
+    /// Where to save the next rendered frame, requested through `EngineContext::request_screenshot`.
+    pending_screenshot: Option<std::path::PathBuf>,
+    /// Outcome of the last requested screenshot, handed to the game on the next update.
+    screenshot_result: Option<image::ImageResult<()>>,
     camera: Camera,
 }
 
 impl VoxxelEngine {
-    /// Initializes SDL2, creates an OpenGL 4.5 window, and returns a new engine instance.
+    /// Initializes SDL2, creates an OpenGL 4.5 window with the default [`EngineConfig`], and returns a new engine instance.
     pub fn new() -> Self {
+        Self::with_config(EngineConfig::default())
+    }
+
+    /// Initializes SDL2 and creates an OpenGL 4.5 window with the given title, size, VSync, and fullscreen settings.
+    pub fn with_config(config: EngineConfig) -> Self {
         let sdl = sdl2::init().unwrap();
         let video = sdl.video().unwrap();
 
         let gl_attr = video.gl_attr();
         gl_attr.set_context_profile(sdl2::video::GLProfile::Core);
         gl_attr.set_context_version(4, 5);
+        // Fixed depth format so the OIT pass can blit the default depth buffer
+        gl_attr.set_depth_size(24);
+        let mut window_builder = video.window(&config.title, config.width, config.height);
+        window_builder.opengl().resizable();
+        if config.fullscreen {
+            window_builder.fullscreen_desktop();
+        }
+        let window = window_builder.build().unwrap();
 
-        let window = video
-            .window("Voxxel Engine", 1280, 720)
-            .opengl()
-            .resizable()
-            .build()
-            .unwrap();
-
-        let gl_context = window.gl_create_context().unwrap();
+        let (gl_context, robust_context) = Self::create_gl_context(&video, &window);
         gl::load_with(|s| video.gl_get_proc_address(s) as *const _);
 
         let event_pump = sdl.event_pump().unwrap();
-        sdl.mouse().set_relative_mouse_mode(true);
 
-        // Adaptive VSync: syncs when possible, doesn't stall when behind.
-        // Falls back to no VSync if the driver doesn't support it.
-        if video.gl_set_swap_interval(sdl2::video::SwapInterval::LateSwapTearing).is_err() {
-            let _ = video.gl_set_swap_interval(sdl2::video::SwapInterval::Immediate);
-        }
+        let controller_subsystem = sdl.game_controller().unwrap();
+        let controller = (0..controller_subsystem.num_joysticks().unwrap_or(0))
+            .filter(|&i| controller_subsystem.is_game_controller(i))
+            .find_map(|i| controller_subsystem.open(i).ok());
+        let mouse = sdl.mouse();
+        mouse.set_relative_mouse_mode(true);
+        // Deliver typed characters (with IME composition) as TextInput events
+        video.text_input().start();
 
-        unsafe {
-            gl::Enable(gl::DEPTH_TEST);
-            gl::ClearColor(0.5, 0.7, 1.0, 1.0); // Sky blue
+        // Try the requested swap interval, falling back to no VSync if the driver refuses it.
+        for &interval in config.vsync.swap_intervals() {
+            if video.gl_set_swap_interval(interval).is_ok() {
+                break;
+            }
         }
 
+        Self::init_gl_state();
+        let (w, h) = window.size();
+        Self::set_viewport(w, h);
+
         Self {
             window,
-            _gl_context: gl_context,
+            video,
+            gl_context,
+            robust_context,
+            mouse,
+            controller_subsystem,
+            controller,
             event_pump,
             input: Input::new(),
             renderer: Renderer::new(),
+            fixed_timestep: FixedTimestep::default(),
+            frame_stats: FrameStats::default(),
+            render_stats: RenderStats::default(),
+            environment: RenderEnvironment::default(),
+            gui_batch: Some(GuiBatch::new()),
+            builtins: None,
+            last_frame: None,
+            quit_requested: false,
+            pending_screenshot: None,
+            screenshot_result: None,
             camera: Camera::new(glm::vec3(0.0, 0.0, 0.0)),
         }
     }
@@ -71,115 +128,322 @@ impl VoxxelEngine {
         &self.window
     }
 
+    /// Sets the `fixed_update` step in seconds (default 1/60).
+    pub fn set_fixed_timestep(&mut self, step: f32) {
+        self.fixed_timestep = FixedTimestep::new(step, 5);
+    }
+
+    fn engine_context<'a>(
+        &'a mut self,
+        delta_time: f32,
+        width: u32,
+        height: u32,
+        screenshot_result: Option<&'a image::ImageResult<()>>,
+    ) -> EngineContext<'a> {
+        EngineContext {
+            input: &self.input,
+            delta_time,
+            fixed_alpha: self.fixed_timestep.alpha(),
+            frame_count: self.frame_stats.frame_count(),
+            fps: self.frame_stats.fps(),
+            time_since_start: self.frame_stats.time_since_start(),
+            render_stats: self.render_stats,
+            camera: &mut self.camera,
+            screen_width: width as f32,
+            screen_height: height as f32,
+            cursor_captured: self.input.is_mouse_captured(),
+            screenshot: None,
+            screenshot_result,
+        }
+    }
+
+    fn apply_cursor_capture(&mut self, captured: bool) {
+        if captured != self.input.is_mouse_captured() {
+            self.mouse.set_relative_mouse_mode(captured);
+            self.input.set_mouse_captured(captured);
+        }
+    }
+
+    /// Sets the global GL state the renderer expects. Called for every new context.
+    fn init_gl_state() {
+        unsafe {
+            gl::Enable(gl::DEPTH_TEST);
+        }
+    }
+
+    /// Maps rendering to the full `width` x `height` window.
+    fn set_viewport(width: u32, height: u32) {
+        unsafe {
+            gl::Viewport(0, 0, width as i32, height as i32);
+        }
+    }
+
+    /// Creates the GL context, robust with LOSE_CONTEXT_ON_RESET where the driver supports it
+    /// (GL_KHR_robustness) so resets are reported through glGetGraphicsResetStatus instead of
+    /// silently corrupting state. Falls back to a plain context otherwise; the returned flag
+    /// says which one was created.
+    fn create_gl_context(video: &sdl2::VideoSubsystem, window: &sdl2::video::Window) -> (sdl2::video::GLContext, bool) {
+        let gl_attr = video.gl_attr();
+        gl_attr.set_context_flags().robust_access().set();
+        Self::set_reset_notification(sdl2::sys::SDL_GLContextResetNotification::SDL_GL_CONTEXT_RESET_LOSE_CONTEXT);
+        if let Ok(context) = window.gl_create_context() {
+            return (context, true);
+        }
+
+        gl_attr.set_context_flags().set();
+        Self::set_reset_notification(sdl2::sys::SDL_GLContextResetNotification::SDL_GL_CONTEXT_RESET_NO_NOTIFICATION);
+        (window.gl_create_context().unwrap(), false)
+    }
+
+    fn set_reset_notification(notification: sdl2::sys::SDL_GLContextResetNotification) {
+        unsafe {
+            sdl2::sys::SDL_GL_SetAttribute(
+                sdl2::sys::SDL_GLattr::SDL_GL_CONTEXT_RESET_NOTIFICATION,
+                notification as i32,
+            );
+        }
+    }
+
+    /// Queries `glGetGraphicsResetStatus`; returns `gl::NO_ERROR` if the context isn't robust
+    /// or the driver lacks it.
+    fn graphics_reset_status(&self) -> u32 {
+        if !self.robust_context || !gl::GetGraphicsResetStatus::is_loaded() {
+            return gl::NO_ERROR;
+        }
+        unsafe { gl::GetGraphicsResetStatus() }
+    }
+
+    /// Replaces the lost GL context with a fresh one and reloads function pointers.
+    fn recreate_gl_context(&mut self) {
+        // Drop GPU objects the renderer owns while the lost context is still current
+        self.renderer = Renderer::new();
+        (self.gl_context, self.robust_context) = Self::create_gl_context(&self.video, &self.window);
+        gl::load_with(|s| self.video.gl_get_proc_address(s) as *const _);
+        Self::init_gl_state();
+        let (w, h) = self.window.size();
+        Self::set_viewport(w, h);
+    }
+
     /// Starts the main loop: polls events, updates the game, renders, and swaps buffers.
+    /// A convenience wrapper over [`Self::init`], [`Self::frame_delta`], [`Self::poll_events`],
+    /// [`Self::update`], [`Self::render`], and [`Self::should_quit`] for games that don't
+    /// need their own loop.
     pub fn run<G: VoxxelGame>(mut self, mut game: G) {
-        // Compile built-in shaders from embedded source
-        let voxel_shader = game.resources_mut().insert(Shader::from_source(
-            include_str!("../../assets/shaders/vertex.glsl"),
-            include_str!("../../assets/shaders/fragment.glsl"),
-        ));
-        let text_shader = game.resources_mut().insert(Shader::from_source(
-            include_str!("../../assets/shaders/text_vertex.glsl"),
-            include_str!("../../assets/shaders/text_fragment.glsl"),
-        ));
-        let ui_shader = game.resources_mut().insert(Shader::from_source(
-            include_str!("../../assets/shaders/ui_vertex.glsl"),
-            include_str!("../../assets/shaders/ui_fragment.glsl"),
-        ));
-        let wireframe_shader = game.resources_mut().insert(Shader::from_source(
-            include_str!("../../assets/shaders/wireframe_vertex.glsl"),
-            include_str!("../../assets/shaders/wireframe_fragment.glsl"),
-        ));
-
-        // Rasterize default font from embedded TTF
-        let default_font = game.resources_mut().insert(
-            Font::from_ttf_bytes(include_bytes!("../../assets/fonts/Pix32.ttf"), 24.0),
-        );
+        self.init(&mut game);
 
-        game.on_init(BuiltinResources {
-            voxel_shader,
-            text_shader,
-            ui_shader,
-            wireframe_shader,
-            default_font,
-        });
+        while !self.should_quit() {
+            let delta_time = self.frame_delta();
+            self.poll_events(&mut game);
+            if self.should_quit() {
+                break;
+            }
+            self.update(&mut game, delta_time);
+            self.render(&mut game);
+        }
 
-        let mut last_frame = std::time::Instant::now();
+        // The GL context is still current here: `game` is dropped before `self` (and the
+        // context it owns), so GPU resources released in `on_exit` or by `Drop` are valid.
+        game.on_exit();
+    }
 
-        'running: loop {
-            let now = std::time::Instant::now();
-            let mut delta_time = now.duration_since(last_frame).as_secs_f32();
-            last_frame = now;
+    /// Registers the built-in resources into the game's resources and calls `on_init`.
+    /// Call once before driving the other steps from a custom loop.
+    pub fn init<G: VoxxelGame>(&mut self, game: &mut G) {
+        let builtins = BuiltinResources::register(game.resources_mut());
+        self.renderer.set_oit_shaders(builtins.oit_accum_shader, builtins.oit_composite_shader);
+        self.builtins = Some(builtins);
+        game.on_init(builtins);
+    }
 
-            // Prevent huge first-frame delta_time or lag spikes from breaking physics
-            if delta_time > 0.1 {
-                delta_time = 0.016; // Assume ~60fps if we have a huge lag spike
-            }
+    /// Returns the seconds since the previous call (0 on the first), and counts a frame for
+    /// FPS stats. Spikes over 0.1s (e.g. a debugger pause) are replaced by ~60fps so they
+    /// don't break physics.
+    pub fn frame_delta(&mut self) -> f32 {
+        let now = std::time::Instant::now();
+        let mut delta_time = self.last_frame.map_or(0.0, |last| now.duration_since(last).as_secs_f32());
+        self.last_frame = Some(now);
+        self.frame_stats.tick(delta_time);
 
-            while let Some(event) = self.event_pump.poll_event() {
-                match event {
-                    Event::Quit { .. } => break 'running,
-                    Event::KeyDown { scancode: Some(k), .. } => {
-                        self.input.set_key(k, true);
-                    }
-                    Event::KeyUp { scancode: Some(k), .. } => self.input.set_key(k, false),
-                    Event::MouseButtonDown { mouse_btn, .. } => { self.input.set_mouse_button(mouse_btn, true); }
-                    Event::MouseButtonUp { mouse_btn, .. } => { self.input.set_mouse_button(mouse_btn, false); }
-                    Event::MouseMotion { xrel, yrel, .. } => {
-                        self.input.add_mouse_delta(xrel as f32, yrel as f32);
-                    }
-                    _ => {}
+        // Prevent huge first-frame delta_time or lag spikes from breaking physics
+        if delta_time > 0.1 {
+            delta_time = 0.016; // Assume ~60fps if we have a huge lag spike
+        }
+        delta_time
+    }
+
+    /// Starts a new input frame and handles pending SDL events: input, resizes, controller
+    /// hot-plugging, quit requests (see [`Self::should_quit`]), and GL context loss.
+    pub fn poll_events<G: VoxxelGame>(&mut self, game: &mut G) {
+        self.input.update();
+
+        let mut device_reset = false;
+        while let Some(event) = self.event_pump.poll_event() {
+            match event {
+                Event::Quit { .. } => {
+                    self.quit_requested = true;
+                    return;
+                }
+                Event::RenderDeviceReset { .. } => device_reset = true,
+                Event::Window { win_event: WindowEvent::Resized(w, h), .. } => {
+                    let (w, h) = (w.max(0) as u32, h.max(0) as u32);
+                    Self::set_viewport(w, h);
+                    game.on_resize(w, h);
                 }
+                Event::KeyDown { scancode: Some(k), .. } => {
+                    self.input.set_key(k, true);
+                }
+                Event::KeyUp { scancode: Some(k), .. } => self.input.set_key(k, false),
+                Event::MouseButtonDown { mouse_btn, .. } => { self.input.set_mouse_button(mouse_btn, true); }
+                Event::MouseButtonUp { mouse_btn, .. } => { self.input.set_mouse_button(mouse_btn, false); }
+                Event::MouseMotion { x, y, xrel, yrel, .. } => {
+                    self.input.set_mouse_position(x as f32, y as f32);
+                    self.input.add_mouse_delta(xrel as f32, yrel as f32);
+                }
+                Event::MouseWheel { precise_y, direction, .. } => {
+                    // Normalize "natural" scrolling so positive always means away from the user
+                    let y = if direction == sdl2::mouse::MouseWheelDirection::Flipped { -precise_y } else { precise_y };
+                    self.input.add_scroll_delta(y);
+                }
+                Event::TextInput { text, .. } => self.input.push_text(&text),
+                Event::ControllerDeviceAdded { which, .. } if self.controller.is_none() => {
+                    self.controller = self.controller_subsystem.open(which).ok();
+                }
+                Event::ControllerDeviceRemoved { which, .. }
+                    if self.controller.as_ref().is_some_and(|c| c.instance_id() == which) =>
+                {
+                    self.controller = None;
+                    self.input.clear_gamepad();
+                }
+                Event::ControllerButtonDown { button, .. } => self.input.set_gamepad_button(button, true),
+                Event::ControllerButtonUp { button, .. } => self.input.set_gamepad_button(button, false),
+                Event::ControllerAxisMotion { axis, value, .. } => self.input.set_gamepad_axis(axis, value),
+                _ => {}
             }
+        }
 
-            let (w, h) = self.window.size();
+        // --- Context loss ---
+        let reset_status = if device_reset {
+            gl::UNKNOWN_CONTEXT_RESET
+        } else {
+            self.graphics_reset_status()
+        };
+        let Some(old_builtins) = self.builtins else { return };
+        handle_context_reset(game, reset_status, |resources| {
+            old_builtins.unregister(resources);
+            self.gui_batch = None;
+            self.recreate_gl_context();
+            self.gui_batch = Some(GuiBatch::new());
+            let builtins = BuiltinResources::register(resources);
+            self.renderer.set_oit_shaders(builtins.oit_accum_shader, builtins.oit_composite_shader);
+            self.builtins = Some(builtins);
+            builtins
+        });
+    }
 
-            // --- Update ---
-            {
-                let mut engine_ctx = EngineContext {
-                    input: &self.input,
-                    delta_time,
-                    camera: &mut self.camera,
-                    screen_width: w as f32,
-                    screen_height: h as f32,
-                };
+    /// Reads the window's framebuffer back with `glReadPixels` and saves it as a PNG at `path`.
+    /// Reads the back buffer, so it only sees a finished frame between drawing and the buffer
+    /// swap; from game code use `EngineContext::request_screenshot`, which captures there.
+    pub fn capture_screenshot(&self, path: impl AsRef<std::path::Path>) -> image::ImageResult<()> {
+        let (w, h) = self.window.size();
+        let pixels = screenshot::read_back_buffer(w, h);
+        screenshot::save_png(path.as_ref(), w, h, &pixels)
+    }
 
-                game.update(&mut engine_ctx);
-            }
+    /// Returns `true` once the window was asked to close.
+    pub fn should_quit(&self) -> bool {
+        self.quit_requested
+    }
 
-            // --- Render ---
-            unsafe {
-                gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
-            }
+    /// Runs as many `fixed_update` steps as `delta_time` covers, then `update` once.
+    pub fn update<G: VoxxelGame>(&mut self, game: &mut G, delta_time: f32) {
+        let (w, h) = self.window.size();
+        let screenshot_result = self.screenshot_result.take();
 
-            let aspect = w as f32 / h as f32;
-            let mut render_ctx = RenderContext::new(
-                self.camera.view_matrix(),
-                self.camera.projection_matrix(aspect),
-                w as f32,
-                h as f32,
-            );
+        // --- Fixed update ---
+        let steps = self.fixed_timestep.advance(delta_time);
+        for _ in 0..steps {
+            let step = self.fixed_timestep.step();
+            let mut engine_ctx = self.engine_context(step, w, h, screenshot_result.as_ref());
+            game.fixed_update(&mut engine_ctx);
+            let captured = engine_ctx.cursor_captured;
+            let screenshot = engine_ctx.screenshot.take();
+            self.apply_cursor_capture(captured);
+            self.pending_screenshot = screenshot.or(self.pending_screenshot.take());
+        }
+
+        // --- Update ---
+        let mut engine_ctx = self.engine_context(delta_time, w, h, screenshot_result.as_ref());
+        game.update(&mut engine_ctx);
+        let captured = engine_ctx.cursor_captured;
+        let screenshot = engine_ctx.screenshot.take();
+        self.apply_cursor_capture(captured);
+        self.pending_screenshot = screenshot.or(self.pending_screenshot.take());
+    }
 
-            // Game submits commands to queues
-            game.render(&mut render_ctx);
+    /// Renders a frame (queues, then immediate-mode UI) and swaps buffers.
+    pub fn render<G: VoxxelGame>(&mut self, game: &mut G) {
+        let (w, h) = self.window.size();
+        let aspect = w as f32 / h as f32;
+        let (view, projection) = game.view_projection(&self.camera, aspect);
+        self.environment.time = self.frame_stats.time_since_start();
+        let mut render_ctx = RenderContext::new(
+            view,
+            projection,
+            w as f32,
+            h as f32,
+            self.environment,
+        );
 
-            // Engine processes all queues (opaque -> transparent -> gui)
-            self.renderer.render(&mut render_ctx, game.resources());
+        // Game submits commands to queues
+        game.render(&mut render_ctx);
 
-            // GUI immediate-mode path (kept for GuiContext/Font compatibility)
-            // Blend is still enabled and depth test disabled from the renderer's GUI pass
-            let gui_ctx = GuiContext::new(w as f32, h as f32);
-            game.render_ui(&gui_ctx);
+        // Clear to the sky color the game chose this frame
+        self.environment = render_ctx.environment;
+        let clear = self.environment.clear_color();
+        unsafe {
+            gl::ClearColor(clear.x, clear.y, clear.z, clear.w);
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+        }
 
-            // Restore GL state for next frame
-            unsafe {
-                gl::Disable(gl::BLEND);
-                gl::Enable(gl::DEPTH_TEST);
-            }
+        // Engine processes all queues (opaque -> transparent -> gui)
+        self.render_stats = self.renderer.render(&mut render_ctx, game.resources());
 
-            self.window.gl_swap_window();
+        // GUI immediate-mode path (kept for GuiContext/Font compatibility)
+        // Blend is still enabled and depth test disabled from the renderer's GUI pass
+        let mut gui_ctx = GuiContext::new(w as f32, h as f32);
+        if let Some(batch) = &self.gui_batch {
+            gui_ctx = gui_ctx.with_batch(batch);
+        }
+        game.render_ui(&gui_ctx);
+
+        if let Some(path) = self.pending_screenshot.take() {
+            self.screenshot_result = Some(self.capture_screenshot(path));
+        }
 
-            self.input.update();
+        // Restore GL state for next frame
+        unsafe {
+            gl::Disable(gl::BLEND);
+            gl::Enable(gl::DEPTH_TEST);
         }
+
+        self.window.gl_swap_window();
     }
 }
+
+/// Runs the context-loss hooks if `status` (from `glGetGraphicsResetStatus`) reports a reset.
+/// `recreate` builds the new context and built-ins between `on_context_lost` and
+/// `on_context_restored`. Returns `true` if a reset was handled.
+pub(crate) fn handle_context_reset<G: VoxxelGame>(
+    game: &mut G,
+    status: u32,
+    recreate: impl FnOnce(&mut G::Resources) -> BuiltinResources,
+) -> bool {
+    if status == gl::NO_ERROR {
+        return false;
+    }
+
+    game.on_context_lost();
+    let builtins = recreate(game.resources_mut());
+    game.on_context_restored(builtins);
+    true
+}
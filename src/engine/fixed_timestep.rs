@@ -0,0 +1,39 @@
+/// Accumulator that turns variable frame times into a whole number of fixed steps.
+pub struct FixedTimestep {
+    step: f32,
+    max_steps: u32,
+    accumulator: f32,
+}
+
+impl FixedTimestep {
+    /// Creates an accumulator with the given step (seconds) that runs at most `max_steps` per frame.
+    pub fn new(step: f32, max_steps: u32) -> Self {
+        Self { step, max_steps: max_steps.max(1), accumulator: 0.0 }
+    }
+
+    /// Returns the fixed step in seconds.
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Adds a frame's elapsed time and returns how many fixed steps to run.
+    /// Time beyond `max_steps` is dropped so a lag spike can't snowball (spiral of death).
+    pub fn advance(&mut self, delta_time: f32) -> u32 {
+        self.accumulator = (self.accumulator + delta_time).min(self.step * self.max_steps as f32);
+        let steps = (self.accumulator / self.step).floor() as u32;
+        self.accumulator -= steps as f32 * self.step;
+        steps
+    }
+
+    /// Fraction of a step left in the accumulator, for interpolating between fixed states.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator / self.step
+    }
+}
+
+impl Default for FixedTimestep {
+    /// 60 Hz, at most 5 steps per frame.
+    fn default() -> Self {
+        Self::new(1.0 / 60.0, 5)
+    }
+}
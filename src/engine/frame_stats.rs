@@ -0,0 +1,52 @@
+/// Frame counter, smoothed FPS, and total running time, updated once per frame.
+pub struct FrameStats {
+    frame_count: u64,
+    fps: f32,
+    time_since_start: f32,
+    smoothing: f32,
+}
+
+impl FrameStats {
+    /// Creates stats where each frame contributes `smoothing` (0..1) to the FPS moving average.
+    pub fn new(smoothing: f32) -> Self {
+        Self { frame_count: 0, fps: 0.0, time_since_start: 0.0, smoothing: smoothing.clamp(0.0, 1.0) }
+    }
+
+    /// Records a frame that took `delta_time` seconds (unclamped wall time).
+    pub fn tick(&mut self, delta_time: f32) {
+        self.frame_count += 1;
+        self.time_since_start += delta_time;
+        if delta_time <= 0.0 {
+            return;
+        }
+        let instant_fps = 1.0 / delta_time;
+        // Seed with the first sample so the average doesn't ramp up from zero
+        self.fps = if self.frame_count == 1 {
+            instant_fps
+        } else {
+            self.fps + (instant_fps - self.fps) * self.smoothing
+        };
+    }
+
+    /// Number of frames recorded so far.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Exponential moving average of frames per second.
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    /// Seconds since the first frame.
+    pub fn time_since_start(&self) -> f32 {
+        self.time_since_start
+    }
+}
+
+impl Default for FrameStats {
+    /// Smoothing of 0.1 (roughly a 10-frame window).
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
@@ -1,21 +1,28 @@
+use std::cell::RefCell;
 use nalgebra_glm as glm;
 use crate::graphics::gui_material::GuiInstance;
 use crate::graphics::gpu_mesh::GpuMesh;
 use crate::graphics::font::Font;
 use crate::graphics::shader::Shader;
+use crate::graphics::texture::texture::{PixelFormat, Texture};
+use crate::graphics::vertex::VertexPosUv;
 
 /// Immediate-mode GUI rendering context with an orthographic projection.
-pub struct GuiContext {
+pub struct GuiContext<'a> {
     /// Screen width in pixels.
     pub width: f32,
     /// Screen height in pixels.
     pub height: f32,
     /// Orthographic projection matrix for Y-down UI coordinates.
     pub projection: glm::Mat4,
+    /// Shared GPU objects for the primitive helpers; `None` makes them no-ops.
+    batch: Option<&'a GuiBatch>,
 }
 
-impl GuiContext {
+impl<'a> GuiContext<'a> {
     /// Creates a new GUI context for the given screen dimensions.
+    /// Primitives (`fill_rect`, `draw_line`, `draw_nine_slice`) draw nothing until the
+    /// engine attaches its batch; the context handed to `render_ui` always has one.
     pub fn new(width: f32, height: f32) -> Self {
         Self {
             width,
@@ -25,11 +32,18 @@ impl GuiContext {
                 height, 0.0, // Y-down UI coordinates
                 -1.0, 1.0,
             ),
+            batch: None,
         }
     }
+
+    /// Attaches the shared primitive mesh, shader, and white texture.
+    pub(crate) fn with_batch(mut self, batch: &'a GuiBatch) -> Self {
+        self.batch = Some(batch);
+        self
+    }
 }
 
-impl GuiContext {
+impl GuiContext<'_> {
     /// Draws a mesh using a GUI material and model transform.
     pub fn draw(
         &self,
@@ -67,5 +81,241 @@ impl GuiContext {
 
         mesh.draw();
     }
+
+    /// Draws `text` anchored to a screen edge, corner, or the center. The text's measured
+    /// size (see [`Font::measure`]) keeps right- and bottom-anchored text fully on screen.
+    /// `offset` (pixels) moves the text away from the anchored edges, so `(10, 10)` is a
+    /// 10px margin for every corner; on centered axes it's added as is (positive = right/down).
+    pub fn draw_text_aligned(
+        &self,
+        text: &str,
+        font: &Font,
+        shader: &Shader,
+        anchor: Anchor,
+        offset: glm::Vec2,
+        color: &glm::Vec4,
+    ) {
+        let origin = anchor.place((self.width, self.height), font.measure(text), offset);
+        self.draw_text_at(text, font, shader, origin, color);
+    }
+
+    /// Draws `text` aligned horizontally inside `span` (`(x, y, width)` in pixels, Y down), with
+    /// its top edge at `y`, e.g. centered in a button.
+    pub fn draw_text_in_width(
+        &self,
+        text: &str,
+        font: &Font,
+        shader: &Shader,
+        span: (f32, f32, f32),
+        align: HAlign,
+        color: &glm::Vec4,
+    ) {
+        let (x, y, width) = span;
+        let x = x + align.offset(width, font.measure(text).0);
+        self.draw_text_at(text, font, shader, glm::vec2(x, y), color);
+    }
+
+    /// Builds `text` into the shared mesh and draws it with its top-left corner at `origin`.
+    fn draw_text_at(&self, text: &str, font: &Font, shader: &Shader, origin: glm::Vec2, color: &glm::Vec4) {
+        let Some(batch) = self.batch else { return };
+        let mut mesh = batch.mesh.borrow_mut();
+        font.update_mesh(&mut mesh, text);
+        let model = glm::translation(&glm::vec3(origin.x.round(), origin.y.round(), 0.0));
+        self.draw_text(&mesh, font, shader, &model, color);
+    }
+
+    /// Fills the rectangle with top-left corner `(x, y)` and size `w` x `h` (pixels, Y down).
+    pub fn fill_rect(&self, x: f32, y: f32, w: f32, h: f32, color: &glm::Vec4) {
+        let Some(batch) = self.batch else { return };
+        let vertices = rect_vertices((x, y, w, h), WHITE_UV, WHITE_UV);
+        self.draw_primitive(batch, &batch.white, &vertices, color);
+    }
+
+    /// Draws a line from `a` to `b` (pixels, Y down) as a quad `thickness` pixels wide.
+    pub fn draw_line(&self, a: glm::Vec2, b: glm::Vec2, color: &glm::Vec4, thickness: f32) {
+        let Some(batch) = self.batch else { return };
+        if let Some(vertices) = line_vertices(a, b, thickness) {
+            self.draw_primitive(batch, &batch.white, &vertices, color);
+        }
+    }
+
+    /// Stretches `texture` over `rect` (`(x, y, w, h)` in pixels, Y down) as a nine-slice
+    /// panel: the `border_px` wide edges and corners keep their size and only the middle
+    /// stretches. Expects the texture flipped on load like [`Texture::from_file`].
+    pub fn draw_nine_slice(&self, rect: (f32, f32, f32, f32), texture: &Texture, border_px: f32) {
+        let Some(batch) = self.batch else { return };
+        let texture_size = (texture.width as f32, texture.height as f32);
+        let vertices = nine_slice_vertices(rect, texture_size, border_px);
+        self.draw_primitive(batch, texture, &vertices, &glm::vec4(1.0, 1.0, 1.0, 1.0));
+    }
+
+    /// Uploads `vertices` into the shared mesh and draws them tinted by `color`.
+    fn draw_primitive(&self, batch: &GuiBatch, texture: &Texture, vertices: &[VertexPosUv], color: &glm::Vec4) {
+        let mut mesh = batch.mesh.borrow_mut();
+        mesh.update_vertices(vertices);
+
+        let shader = &batch.shader;
+        shader.use_program();
+        texture.bind(0);
+        shader.set_int("uTexture", 0);
+        shader.set_mat4("projection", &self.projection);
+        shader.set_mat4("model", &glm::identity());
+        shader.set_vec4("uColor", color);
+
+        mesh.draw();
+    }
+}
+
+/// Where [`GuiContext::draw_text_aligned`] places text on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns how far along each axis the anchor sits: 0.0 = left/top, 0.5 = center, 1.0 = right/bottom.
+    fn factors(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::TopCenter => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::CenterLeft => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::CenterRight => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::BottomCenter => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+
+    /// Returns the top-left corner for a `size` box anchored inside `screen`, with `offset`
+    /// pointing away from the anchored edges (see [`GuiContext::draw_text_aligned`]).
+    pub fn place(self, screen: (f32, f32), size: (f32, f32), offset: glm::Vec2) -> glm::Vec2 {
+        let (fx, fy) = self.factors();
+        // Right/bottom edges flip the offset so it insets; centers keep it as is
+        let sign = |f: f32| if f == 1.0 { -1.0 } else { 1.0 };
+        glm::vec2(
+            (screen.0 - size.0) * fx + offset.x * sign(fx),
+            (screen.1 - size.1) * fy + offset.y * sign(fy),
+        )
+    }
+}
+
+/// Horizontal alignment of text inside a given width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl HAlign {
+    /// Returns the x offset of a `content_width` line aligned inside `width`.
+    pub fn offset(self, width: f32, content_width: f32) -> f32 {
+        match self {
+            HAlign::Left => 0.0,
+            HAlign::Center => (width - content_width) * 0.5,
+            HAlign::Right => width - content_width,
+        }
+    }
+}
+
+/// GPU objects shared by every frame's [`GuiContext`] primitives, owned by the engine.
+pub(crate) struct GuiBatch {
+    shader: Shader,
+    /// 1x1 white texture so flat colors go through the same textured shader.
+    white: Texture,
+    /// Re-filled for every primitive.
+    mesh: RefCell<GpuMesh>,
+}
+
+impl GuiBatch {
+    /// Compiles the primitive shader and allocates the shared mesh. Requires a current GL context.
+    pub(crate) fn new() -> Self {
+        let shader = Shader::from_source(
+            include_str!("../../assets/shaders/ui_vertex.glsl"),
+            include_str!("../../assets/shaders/gui_primitive_fragment.glsl"),
+        );
+        let white = Texture::from_bytes_format(&[255; 4], 1, 1, PixelFormat::RGBA8);
+        // Sized for a nine-slice so most frames never regrow the buffer
+        let mesh = GpuMesh::from_vertices(&[VertexPosUv { position: [0.0; 3], uv: [0.0; 2] }; 54]);
+        Self { shader, white, mesh: RefCell::new(mesh) }
+    }
+}
+
+/// UV of the white texture's only texel.
+const WHITE_UV: (f32, f32) = (0.5, 0.5);
+
+/// Two triangles covering `corners` (top-left, top-right, bottom-right, bottom-left)
+/// with the matching `uvs`.
+fn quad(corners: [glm::Vec2; 4], uvs: [(f32, f32); 4]) -> [VertexPosUv; 6] {
+    let vertex = |i: usize| VertexPosUv {
+        position: [corners[i].x, corners[i].y, 0.0],
+        uv: [uvs[i].0, uvs[i].1],
+    };
+    [vertex(0), vertex(3), vertex(2), vertex(0), vertex(2), vertex(1)]
+}
+
+/// Vertices for the axis-aligned `rect` (`(x, y, w, h)`, Y down), with `uv_top_left` and
+/// `uv_bottom_right` at its corners.
+pub(crate) fn rect_vertices(
+    rect: (f32, f32, f32, f32),
+    uv_top_left: (f32, f32),
+    uv_bottom_right: (f32, f32),
+) -> [VertexPosUv; 6] {
+    let (x, y, w, h) = rect;
+    let (u0, v0) = uv_top_left;
+    let (u1, v1) = uv_bottom_right;
+    quad(
+        [glm::vec2(x, y), glm::vec2(x + w, y), glm::vec2(x + w, y + h), glm::vec2(x, y + h)],
+        [(u0, v0), (u1, v0), (u1, v1), (u0, v1)],
+    )
+}
+
+/// Vertices for a `thickness` wide quad centered on the segment `a`-`b`, or `None` if the
+/// segment has no length.
+pub(crate) fn line_vertices(a: glm::Vec2, b: glm::Vec2, thickness: f32) -> Option<[VertexPosUv; 6]> {
+    let dir = b - a;
+    let length = glm::length(&dir);
+    if length <= f32::EPSILON {
+        return None;
+    }
+    let offset = glm::vec2(-dir.y, dir.x) * (thickness * 0.5 / length);
+    Some(quad([a + offset, b + offset, b - offset, a - offset], [WHITE_UV; 4]))
 }
 
+/// Vertices for the nine quads of a nine-slice panel (54 vertices). `border_px` is
+/// clamped to half the rect so small panels don't fold over.
+pub(crate) fn nine_slice_vertices(
+    rect: (f32, f32, f32, f32),
+    texture_size: (f32, f32),
+    border_px: f32,
+) -> Vec<VertexPosUv> {
+    let (x, y, w, h) = rect;
+    let border = border_px.min(w * 0.5).min(h * 0.5).max(0.0);
+    let xs = [x, x + border, x + w - border, x + w];
+    let ys = [y, y + border, y + h - border, y + h];
+    // Same clamped border in texels, so corners are cropped rather than squeezed
+    let (bu, bv) = ((border / texture_size.0).min(0.5), (border / texture_size.1).min(0.5));
+    let us = [0.0, bu, 1.0 - bu, 1.0];
+    // Textures are flipped on load, so the top of the image is at v = 1
+    let vs = [1.0, 1.0 - bv, bv, 0.0];
+
+    let mut vertices = Vec::with_capacity(54);
+    for row in 0..3 {
+        for col in 0..3 {
+            let cell = (xs[col], ys[row], xs[col + 1] - xs[col], ys[row + 1] - ys[row]);
+            vertices.extend(rect_vertices(cell, (us[col], vs[row]), (us[col + 1], vs[row + 1])));
+        }
+    }
+    vertices
+}
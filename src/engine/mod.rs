@@ -1,4 +1,11 @@
 pub mod builtins;
+pub mod config;
 pub mod context;
 pub mod engine;
-pub mod gui_context;
\ No newline at end of file
+pub mod fixed_timestep;
+pub mod frame_stats;
+pub mod gui_context;
+pub(crate) mod screenshot;
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+/// Reads `width` x `height` RGBA pixels from the default framebuffer's back buffer, top row
+/// first (flipped from GL's bottom-left origin) and fully opaque. Call after drawing and before
+/// the swap.
+pub(crate) fn read_back_buffer(width: u32, height: u32) -> Vec<u8> {
+    let row_len = width as usize * 4;
+    let mut pixels = vec![0u8; row_len * height as usize];
+    unsafe {
+        gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+        gl::ReadBuffer(gl::BACK);
+        // Rows are tightly packed
+        gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+        gl::ReadPixels(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            gl::RGBA,
+            gl::UNSIGNED_BYTE,
+            pixels.as_mut_ptr() as *mut _,
+        );
+    }
+    flip_rows(&mut pixels, row_len);
+    make_opaque(&mut pixels);
+    pixels
+}
+
+/// Sets the alpha of every RGBA pixel to 255. Blending writes alpha into the framebuffer too,
+/// which would otherwise leave blended areas of the image see-through.
+pub(crate) fn make_opaque(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel[3] = 255;
+    }
+}
+
+/// Reverses the order of the `row_len`-byte rows in `pixels` in place.
+pub(crate) fn flip_rows(pixels: &mut [u8], row_len: usize) {
+    if row_len == 0 {
+        return;
+    }
+    let rows = pixels.len() / row_len;
+    for top in 0..rows / 2 {
+        let bottom = rows - 1 - top;
+        let (upper, lower) = pixels.split_at_mut(bottom * row_len);
+        upper[top * row_len..(top + 1) * row_len].swap_with_slice(&mut lower[..row_len]);
+    }
+}
+
+/// Writes top-row-first RGBA pixels to a PNG file.
+pub(crate) fn save_png(path: &Path, width: u32, height: u32, pixels: &[u8]) -> image::ImageResult<()> {
+    image::save_buffer(path, pixels, width, height, image::ColorType::Rgba8)
+}
@@ -0,0 +1,32 @@
+use sdl2::video::SwapInterval;
+use crate::engine::config::{EngineConfig, VsyncMode};
+
+#[test]
+fn test_default_matches_previous_hard_coded_window() {
+    let config = EngineConfig::default();
+    assert_eq!(config.title, "Voxxel Engine");
+    assert_eq!((config.width, config.height), (1280, 720));
+    assert_eq!(config.vsync, VsyncMode::Adaptive);
+    assert!(!config.fullscreen);
+}
+
+#[test]
+fn test_builder_overrides_fields() {
+    let config = EngineConfig::default()
+        .with_title("My Game")
+        .with_size(1920, 1080)
+        .with_vsync(VsyncMode::Off)
+        .with_fullscreen(true);
+
+    assert_eq!(config.title, "My Game");
+    assert_eq!((config.width, config.height), (1920, 1080));
+    assert_eq!(config.vsync, VsyncMode::Off);
+    assert!(config.fullscreen);
+}
+
+#[test]
+fn test_vsync_mode_fallback_chains() {
+    assert_eq!(VsyncMode::Adaptive.swap_intervals(), &[SwapInterval::LateSwapTearing, SwapInterval::Immediate]);
+    assert_eq!(VsyncMode::On.swap_intervals(), &[SwapInterval::VSync, SwapInterval::Immediate]);
+    assert_eq!(VsyncMode::Off.swap_intervals(), &[SwapInterval::Immediate]);
+}
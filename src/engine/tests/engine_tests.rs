@@ -0,0 +1,97 @@
+use nalgebra_glm as glm;
+use std::path::PathBuf;
+use crate::camera::Camera;
+use crate::core::handle::Handle;
+use crate::engine::builtins::BuiltinResources;
+use crate::engine::context::EngineContext;
+use crate::engine::engine::handle_context_reset;
+use crate::engine::gui_context::GuiContext;
+use crate::files::file_manager::{FileManager, Mount};
+use crate::files::path::{DirPolicy, LogicalPath, ResourcePath};
+use crate::game::VoxxelGame;
+use crate::render::render_context::RenderContext;
+use crate::resource::resource_manager::ResourceManager;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct TestPath;
+
+impl LogicalPath for TestPath {
+    fn resource_path(&self) -> ResourcePath {
+        ResourcePath { mount: Mount::Game, policy: DirPolicy::Optional, relative_path: PathBuf::new() }
+    }
+}
+
+/// Records which lifecycle hooks ran, in order.
+struct TestGame {
+    resources: ResourceManager<TestPath>,
+    events: Vec<&'static str>,
+}
+
+impl TestGame {
+    fn new() -> Self {
+        Self { resources: ResourceManager::new(FileManager::new("test")), events: Vec::new() }
+    }
+}
+
+impl VoxxelGame for TestGame {
+    type Resources = ResourceManager<TestPath>;
+
+    fn on_init(&mut self, _builtins: BuiltinResources) {}
+    fn update(&mut self, _ctx: &mut EngineContext) {}
+    fn render(&mut self, _ctx: &mut RenderContext) {}
+    fn render_ui(&self, _ctx: &GuiContext) {}
+    fn on_context_lost(&mut self) {
+        self.events.push("lost");
+    }
+    fn on_context_restored(&mut self, _builtins: BuiltinResources) {
+        self.events.push("restored");
+    }
+    fn resources(&self) -> &Self::Resources { &self.resources }
+    fn resources_mut(&mut self) -> &mut Self::Resources { &mut self.resources }
+}
+
+fn fake_builtins() -> BuiltinResources {
+    BuiltinResources {
+        voxel_shader: Handle::new(0),
+        text_shader: Handle::new(1),
+        ui_shader: Handle::new(2),
+        wireframe_shader: Handle::new(3),
+        oit_accum_shader: Handle::new(4),
+        oit_composite_shader: Handle::new(5),
+        default_font: Handle::new(0),
+    }
+}
+
+#[test]
+fn test_no_reset_does_not_invoke_hooks() {
+    let mut game = TestGame::new();
+    let handled = handle_context_reset(&mut game, gl::NO_ERROR, |_| panic!("context recreated without a reset"));
+
+    assert!(!handled);
+    assert!(game.events.is_empty());
+}
+
+#[test]
+fn test_simulated_reset_invokes_hooks_in_order() {
+    let mut game = TestGame::new();
+    let mut recreated = false;
+    let handled = handle_context_reset(&mut game, gl::GUILTY_CONTEXT_RESET, |_| {
+        recreated = true;
+        fake_builtins()
+    });
+
+    assert!(handled);
+    assert!(recreated);
+    assert_eq!(game.events, vec!["lost", "restored"]);
+}
+
+#[test]
+fn test_default_view_projection_uses_engine_camera() {
+    let game = TestGame::new();
+    let camera = Camera::new(glm::vec3(3.0, 4.0, 5.0));
+
+    let (view, projection) = game.view_projection(&camera, 16.0 / 9.0);
+
+    assert_eq!(view, camera.view_matrix());
+    assert_eq!(projection, camera.projection_matrix(16.0 / 9.0));
+}
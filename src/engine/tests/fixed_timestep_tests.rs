@@ -0,0 +1,25 @@
+use crate::engine::fixed_timestep::FixedTimestep;
+
+#[test]
+fn test_accumulates_partial_frames() {
+    let mut timestep = FixedTimestep::new(0.1, 5);
+
+    assert_eq!(timestep.advance(0.05), 0);
+    assert_eq!(timestep.advance(0.06), 1);
+    assert!((timestep.alpha() - 0.1).abs() < 1e-4);
+}
+
+#[test]
+fn test_long_frame_runs_multiple_steps() {
+    let mut timestep = FixedTimestep::new(0.1, 5);
+    assert_eq!(timestep.advance(0.35), 3);
+    assert!((timestep.alpha() - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_lag_spike_is_clamped() {
+    let mut timestep = FixedTimestep::new(0.1, 5);
+    assert_eq!(timestep.advance(10.0), 5);
+    // Excess time was dropped, not carried into the next frame
+    assert_eq!(timestep.advance(0.0), 0);
+}
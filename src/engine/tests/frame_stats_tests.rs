@@ -0,0 +1,30 @@
+use crate::engine::frame_stats::FrameStats;
+
+#[test]
+fn test_first_frame_seeds_fps() {
+    let mut stats = FrameStats::new(0.1);
+    stats.tick(0.02);
+
+    assert_eq!(stats.frame_count(), 1);
+    assert!((stats.fps() - 50.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_fps_moves_towards_new_rate() {
+    let mut stats = FrameStats::new(0.5);
+    stats.tick(0.01); // 100 fps
+    stats.tick(0.02); // 50 fps
+
+    assert!((stats.fps() - 75.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_time_since_start_accumulates() {
+    let mut stats = FrameStats::default();
+    for _ in 0..4 {
+        stats.tick(0.25);
+    }
+
+    assert_eq!(stats.frame_count(), 4);
+    assert!((stats.time_since_start() - 1.0).abs() < 1e-5);
+}
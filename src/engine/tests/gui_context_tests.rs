@@ -0,0 +1,92 @@
+use crate::engine::gui_context::{line_vertices, nine_slice_vertices, rect_vertices, Anchor, HAlign};
+use nalgebra_glm as glm;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+#[test]
+fn test_rect_vertices_cover_rect() {
+    let vertices = rect_vertices((10.0, 20.0, 30.0, 40.0), (0.0, 1.0), (1.0, 0.0));
+
+    let xs: Vec<f32> = vertices.iter().map(|v| v.position[0]).collect();
+    let ys: Vec<f32> = vertices.iter().map(|v| v.position[1]).collect();
+    assert_eq!(xs.iter().cloned().fold(f32::MAX, f32::min), 10.0);
+    assert_eq!(xs.iter().cloned().fold(f32::MIN, f32::max), 40.0);
+    assert_eq!(ys.iter().cloned().fold(f32::MAX, f32::min), 20.0);
+    assert_eq!(ys.iter().cloned().fold(f32::MIN, f32::max), 60.0);
+
+    // Top-left corner carries the top-left UV
+    let top_left = vertices.iter().find(|v| v.position[0] == 10.0 && v.position[1] == 20.0).unwrap();
+    assert_eq!(top_left.uv, [0.0, 1.0]);
+}
+
+#[test]
+fn test_line_vertices_are_offset_by_half_thickness() {
+    let vertices = line_vertices(glm::vec2(0.0, 0.0), glm::vec2(10.0, 0.0), 4.0).unwrap();
+
+    for v in &vertices {
+        assert!(approx(v.position[1].abs(), 2.0));
+    }
+}
+
+#[test]
+fn test_zero_length_line_has_no_vertices() {
+    assert!(line_vertices(glm::vec2(5.0, 5.0), glm::vec2(5.0, 5.0), 2.0).is_none());
+}
+
+#[test]
+fn test_nine_slice_keeps_border_size() {
+    let vertices = nine_slice_vertices((0.0, 0.0, 100.0, 50.0), (32.0, 32.0), 8.0);
+    assert_eq!(vertices.len(), 54);
+
+    let mut xs: Vec<f32> = vertices.iter().map(|v| v.position[0]).collect();
+    xs.sort_by(f32::total_cmp);
+    xs.dedup();
+    assert_eq!(xs, vec![0.0, 8.0, 92.0, 100.0]);
+
+    let mut us: Vec<f32> = vertices.iter().map(|v| v.uv[0]).collect();
+    us.sort_by(f32::total_cmp);
+    us.dedup();
+    assert_eq!(us, vec![0.0, 0.25, 0.75, 1.0]);
+}
+
+#[test]
+fn test_nine_slice_border_clamped_to_small_rect() {
+    let vertices = nine_slice_vertices((0.0, 0.0, 10.0, 10.0), (32.0, 32.0), 8.0);
+
+    let mut xs: Vec<f32> = vertices.iter().map(|v| v.position[0]).collect();
+    xs.sort_by(f32::total_cmp);
+    xs.dedup();
+    assert_eq!(xs, vec![0.0, 5.0, 10.0]);
+
+    // UVs use the same 5px border, so corners map one-to-one instead of stretching
+    let mut us: Vec<f32> = vertices.iter().map(|v| v.uv[0]).collect();
+    us.sort_by(f32::total_cmp);
+    us.dedup();
+    assert_eq!(us, vec![0.0, 5.0 / 32.0, 27.0 / 32.0, 1.0]);
+}
+
+#[test]
+fn test_anchor_keeps_text_on_screen() {
+    let screen = (800.0, 600.0);
+    let size = (120.0, 24.0);
+    let margin = glm::vec2(10.0, 10.0);
+
+    assert_eq!(Anchor::TopLeft.place(screen, size, margin), glm::vec2(10.0, 10.0));
+    assert_eq!(Anchor::BottomRight.place(screen, size, margin), glm::vec2(670.0, 566.0));
+    assert_eq!(Anchor::TopRight.place(screen, size, margin), glm::vec2(670.0, 10.0));
+}
+
+#[test]
+fn test_center_anchor_adds_offset() {
+    let placed = Anchor::Center.place((800.0, 600.0), (100.0, 20.0), glm::vec2(0.0, 50.0));
+    assert_eq!(placed, glm::vec2(350.0, 340.0));
+}
+
+#[test]
+fn test_halign_offset() {
+    assert_eq!(HAlign::Left.offset(200.0, 50.0), 0.0);
+    assert_eq!(HAlign::Center.offset(200.0, 50.0), 75.0);
+    assert_eq!(HAlign::Right.offset(200.0, 50.0), 150.0);
+}
@@ -0,0 +1,6 @@
+pub mod engine_tests;
+pub mod fixed_timestep_tests;
+pub mod config_tests;
+pub mod frame_stats_tests;
+pub mod gui_context_tests;
+pub mod screenshot_tests;
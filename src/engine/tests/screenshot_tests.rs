@@ -0,0 +1,32 @@
+use crate::engine::screenshot::{flip_rows, make_opaque, save_png};
+
+#[test]
+fn test_flip_rows_reverses_row_order() {
+    let mut pixels = vec![1, 1, 2, 2, 3, 3];
+    flip_rows(&mut pixels, 2);
+    assert_eq!(pixels, vec![3, 3, 2, 2, 1, 1]);
+
+    let mut even = vec![1, 2, 3, 4];
+    flip_rows(&mut even, 2);
+    assert_eq!(even, vec![3, 4, 1, 2]);
+}
+
+#[test]
+fn test_make_opaque_only_touches_alpha() {
+    let mut pixels = vec![10, 20, 30, 0, 40, 50, 60, 128, 70, 80, 90, 255];
+    make_opaque(&mut pixels);
+    assert_eq!(pixels, vec![10, 20, 30, 255, 40, 50, 60, 255, 70, 80, 90, 255]);
+}
+
+#[test]
+fn test_save_png_round_trip() {
+    let path = std::env::temp_dir().join(format!("voxxel_screenshot_{}.png", std::process::id()));
+    // 2x1: red, then half-transparent green
+    let pixels = [255, 0, 0, 255, 0, 255, 0, 128];
+    save_png(&path, 2, 1, &pixels).unwrap();
+
+    let image = image::open(&path).unwrap().to_rgba8();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(image.dimensions(), (2, 1));
+    assert_eq!(image.as_raw().as_slice(), &pixels);
+}
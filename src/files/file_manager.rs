@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use crate::files::FileError;
 use crate::files::path::{LogicalPath, DirPolicy, ResourcePath};
 
@@ -25,32 +25,48 @@ impl<P: LogicalPath> FileManager<P> {
     /// Creates a file manager with default mount points derived from the current directory.
     pub fn new(game_name: &str) -> Self {
         let root = std::env::current_dir().expect("Current dir missing");
-        let mut mount_points = HashMap::new();
 
         // Define the 3 main "Roots"
-        mount_points.insert(Mount::Engine, root.join("engine_assets"));
-        mount_points.insert(Mount::Game, root.join("game_assets").join(game_name));
-        mount_points.insert(Mount::User, root.join("user_data").join(game_name));
+        Self::with_roots(
+            root.join("engine_assets"),
+            root.join("game_assets").join(game_name),
+            root.join("user_data").join(game_name),
+        )
+    }
 
-        let fm = FileManager {
+    /// Creates a file manager with explicit mount roots, e.g. relative to the executable
+    /// for installed games. See [`user_data_dir`] for an OS-appropriate `user` root.
+    pub fn with_roots(engine: PathBuf, game: PathBuf, user: PathBuf) -> Self {
+        let mut mount_points = HashMap::new();
+        mount_points.insert(Mount::Engine, engine);
+        mount_points.insert(Mount::Game, game);
+        mount_points.insert(Mount::User, user);
+
+        FileManager {
             mount_points,
             _marker: std::marker::PhantomData,
-        };
+        }
+    }
+
+    /// Points `mount` at a new root directory, e.g. moving `User` to [`user_data_dir`].
+    pub fn set_mount(&mut self, mount: Mount, root: PathBuf) {
+        self.mount_points.insert(mount, root);
+    }
 
-        fm
+    /// Returns the root directory of `mount`, if registered.
+    pub fn mount_root(&self, mount: Mount) -> Option<&Path> {
+        self.mount_points.get(&mount).map(PathBuf::as_path)
     }
 
     /// Resolves a logical path and filename to a physical file path.
+    /// Under [`DirPolicy::AutoCreate`] a missing directory is created first.
     pub fn resolve(
         &self,
         logical: P,
         file: &str,
     ) -> Result<PathBuf, FileError> {
         let res = logical.resource_path();
-        let base = self.mount_points.get(&res.mount)
-            .ok_or(FileError::InvalidMount)?;
-
-        let full = base.join(&res.relative_path).join(file);
+        let full = self.prepare_dir(&res)?.join(file);
 
         if !full.exists() {
             match res.policy {
@@ -63,4 +79,161 @@ impl<P: LogicalPath> FileManager<P> {
 
         Ok(full)
     }
+
+    /// Resolves the path a file should be written to; unlike [`Self::resolve`], the file itself
+    /// need not exist. The directory must exist unless the policy is [`DirPolicy::AutoCreate`],
+    /// in which case it is created.
+    pub fn resolve_for_write(
+        &self,
+        logical: P,
+        file: &str,
+    ) -> Result<PathBuf, FileError> {
+        let res = logical.resource_path();
+        Ok(self.prepare_dir(&res)?.join(file))
+    }
+
+    /// Reads a whole file through the VFS.
+    pub fn read(&self, logical: P, file: &str) -> Result<Vec<u8>, FileError> {
+        let full = self.resolve(logical, file)?;
+        fs::read(full).map_err(FileError::Io)
+    }
+
+    /// Reads a whole UTF-8 text file through the VFS.
+    pub fn read_to_string(&self, logical: P, file: &str) -> Result<String, FileError> {
+        let full = self.resolve(logical, file)?;
+        fs::read_to_string(full).map_err(FileError::Io)
+    }
+
+    /// Creates or replaces a file through the VFS, creating its directory under
+    /// [`DirPolicy::AutoCreate`] (e.g. saves and config on the `User` mount).
+    pub fn write(&self, logical: P, file: &str, bytes: &[u8]) -> Result<(), FileError> {
+        let full = self.resolve_for_write(logical, file)?;
+        fs::write(full, bytes).map_err(FileError::Io)
+    }
+
+    /// Returns the sorted names of the files (not subdirectories) directly in a logical directory.
+    /// A missing directory follows its [`DirPolicy`], so an `AutoCreate` one lists as empty.
+    pub fn list(&self, logical: P) -> Result<Vec<String>, FileError> {
+        self.list_filtered(logical, None, false)
+    }
+
+    /// Like [`Self::list`], keeping only files with the extension `ext` (without the dot).
+    pub fn list_ext(&self, logical: P, ext: &str) -> Result<Vec<String>, FileError> {
+        self.list_filtered(logical, Some(ext), false)
+    }
+
+    /// Lists files in `logical` and, if `recursive`, all its subdirectories, optionally
+    /// filtered by extension. Nested files are named by their `/`-separated path relative
+    /// to the logical directory.
+    pub fn list_filtered(
+        &self,
+        logical: P,
+        ext: Option<&str>,
+        recursive: bool,
+    ) -> Result<Vec<String>, FileError> {
+        let dir = self.prepare_dir(&logical.resource_path())?;
+        let mut names = Vec::new();
+        collect_files(&dir, "", ext, recursive, &mut names)?;
+        names.sort();
+        Ok(names)
+    }
+
+    /// Returns the physical directory for `res`, creating it under [`DirPolicy::AutoCreate`].
+    /// A missing directory is [`FileError::MissingRequired`] or [`FileError::NotFound`] otherwise.
+    fn prepare_dir(&self, res: &ResourcePath) -> Result<PathBuf, FileError> {
+        let base = self.mount_points.get(&res.mount)
+            .ok_or(FileError::InvalidMount)?;
+        let dir = base.join(&res.relative_path);
+
+        if !dir.is_dir() {
+            match res.policy {
+                DirPolicy::AutoCreate => fs::create_dir_all(&dir).map_err(FileError::Io)?,
+                DirPolicy::Required => return Err(FileError::MissingRequired),
+                DirPolicy::Optional => return Err(FileError::NotFound),
+            }
+        }
+
+        Ok(dir)
+    }
+
+    /// Reads a file and checks its SHA-256 hash against `expected`.
+    /// Returns [`FileError::Checksum`] if the contents do not match.
+    #[cfg(feature = "checksum")]
+    pub fn read_verified(
+        &self,
+        logical: P,
+        file: &str,
+        expected: &[u8; 32],
+    ) -> Result<Vec<u8>, FileError> {
+        let bytes = self.read(logical, file)?;
+
+        if sha256(&bytes) != *expected {
+            return Err(FileError::Checksum);
+        }
+        Ok(bytes)
+    }
+
+    /// Computes the SHA-256 hash of a file, e.g. to precompute values for [`Self::read_verified`].
+    #[cfg(feature = "checksum")]
+    pub fn hash_file(&self, logical: P, file: &str) -> Result<[u8; 32], FileError> {
+        let bytes = self.read(logical, file)?;
+        Ok(sha256(&bytes))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_mount_points(mount_points: HashMap<Mount, PathBuf>) -> Self {
+        FileManager {
+            mount_points,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Returns the OS-appropriate per-user data directory for `game_name`:
+/// `%APPDATA%\<game>` on Windows, `~/Library/Application Support/<game>` on macOS,
+/// and `$XDG_DATA_HOME/<game>` (falling back to `~/.local/share/<game>`) elsewhere.
+/// Returns `None` if the needed environment variables are unset.
+pub fn user_data_dir(game_name: &str) -> Option<PathBuf> {
+    let env_dir = |key: &str| std::env::var_os(key).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+    let base = if cfg!(windows) {
+        env_dir("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME")?.join("Library").join("Application Support")
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| Some(env_dir("HOME")?.join(".local").join("share")))?
+    };
+    Some(base.join(game_name))
+}
+
+/// Appends the names of the files in `dir` (prefixed by `prefix`) that match `ext`.
+fn collect_files(
+    dir: &Path,
+    prefix: &str,
+    ext: Option<&str>,
+    recursive: bool,
+    out: &mut Vec<String>,
+) -> Result<(), FileError> {
+    for entry in fs::read_dir(dir).map_err(FileError::Io)? {
+        let entry = entry.map_err(FileError::Io)?;
+        let path = entry.path();
+        // Names that aren't valid UTF-8 can't be passed back to `resolve`, so skip them
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+        let name = format!("{prefix}{name}");
+
+        if path.is_dir() {
+            if recursive {
+                collect_files(&path, &format!("{name}/"), ext, recursive, out)?;
+            }
+        } else if ext.is_none_or(|ext| path.extension().is_some_and(|e| e == ext)) {
+            out.push(name);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "checksum")]
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes).into()
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ pub mod file_manager;
 pub mod path;
 
 /// Errors returned by the virtual file system.
+#[derive(Debug)]
 pub enum FileError {
     /// The resolved path does not exist.
     NotFound,
@@ -11,4 +12,30 @@ pub enum FileError {
     MissingRequired,
     /// An underlying I/O error.
     Io(std::io::Error),
-}
\ No newline at end of file
+    /// The file contents did not match the expected SHA-256 hash.
+    Checksum,
+}
+
+impl std::fmt::Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::NotFound => write!(f, "File not found"),
+            FileError::InvalidMount => write!(f, "Mount point is not registered"),
+            FileError::MissingRequired => write!(f, "Required file or directory is missing"),
+            FileError::Io(e) => write!(f, "File I/O failed: {e}"),
+            FileError::Checksum => write!(f, "File contents do not match the expected SHA-256 hash"),
+        }
+    }
+}
+
+impl std::error::Error for FileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FileError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use crate::files::file_manager::{user_data_dir, FileManager, Mount};
+use crate::files::path::{DirPolicy, LogicalPath, ResourcePath};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum TestPath {
+    Data,
+    Saves,
+}
+
+impl LogicalPath for TestPath {
+    fn resource_path(&self) -> ResourcePath {
+        match self {
+            TestPath::Data => ResourcePath {
+                mount: Mount::Game,
+                policy: DirPolicy::Optional,
+                relative_path: PathBuf::from("data"),
+            },
+            TestPath::Saves => ResourcePath {
+                mount: Mount::Game,
+                policy: DirPolicy::AutoCreate,
+                relative_path: PathBuf::from("saves").join("slot1"),
+            },
+        }
+    }
+}
+
+/// Creates an empty scratch directory under the system temp dir, unique per test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("voxxel_fm_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn manager_at(root: &std::path::Path) -> FileManager<TestPath> {
+    let mut mounts = HashMap::new();
+    mounts.insert(Mount::Game, root.to_path_buf());
+    FileManager::with_mount_points(mounts)
+}
+
+#[test]
+fn test_resolve_existing_and_missing() {
+    let root = scratch_dir("resolve");
+    std::fs::create_dir_all(root.join("data")).unwrap();
+    std::fs::write(root.join("data").join("present.txt"), b"hi").unwrap();
+    let fm = manager_at(&root);
+
+    let found = fm.resolve(TestPath::Data, "present.txt").ok();
+    assert_eq!(found, Some(root.join("data").join("present.txt")));
+    assert!(matches!(fm.resolve(TestPath::Data, "missing.txt"), Err(crate::files::FileError::NotFound)));
+}
+
+#[test]
+fn test_resolve_for_write_auto_creates_directory() {
+    let root = scratch_dir("autocreate");
+    let fm = manager_at(&root);
+
+    let path = fm.resolve_for_write(TestPath::Saves, "world.dat").ok();
+    assert_eq!(path, Some(root.join("saves").join("slot1").join("world.dat")));
+    assert!(root.join("saves").join("slot1").is_dir());
+}
+
+#[test]
+fn test_resolve_for_write_optional_needs_directory() {
+    let root = scratch_dir("optional_write");
+    let fm = manager_at(&root);
+
+    assert!(matches!(fm.resolve_for_write(TestPath::Data, "x.txt"), Err(crate::files::FileError::NotFound)));
+    assert!(!root.join("data").exists());
+}
+
+#[test]
+fn test_write_then_read_round_trip() {
+    let root = scratch_dir("round_trip");
+    let fm = manager_at(&root);
+
+    assert!(fm.write(TestPath::Saves, "config.toml", b"volume = 3").is_ok());
+    assert_eq!(fm.read(TestPath::Saves, "config.toml").ok().as_deref(), Some(&b"volume = 3"[..]));
+    assert_eq!(fm.read_to_string(TestPath::Saves, "config.toml").ok().as_deref(), Some("volume = 3"));
+
+    // Overwrites in place
+    assert!(fm.write(TestPath::Saves, "config.toml", b"volume = 5").is_ok());
+    assert_eq!(fm.read_to_string(TestPath::Saves, "config.toml").ok().as_deref(), Some("volume = 5"));
+}
+
+#[test]
+fn test_read_missing_file() {
+    let root = scratch_dir("read_missing");
+    let fm = manager_at(&root);
+
+    assert!(matches!(fm.read(TestPath::Saves, "nope.bin"), Err(crate::files::FileError::NotFound)));
+}
+
+#[test]
+fn test_list_files() {
+    let root = scratch_dir("list");
+    let data = root.join("data");
+    std::fs::create_dir_all(data.join("sub")).unwrap();
+    for file in ["b.png", "a.png", "notes.txt"] {
+        std::fs::write(data.join(file), b"").unwrap();
+    }
+    std::fs::write(data.join("sub").join("c.png"), b"").unwrap();
+    let fm = manager_at(&root);
+
+    assert_eq!(fm.list(TestPath::Data).ok(), Some(vec!["a.png".to_string(), "b.png".into(), "notes.txt".into()]));
+    assert_eq!(fm.list_ext(TestPath::Data, "png").ok(), Some(vec!["a.png".to_string(), "b.png".into()]));
+    assert_eq!(
+        fm.list_filtered(TestPath::Data, Some("png"), true).ok(),
+        Some(vec!["a.png".to_string(), "b.png".into(), "sub/c.png".into()]),
+    );
+}
+
+#[test]
+fn test_list_missing_directory_follows_policy() {
+    let root = scratch_dir("list_missing");
+    let fm = manager_at(&root);
+
+    assert!(matches!(fm.list(TestPath::Data), Err(crate::files::FileError::NotFound)));
+    assert_eq!(fm.list(TestPath::Saves).ok(), Some(Vec::new()));
+}
+
+#[test]
+fn test_with_roots_and_set_mount() {
+    let mut fm = FileManager::<TestPath>::with_roots("/opt/game/engine".into(), "/opt/game/assets".into(), "/tmp/user".into());
+    assert_eq!(fm.mount_root(Mount::Game), Some(std::path::Path::new("/opt/game/assets")));
+
+    fm.set_mount(Mount::User, PathBuf::from("/home/me/.local/share/game"));
+    assert_eq!(fm.mount_root(Mount::User), Some(std::path::Path::new("/home/me/.local/share/game")));
+}
+
+#[test]
+fn test_user_data_dir_ends_with_game_name() {
+    if let Some(dir) = user_data_dir("voxxel_demo") {
+        assert!(dir.ends_with("voxxel_demo"));
+        assert!(dir.is_absolute());
+    }
+}
+
+#[test]
+fn test_file_error_display_and_source() {
+    use crate::files::FileError;
+    use std::error::Error;
+
+    assert_eq!(FileError::NotFound.to_string(), "File not found");
+    assert!(FileError::NotFound.source().is_none());
+
+    let io = FileError::Io(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied"));
+    assert_eq!(io.to_string(), "File I/O failed: denied");
+    assert!(io.source().is_some());
+
+    // Bubbles into boxed errors with `?`
+    let boxed: Box<dyn Error> = Box::new(FileError::InvalidMount);
+    assert_eq!(boxed.to_string(), "Mount point is not registered");
+}
+
+#[cfg(feature = "checksum")]
+mod checksum {
+    use super::*;
+    use crate::files::FileError;
+
+    // SHA-256 of the ASCII bytes "abc"
+    const ABC_SHA256: [u8; 32] = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+        0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+    ];
+
+    #[test]
+    fn test_read_verified_matching_hash() {
+        let root = scratch_dir("verify_ok");
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::write(root.join("data").join("asset.bin"), b"abc").unwrap();
+        let fm = manager_at(&root);
+
+        assert_eq!(fm.hash_file(TestPath::Data, "asset.bin").ok(), Some(ABC_SHA256));
+        let bytes = fm.read_verified(TestPath::Data, "asset.bin", &ABC_SHA256).ok();
+        assert_eq!(bytes.as_deref(), Some(&b"abc"[..]));
+    }
+
+    #[test]
+    fn test_read_verified_mismatched_hash() {
+        let root = scratch_dir("verify_bad");
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::write(root.join("data").join("asset.bin"), b"abd").unwrap();
+        let fm = manager_at(&root);
+
+        let result = fm.read_verified(TestPath::Data, "asset.bin", &ABC_SHA256);
+        assert!(matches!(result, Err(FileError::Checksum)));
+    }
+}
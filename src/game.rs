@@ -1,3 +1,5 @@
+use nalgebra_glm as glm;
+use crate::camera::Camera;
 use crate::engine::builtins::BuiltinResources;
 use crate::engine::context::EngineContext;
 use crate::engine::gui_context::GuiContext;
@@ -13,10 +15,33 @@ pub trait VoxxelGame {
     fn on_init(&mut self, builtins: BuiltinResources);
     /// Called once per frame to update game logic.
     fn update(&mut self, ctx: &mut EngineContext);
+    /// Called zero or more times per frame at a fixed `ctx.delta_time` (default 1/60 s),
+    /// before `update`. Put physics and other deterministic simulation here.
+    fn fixed_update(&mut self, _ctx: &mut EngineContext) {}
+    /// Returns the `(view, projection)` matrices the frame is rendered with. Defaults to the
+    /// engine camera; override to render from game-owned cameras (split-screen, minimap).
+    fn view_projection(&self, camera: &Camera, aspect: f32) -> (glm::Mat4, glm::Mat4) {
+        (camera.view_matrix(), camera.projection_matrix(aspect))
+    }
     /// Called once per frame to submit render commands to the queues.
     fn render(&mut self, ctx: &mut RenderContext);
     /// Called once per frame to draw immediate-mode GUI elements.
     fn render_ui(&self, ctx: &GuiContext);
+    /// Called after the window was resized and the viewport updated, with the new size in pixels.
+    /// Rebuild size-dependent resources (off-screen framebuffers, UI layout) here.
+    fn on_resize(&mut self, _width: u32, _height: u32) {}
+    /// Called when the GL context was lost (driver reset, GPU removal). Every GPU object the
+    /// game holds (`GpuMesh`, `Shader`, `Texture`, `TextureArray`, `Texture3D`, `Font`) is now
+    /// invalid and should be dropped here; the lost context is still current, so their `Drop`
+    /// impls are harmless. The engine removes its own built-in resources afterwards.
+    fn on_context_lost(&mut self) {}
+    /// Called once a fresh GL context has replaced the lost one, with newly registered built-ins.
+    /// Recreate GPU resources here: re-upload textures and lightmaps, rebuild meshes,
+    /// recompile custom shaders, and rebuild materials that referenced the old handles.
+    fn on_context_restored(&mut self, _builtins: BuiltinResources) {}
+    /// Called once when the main loop exits (window closed), right before `run` returns.
+    /// The GL context is still alive, so flush saves and release GPU resources here.
+    fn on_exit(&mut self) {}
     /// Returns a reference to the game's resource storage.
     fn resources(&self) -> &Self::Resources;
     /// Returns a mutable reference to the game's resource storage.
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use crate::graphics::uv_rect::UvRect;
 use crate::graphics::gpu_mesh::GpuMesh;
 use crate::graphics::vertex::VertexPosUv;
@@ -6,6 +7,7 @@ use nalgebra_glm as glm;
 use crate::graphics::texture::texture::Texture;
 
 /// Metrics and UV data for a single rasterized character.
+#[derive(Debug, Clone)]
 pub struct Glyph {
     /// UV region of this glyph in the font atlas.
     pub uv_rect: UvRect,
@@ -17,18 +19,33 @@ pub struct Glyph {
     pub advance: f32,
     /// Horizontal bearing offset in pixels.
     pub offset_x: f32,
-    /// Vertical bearing offset in pixels.
+    /// Distance in pixels from the baseline up to the glyph's top edge (`height + ymin`).
+    /// Smaller than `height` for glyphs with descenders (g, p, y), which extend below the baseline.
     pub offset_y: f32,
 }
 
+/// Printable ASCII (space through `~`), the default range for [`Font::from_ttf_bytes`].
+pub const ASCII_PRINTABLE: RangeInclusive<u32> = 32..=126;
+
 /// A rasterized font atlas with glyph metrics for text rendering.
 pub struct Font {
     /// The grayscale atlas texture containing all rasterized glyphs.
     pub texture: Texture,
     /// Per-character glyph metrics keyed by character.
     pub glyphs: HashMap<char, Glyph>,
+    /// Glyph drawn for characters missing from `glyphs` (a "tofu" box), if any.
+    pub fallback: Option<Glyph>,
     /// Vertical spacing between lines in pixels.
     pub line_height: f32,
+    /// Distance from the top of a line to its baseline in pixels. Text meshes start at the top-left
+    /// of the first line, so every glyph on a line sits on `line_top + ascent`.
+    pub ascent: f32,
+    /// Horizontal kerning adjustment in pixels for `(left, right)` character pairs.
+    pub kerning: HashMap<(char, char), f32>,
+    /// Multiplier on every glyph advance (1.0 = the font's own spacing).
+    pub letter_spacing: f32,
+    /// Multiplier on `line_height` between lines (1.0 = single spacing).
+    pub line_spacing: f32,
 }
 
 impl Font {
@@ -37,7 +54,12 @@ impl Font {
         Self {
             texture,
             glyphs,
+            fallback: None,
             line_height,
+            ascent: 0.0,
+            kerning: HashMap::new(),
+            letter_spacing: 1.0,
+            line_spacing: 1.0,
         }
     }
 
@@ -47,68 +69,26 @@ impl Font {
         Self::from_ttf_bytes(&bytes, size)
     }
 
-    /// Rasterizes a TTF font from in-memory bytes at the given size into an atlas.
+    /// Rasterizes the printable ASCII range of a TTF font from in-memory bytes into an atlas.
     pub fn from_ttf_bytes(bytes: &[u8], size: f32) -> Self {
-        let font = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default()).unwrap();
-
-        let mut glyphs = HashMap::new();
-        let chars: Vec<char> = (32..127).map(|i| std::char::from_u32(i).unwrap()).collect();
-
-        // 1. Calculate atlas size with 1px padding to prevent bleeding
-        let mut atlas_width = 0;
-        let mut atlas_height = 0;
-        for &c in &chars {
-            let metrics = font.metrics(c, size);
-            atlas_width += metrics.width + 1; // 1px padding
-            atlas_height = atlas_height.max(metrics.height);
-        }
-
-        let mut atlas_pixels = vec![0u8; atlas_width * atlas_height];
-        let mut current_x = 0;
-
-        // 2. Rasterize and copy
-        for &c in &chars {
-            let (metrics, bitmap) = font.rasterize(c, size);
-
-            for y in 0..metrics.height {
-                for x in 0..metrics.width {
-                    // Correct indexing for a row-major atlas
-                    let dest_idx = y * atlas_width + (current_x + x);
-                    let src_idx = y * metrics.width + x;
-                    if dest_idx < atlas_pixels.len() {
-                        atlas_pixels[dest_idx] = bitmap[src_idx];
-                    }
-                }
-            }
-
-            // 3. UV Mapping
-            // We use half-pixel offsets (0.5) to ensure we sample the center of the pixel
-            let uv_rect = UvRect {
-                min: glm::vec2(current_x as f32 / atlas_width as f32, 0.0),
-                max: glm::vec2((current_x + metrics.width) as f32 / atlas_width as f32, metrics.height as f32 / atlas_height as f32),
-            };
-
-            let offset_y = metrics.height as f32 + metrics.ymin as f32;
-
-            glyphs.insert(c, Glyph {
-                uv_rect,
-                width: metrics.width as f32,
-                height: metrics.height as f32,
-                advance: metrics.advance_width,
-                offset_x: metrics.xmin as f32,
-                // 4. Correct vertical offset for Top-Left coordinate systems
-                offset_y,
-            });
-
-            current_x += metrics.width + 1;
-        }
+        Self::from_ttf_bytes_ranges(bytes, size, &[ASCII_PRINTABLE])
+    }
 
-        let texture = Texture::from_bytes(&atlas_pixels, atlas_width as u32, atlas_height as u32);
+    /// Rasterizes every character in `ranges` (Unicode code points) that the font provides.
+    /// Characters outside the ranges, or missing from the font, render as a hollow box.
+    pub fn from_ttf_bytes_ranges(bytes: &[u8], size: f32, ranges: &[RangeInclusive<u32>]) -> Self {
+        let atlas = FontAtlas::rasterize(bytes, size, ranges);
+        let texture = Texture::from_bytes(&atlas.pixels, atlas.width as u32, atlas.height as u32);
 
         Self {
             texture,
-            glyphs,
+            glyphs: atlas.glyphs,
+            fallback: Some(atlas.fallback),
             line_height: size,
+            ascent: atlas.ascent,
+            kerning: atlas.kerning,
+            letter_spacing: 1.0,
+            line_spacing: 1.0,
         }
     }
 
@@ -117,6 +97,12 @@ impl Font {
         self.glyphs.get(&c)
     }
 
+    /// Returns the glyph to draw for `c`: its own, or the fallback box for printable characters
+    /// that weren't rasterized. Control characters without a glyph draw nothing.
+    pub fn glyph_or_fallback(&self, c: char) -> Option<&Glyph> {
+        self.glyphs.get(&c).or_else(|| if c.is_control() { None } else { self.fallback.as_ref() })
+    }
+
     /// Generates a GPU mesh for the given text string.
     pub fn generate_mesh(&self, text: &str) -> GpuMesh {
         GpuMesh::from_vertices(&self.create_vertices(text))
@@ -127,42 +113,291 @@ impl Font {
         mesh.update_vertices(&self.create_vertices(text));
     }
 
-    fn create_vertices(&self, text: &str) -> Vec<VertexPosUv> {
+    /// Generates a GPU mesh for the text, word-wrapped to `max_width` pixels (see [`Self::wrap_text`]).
+    pub fn generate_mesh_wrapped(&self, text: &str, max_width: f32) -> GpuMesh {
+        GpuMesh::from_vertices(&self.create_vertices(&self.wrap_text(text, max_width)))
+    }
+
+    /// Updates an existing mesh with word-wrapped text vertices.
+    pub fn update_mesh_wrapped(&self, mesh: &mut GpuMesh, text: &str, max_width: f32) {
+        mesh.update_vertices(&self.create_vertices(&self.wrap_text(text, max_width)));
+    }
+
+    /// Inserts line breaks so no line's advance exceeds `max_width`. Breaks at spaces (the space
+    /// is dropped); words wider than `max_width` are hard-broken between characters. Existing
+    /// `\n`s are kept.
+    pub fn wrap_text(&self, text: &str, max_width: f32) -> String {
+        let advance = |c: char| self.glyph_or_fallback(c).map_or(0.0, |g| g.advance * self.letter_spacing);
+        let space_width = advance(' ');
+        let mut lines = Vec::new();
+
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+            let mut line_width = 0.0;
+
+            for word in paragraph.split(' ') {
+                let word_width = self.measure(word).0;
+                if !line.is_empty() && line_width + space_width + word_width > max_width {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0.0;
+                } else if !line.is_empty() {
+                    line.push(' ');
+                    line_width += space_width;
+                }
+
+                if word_width <= max_width {
+                    line.push_str(word);
+                    line_width += word_width;
+                    continue;
+                }
+                // Hard-break a word that can't fit on any line
+                for c in word.chars() {
+                    let char_width = advance(c);
+                    if !line.is_empty() && line_width + char_width > max_width {
+                        lines.push(std::mem::take(&mut line));
+                        line_width = 0.0;
+                    }
+                    line.push(c);
+                    line_width += char_width;
+                }
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Returns the `(width, height)` in pixels the text would occupy, without building a mesh:
+    /// the widest line's advance (with kerning and `letter_spacing`) and `line_height * line_spacing`
+    /// per line (including lines broken by `\n`).
+    pub fn measure(&self, text: &str) -> (f32, f32) {
+        self.layout(text, |_, _, _| {})
+    }
+
+    pub(crate) fn create_vertices(&self, text: &str) -> Vec<VertexPosUv> {
         let mut vertices = Vec::with_capacity(text.len() * 6);
-        let mut cursor_x = 0.0;
+        self.layout(text, |glyph, cursor_x, line_top| {
+            // Y grows downwards: the glyph top sits `offset_y` above the shared baseline
+            let baseline = line_top + self.ascent;
+            let x0 = cursor_x + glyph.offset_x;
+            let y0 = baseline - glyph.offset_y;
+            let x1 = x0 + glyph.width;
+            let y1 = y0 + glyph.height;
+
+            let u0 = glyph.uv_rect.min.x;
+            let v0 = glyph.uv_rect.min.y;
+            let u1 = glyph.uv_rect.max.x;
+            let v1 = glyph.uv_rect.max.y;
+
+            // Two triangles (6 vertices)
+            vertices.extend_from_slice(&[
+                VertexPosUv { position: [x0, y0, 0.0], uv: [u0, v0] },
+                VertexPosUv { position: [x1, y0, 0.0], uv: [u1, v0] },
+                VertexPosUv { position: [x1, y1, 0.0], uv: [u1, v1] },
+                VertexPosUv { position: [x1, y1, 0.0], uv: [u1, v1] },
+                VertexPosUv { position: [x0, y1, 0.0], uv: [u0, v1] },
+                VertexPosUv { position: [x0, y0, 0.0], uv: [u0, v0] },
+            ]);
+        });
+        vertices
+    }
+
+    /// Walks the text, calling `emit(glyph, cursor_x, line_top)` for every drawn glyph, and
+    /// returns the laid-out `(width, height)`.
+    fn layout(&self, text: &str, mut emit: impl FnMut(&Glyph, f32, f32)) -> (f32, f32) {
+        let line_advance = self.line_height * self.line_spacing;
+        let mut cursor_x: f32 = 0.0;
         let mut cursor_y = 0.0;
+        let mut width: f32 = 0.0;
+        let mut previous = None;
 
         for c in text.chars() {
             if c == '\n' {
+                width = width.max(cursor_x);
                 cursor_x = 0.0;
-                cursor_y += self.line_height;
+                cursor_y += line_advance;
+                previous = None;
                 continue;
             }
 
-            if let Some(glyph) = self.get_glyph(c) {
-                let x0 = cursor_x + glyph.offset_x;
-                let y0 = cursor_y - glyph.offset_y;
-                let x1 = x0 + glyph.width;
-                let y1 = y0 + glyph.height;
-
-                let u0 = glyph.uv_rect.min.x;
-                let v0 = glyph.uv_rect.min.y;
-                let u1 = glyph.uv_rect.max.x;
-                let v1 = glyph.uv_rect.max.y;
-
-                // Two triangles (6 vertices)
-                vertices.extend_from_slice(&[
-                    VertexPosUv { position: [x0, y0, 0.0], uv: [u0, v0] },
-                    VertexPosUv { position: [x1, y0, 0.0], uv: [u1, v0] },
-                    VertexPosUv { position: [x1, y1, 0.0], uv: [u1, v1] },
-                    VertexPosUv { position: [x1, y1, 0.0], uv: [u1, v1] },
-                    VertexPosUv { position: [x0, y1, 0.0], uv: [u0, v1] },
-                    VertexPosUv { position: [x0, y0, 0.0], uv: [u0, v0] },
-                ]);
-
-                cursor_x += glyph.advance;
+            if let Some(glyph) = self.glyph_or_fallback(c) {
+                if let Some(kern) = previous.and_then(|p| self.kerning.get(&(p, c))) {
+                    cursor_x += kern;
+                }
+                emit(glyph, cursor_x, cursor_y);
+                cursor_x += glyph.advance * self.letter_spacing;
+                previous = Some(c);
             }
         }
-        vertices
+
+        if text.is_empty() {
+            return (0.0, 0.0);
+        }
+        (width.max(cursor_x), cursor_y + line_advance)
+    }
+}
+
+/// Atlas rows wrap at this width, keeping large character sets well under `GL_MAX_TEXTURE_SIZE`.
+const MAX_ATLAS_WIDTH: usize = 1024;
+
+/// Result of [`pack_shelves`]: top-left position per rect and the atlas size.
+pub(crate) struct ShelfPacking {
+    pub(crate) positions: Vec<(usize, usize)>,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+}
+
+/// Packs `(width, height)` rects into rows ("shelves") no wider than `max_width`, tallest first,
+/// leaving 1px of padding right of and below every rect. Positions are returned in input order.
+pub(crate) fn pack_shelves(sizes: &[(usize, usize)], max_width: usize) -> ShelfPacking {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|&a, &b| sizes[b].1.cmp(&sizes[a].1));
+
+    let mut positions = vec![(0, 0); sizes.len()];
+    let (mut x, mut shelf_y, mut shelf_height) = (0, 0, 0);
+    let mut width = 0;
+    for i in order {
+        let (w, h) = sizes[i];
+        // Start a new shelf when this rect doesn't fit (a lone over-wide rect gets its own row)
+        if x > 0 && x + w + 1 > max_width {
+            shelf_y += shelf_height;
+            x = 0;
+            shelf_height = 0;
+        }
+        positions[i] = (x, shelf_y);
+        x += w + 1;
+        width = width.max(x);
+        shelf_height = shelf_height.max(h + 1);
     }
+
+    ShelfPacking { positions, width: width.max(1), height: (shelf_y + shelf_height).max(1) }
+}
+
+/// CPU-side single-channel glyph atlas, before upload.
+pub(crate) struct FontAtlas {
+    pub(crate) pixels: Vec<u8>,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) glyphs: HashMap<char, Glyph>,
+    pub(crate) fallback: Glyph,
+    pub(crate) kerning: HashMap<(char, char), f32>,
+    pub(crate) ascent: f32,
+}
+
+impl FontAtlas {
+    /// Rasterizes the characters in `ranges` that the font has, plus a fallback box, into one atlas.
+    pub(crate) fn rasterize(bytes: &[u8], size: f32, ranges: &[RangeInclusive<u32>]) -> Self {
+        let font = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default()).unwrap();
+
+        // Skip code points that aren't chars (surrogates) or that the font lacks (glyph 0)
+        let chars: Vec<char> = ranges
+            .iter()
+            .flat_map(|range| range.clone())
+            .filter_map(std::char::from_u32)
+            .filter(|&c| font.lookup_glyph_index(c) != 0)
+            .collect();
+
+        let mut bitmaps: Vec<(fontdue::Metrics, Vec<u8>)> = chars.iter().map(|&c| font.rasterize(c, size)).collect();
+        let tofu = tofu_bitmap(size);
+        bitmaps.push(tofu);
+
+        // 1. Pack glyph rects into shelves (with 1px padding to prevent bleeding)
+        let sizes: Vec<(usize, usize)> = bitmaps.iter().map(|(m, _)| (m.width, m.height)).collect();
+        let packing = pack_shelves(&sizes, MAX_ATLAS_WIDTH);
+        let (atlas_width, atlas_height) = (packing.width, packing.height);
+
+        let mut atlas_pixels = vec![0u8; atlas_width * atlas_height];
+        let mut placed = Vec::with_capacity(bitmaps.len());
+
+        // 2. Copy into the atlas
+        for ((metrics, bitmap), &(glyph_x, glyph_y)) in bitmaps.iter().zip(&packing.positions) {
+            for y in 0..metrics.height {
+                let dest = (glyph_y + y) * atlas_width + glyph_x;
+                atlas_pixels[dest..dest + metrics.width]
+                    .copy_from_slice(&bitmap[y * metrics.width..(y + 1) * metrics.width]);
+            }
+
+            // 3. UV Mapping
+            let uv_rect = UvRect {
+                min: glm::vec2(glyph_x as f32 / atlas_width as f32, glyph_y as f32 / atlas_height as f32),
+                max: glm::vec2(
+                    (glyph_x + metrics.width) as f32 / atlas_width as f32,
+                    (glyph_y + metrics.height) as f32 / atlas_height as f32,
+                ),
+            };
+
+            placed.push(Glyph {
+                uv_rect,
+                width: metrics.width as f32,
+                height: metrics.height as f32,
+                advance: metrics.advance_width,
+                offset_x: metrics.xmin as f32,
+                // 4. Correct vertical offset for Top-Left coordinate systems
+                offset_y: metrics.height as f32 + metrics.ymin as f32,
+            });
+        }
+
+        let fallback = placed.pop().unwrap();
+        let kerning = kerning_pairs(&font, &chars, size);
+        // Fall back to the tallest glyph top if the font has no horizontal line metrics
+        let ascent = font.horizontal_line_metrics(size).map_or_else(
+            || placed.iter().map(|g| g.offset_y).fold(0.0, f32::max),
+            |line| line.ascent,
+        );
+        Self {
+            pixels: atlas_pixels,
+            width: atlas_width,
+            height: atlas_height,
+            glyphs: chars.into_iter().zip(placed).collect(),
+            fallback,
+            kerning,
+            ascent,
+        }
+    }
+}
+
+/// Characters below this code point (Latin, Latin-1, Latin Extended) get kerning pairs; the
+/// pair table grows quadratically, so large scripts are left unkerned.
+const KERNING_MAX_CODE_POINT: u32 = 0x250;
+
+/// Collects the font's non-zero horizontal kerning between every pair of kernable `chars`.
+fn kerning_pairs(font: &fontdue::Font, chars: &[char], size: f32) -> HashMap<(char, char), f32> {
+    let kernable: Vec<char> = chars.iter().copied().filter(|&c| (c as u32) < KERNING_MAX_CODE_POINT).collect();
+    let mut pairs = HashMap::new();
+    for &left in &kernable {
+        for &right in &kernable {
+            if let Some(kern) = font.horizontal_kern(left, right, size) {
+                if kern != 0.0 {
+                    pairs.insert((left, right), kern);
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// A hollow rectangle roughly the size of a capital letter, drawn for missing characters.
+fn tofu_bitmap(size: f32) -> (fontdue::Metrics, Vec<u8>) {
+    let width = ((size * 0.5).round() as usize).max(3);
+    let height = ((size * 0.7).round() as usize).max(3);
+    let stroke = ((size / 16.0).round() as usize).clamp(1, width / 3);
+
+    let mut bitmap = vec![0u8; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            if x < stroke || y < stroke || x >= width - stroke || y >= height - stroke {
+                bitmap[y * width + x] = 255;
+            }
+        }
+    }
+
+    let metrics = fontdue::Metrics {
+        xmin: stroke as i32,
+        ymin: 0,
+        width,
+        height,
+        advance_width: (width + 2 * stroke) as f32,
+        advance_height: 0.0,
+        bounds: fontdue::OutlineBounds { xmin: stroke as f32, ymin: 0.0, width: width as f32, height: height as f32 },
+    };
+    (metrics, bitmap)
 }
@@ -1,5 +1,5 @@
 use gl::types::*;
-use crate::graphics::vertex::Vertex;
+use crate::graphics::vertex::{Vertex, VertexLayout};
 
 // Re-export GL draw mode constants so downstream crates don't need the `gl` crate.
 pub const DRAW_TRIANGLES: u32 = gl::TRIANGLES;
@@ -10,7 +10,16 @@ pub const DRAW_POINTS: u32 = gl::POINTS;
 pub struct GpuMesh {
     vao: GLuint,
     vbo: GLuint,
+    /// Element buffer, 0 for non-indexed meshes.
+    ebo: GLuint,
     vertex_count: i32,
+    /// Number of vertices the VBO has storage for.
+    capacity: usize,
+    index_count: i32,
+    /// Whether `draw` uses `glDrawElements` instead of `glDrawArrays`.
+    indexed: bool,
+    /// Per-instance attribute buffer, 0 if none.
+    instance_vbo: GLuint,
     draw_mode: u32,
 }
 
@@ -21,7 +30,12 @@ impl GpuMesh {
             return Self {
                 vao: 0,
                 vbo: 0,
+                ebo: 0,
                 vertex_count: 0,
+                capacity: 0,
+                index_count: 0,
+                indexed: false,
+                instance_vbo: 0,
                 draw_mode: gl::TRIANGLES,
             };
         }
@@ -45,27 +59,7 @@ impl GpuMesh {
                 gl::STATIC_DRAW,
             );
 
-            for attr in layout.attributes {
-                gl::EnableVertexAttribArray(attr.location);
-                if attr.is_integer {
-                    gl::VertexAttribIPointer(
-                        attr.location,
-                        attr.size,
-                        attr.gl_type,
-                        layout.stride as i32,
-                        attr.offset as *const _,
-                    );
-                } else {
-                    gl::VertexAttribPointer(
-                        attr.location,
-                        attr.size,
-                        attr.gl_type,
-                        attr.normalized as u8,
-                        layout.stride as i32,
-                        attr.offset as *const _,
-                    );
-                }
-            }
+            set_attributes(&layout, 0);
 
             gl::BindVertexArray(0);
         }
@@ -73,11 +67,43 @@ impl GpuMesh {
         Self {
             vao,
             vbo,
+            ebo: 0,
             vertex_count: vertices.len() as i32,
+            capacity: vertices.len(),
+            index_count: 0,
+            indexed: false,
+            instance_vbo: 0,
             draw_mode: gl::TRIANGLES,
         }
     }
 
+    /// Uploads shared vertices plus a `u32` index buffer (EBO), drawn with `glDrawElements`.
+    /// Lets quads reuse their 4 corners instead of duplicating vertices per triangle.
+    pub fn from_vertices_indexed<V: Vertex>(vertices: &[V], indices: &[u32]) -> Self {
+        let mut mesh = Self::from_vertices(vertices);
+        mesh.indexed = true;
+        if vertices.is_empty() || indices.is_empty() {
+            return mesh;
+        }
+
+        unsafe {
+            gl::BindVertexArray(mesh.vao);
+            gl::GenBuffers(1, &mut mesh.ebo);
+            // The element buffer binding is stored in the VAO
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, mesh.ebo);
+            gl::BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                std::mem::size_of_val(indices) as isize,
+                indices.as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+            gl::BindVertexArray(0);
+        }
+
+        mesh.index_count = indices.len() as i32;
+        mesh
+    }
+
     /// Sets the OpenGL draw mode (e.g. `gl::LINES`, `gl::TRIANGLES`).
     pub fn with_draw_mode(mut self, mode: u32) -> Self {
         self.draw_mode = mode;
@@ -85,11 +111,13 @@ impl GpuMesh {
     }
 
     /// Re-uploads vertex data to the existing VBO, replacing the previous contents.
+    /// Reallocates the buffer, so this is also how a mesh grows past [`Self::capacity`].
     pub fn update_vertices<V: Vertex>(&mut self, vertices: &[V]) {
         self.vertex_count = vertices.len() as i32;
         if vertices.is_empty() {
             return;
         }
+        self.capacity = vertices.len();
         unsafe {
             gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
             gl::BufferData(
@@ -101,15 +129,138 @@ impl GpuMesh {
         }
     }
 
-    /// Issues a `glDrawArrays` call for this mesh.
+    /// Overwrites `vertices.len()` vertices starting at vertex `offset` with `glBufferSubData`,
+    /// without reallocating or changing the vertex count.
+    ///
+    /// # Panics
+    /// If the range extends past [`Self::capacity`]; grow the mesh with [`Self::update_vertices`].
+    pub fn update_sub<V: Vertex>(&mut self, offset: usize, vertices: &[V]) {
+        let end = offset + vertices.len();
+        assert!(
+            end <= self.capacity,
+            "update_sub range {offset}..{end} exceeds mesh capacity {}",
+            self.capacity
+        );
+        if vertices.is_empty() {
+            return;
+        }
+        let stride = V::layout().stride;
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferSubData(
+                gl::ARRAY_BUFFER,
+                (offset * stride) as isize,
+                (vertices.len() * stride) as isize,
+                vertices.as_ptr() as *const _,
+            );
+        }
+    }
+
+    /// Number of vertices drawn (as of the last upload).
+    pub fn vertex_count(&self) -> i32 {
+        self.vertex_count
+    }
+
+    /// Number of vertices one draw of this mesh processes: the index count for indexed
+    /// meshes, otherwise [`Self::vertex_count`].
+    pub fn element_count(&self) -> i32 {
+        if self.indexed { self.index_count } else { self.vertex_count }
+    }
+
+    /// Returns `true` if drawing this mesh would emit nothing, so it can be culled before submission.
+    pub fn is_empty(&self) -> bool {
+        self.vertex_count == 0 || (self.indexed && self.index_count == 0)
+    }
+
+    /// The OpenGL draw mode (e.g. [`DRAW_TRIANGLES`]).
+    pub fn draw_mode(&self) -> u32 {
+        self.draw_mode
+    }
+
+    /// Number of vertices the GPU buffer can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Uploads per-instance data (e.g. offsets, colors) and binds its attributes with divisor 1,
+    /// replacing any previous instance buffer. `I`'s attribute locations must not overlap the
+    /// mesh vertex layout. Draw with [`Self::draw_instanced`].
+    pub fn set_instance_buffer<I: Vertex>(&mut self, data: &[I]) {
+        if self.vao == 0 {
+            return;
+        }
+        let layout = I::layout();
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            if self.instance_vbo == 0 {
+                gl::GenBuffers(1, &mut self.instance_vbo);
+            }
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.instance_vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (data.len() * layout.stride) as isize,
+                data.as_ptr() as *const _,
+                gl::DYNAMIC_DRAW,
+            );
+            set_attributes(&layout, 1);
+            gl::BindVertexArray(0);
+        }
+    }
+
+    /// Draws `count` instances of this mesh in one call (`glDrawArraysInstanced`, or
+    /// `glDrawElementsInstanced` for indexed meshes).
+    pub fn draw_instanced(&self, count: i32) {
+        if count <= 0 || self.is_empty() {
+            return;
+        }
+        self.bind();
+        self.draw_instanced_no_bind(count);
+    }
+
+    /// Like [`Self::draw_instanced`], but assumes this mesh's VAO is already bound.
+    pub fn draw_instanced_no_bind(&self, count: i32) {
+        if count <= 0 || self.is_empty() {
+            return;
+        }
+
+        unsafe {
+            if self.indexed {
+                gl::DrawElementsInstanced(self.draw_mode, self.index_count, gl::UNSIGNED_INT, std::ptr::null(), count);
+            } else {
+                gl::DrawArraysInstanced(self.draw_mode, 0, self.vertex_count, count);
+            }
+        }
+    }
+
+    /// Issues a `glDrawElements` call for indexed meshes, `glDrawArrays` otherwise.
     pub fn draw(&self) {
-        if self.vertex_count == 0 {
+        if self.is_empty() {
+            return;
+        }
+        self.bind();
+        self.draw_no_bind();
+    }
+
+    /// Like [`Self::draw`], but assumes this mesh's VAO is already bound (see [`Self::bind`]),
+    /// so consecutive draws of the same mesh skip the redundant `glBindVertexArray`.
+    pub fn draw_no_bind(&self) {
+        if self.is_empty() {
             return;
         }
 
+        unsafe {
+            if self.indexed {
+                gl::DrawElements(self.draw_mode, self.index_count, gl::UNSIGNED_INT, std::ptr::null());
+            } else {
+                gl::DrawArrays(self.draw_mode, 0, self.vertex_count);
+            }
+        }
+    }
+
+    /// Binds this mesh's VAO for the `*_no_bind` draw calls.
+    pub fn bind(&self) {
         unsafe {
             gl::BindVertexArray(self.vao);
-            gl::DrawArrays(self.draw_mode, 0, self.vertex_count);
         }
     }
 }
@@ -121,9 +272,42 @@ impl Drop for GpuMesh {
             if self.vbo != 0 {
                 gl::DeleteBuffers(1, &self.vbo);
             }
+            if self.ebo != 0 {
+                gl::DeleteBuffers(1, &self.ebo);
+            }
+            if self.instance_vbo != 0 {
+                gl::DeleteBuffers(1, &self.instance_vbo);
+            }
             if self.vao != 0 {
                 gl::DeleteVertexArrays(1, &self.vao);
             }
         }
     }
 }
+
+/// Enables and describes every attribute in `layout` for the bound VAO and `ARRAY_BUFFER`,
+/// advancing once per `divisor` instances (0 = per vertex).
+unsafe fn set_attributes(layout: &VertexLayout, divisor: u32) {
+    for attr in layout.attributes {
+        gl::EnableVertexAttribArray(attr.location);
+        if attr.is_integer {
+            gl::VertexAttribIPointer(
+                attr.location,
+                attr.size,
+                attr.gl_type,
+                layout.stride as i32,
+                attr.offset as *const _,
+            );
+        } else {
+            gl::VertexAttribPointer(
+                attr.location,
+                attr.size,
+                attr.gl_type,
+                attr.normalized as u8,
+                layout.stride as i32,
+                attr.offset as *const _,
+            );
+        }
+        gl::VertexAttribDivisor(attr.location, divisor);
+    }
+}
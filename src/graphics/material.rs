@@ -1,10 +1,12 @@
 use crate::core::handle::Handle;
+use crate::graphics::render_state::RenderState;
 use crate::graphics::shader::Shader;
 use crate::graphics::texture::texture::Texture;
 use crate::graphics::texture::texture_3d::Texture3D;
 use crate::graphics::texture::texture_array::TextureArray;
 
 /// Specifies which texture type is bound to a material slot.
+#[derive(PartialEq)]
 pub enum TextureBinding {
     /// A single 2D texture.
     Texture2D(Handle<Texture>),
@@ -15,6 +17,7 @@ pub enum TextureBinding {
 }
 
 /// A texture binding assigned to a numbered slot with a shader uniform name.
+#[derive(PartialEq)]
 pub struct TextureSlot {
     /// GL texture unit index (0, 1, 2, ...).
     pub slot: u32,
@@ -30,6 +33,9 @@ pub struct Material {
     pub shader: Handle<Shader>,
     /// Texture slots bound when this material is active.
     pub textures: Vec<TextureSlot>,
+    /// Blend, depth and cull state to draw with; `None` uses the state of the pass
+    /// the material is drawn in (see [`RenderState::OPAQUE`] and friends).
+    pub render_state: Option<RenderState>,
 }
 
 impl Material {
@@ -38,6 +44,7 @@ impl Material {
         Self {
             shader,
             textures: Vec::new(),
+            render_state: None,
         }
     }
 
@@ -46,4 +53,10 @@ impl Material {
         self.textures.push(TextureSlot { slot, uniform_name, binding });
         self
     }
+
+    /// Overrides the pass's render state for this material (builder pattern).
+    pub fn with_render_state(mut self, state: RenderState) -> Self {
+        self.render_state = Some(state);
+        self
+    }
 }
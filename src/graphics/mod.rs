@@ -2,6 +2,7 @@ pub mod gpu_mesh;
 pub mod shader;
 pub mod texture;
 pub mod material;
+pub mod render_state;
 pub mod uv_rect;
 pub mod gui_material;
 pub mod font;
@@ -0,0 +1,139 @@
+/// How a draw's color output is combined with the framebuffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Blending disabled; the fragment replaces the framebuffer color.
+    #[default]
+    Opaque,
+    /// Standard alpha blending (`src * a + dst * (1 - a)`).
+    Alpha,
+    /// Additive blending weighted by alpha (`src * a + dst`), e.g. particles and glow.
+    Additive,
+    /// Alpha blending for colors already multiplied by alpha (`src + dst * (1 - a)`).
+    Premultiplied,
+}
+
+impl BlendMode {
+    /// Returns the `(src, dst)` GL blend factors, or `None` when blending is disabled.
+    pub fn gl_factors(self) -> Option<(u32, u32)> {
+        match self {
+            BlendMode::Opaque => None,
+            BlendMode::Alpha => Some((gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA)),
+            BlendMode::Additive => Some((gl::SRC_ALPHA, gl::ONE)),
+            BlendMode::Premultiplied => Some((gl::ONE, gl::ONE_MINUS_SRC_ALPHA)),
+        }
+    }
+}
+
+/// Which triangle faces are discarded before rasterization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CullMode {
+    /// Both faces are drawn.
+    #[default]
+    None,
+    /// Back faces (clockwise winding) are discarded.
+    Back,
+    /// Front faces (counter-clockwise winding) are discarded.
+    Front,
+}
+
+/// Fixed-function GL state a material draws with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderState {
+    /// Color blending mode.
+    pub blend: BlendMode,
+    /// Whether fragments are tested against the depth buffer.
+    pub depth_test: bool,
+    /// Whether fragments write to the depth buffer.
+    pub depth_write: bool,
+    /// Face culling mode.
+    pub cull: CullMode,
+}
+
+impl RenderState {
+    /// Default state of the opaque pass: no blending, depth test and writes on.
+    pub const OPAQUE: Self = Self {
+        blend: BlendMode::Opaque,
+        depth_test: true,
+        depth_write: true,
+        cull: CullMode::None,
+    };
+
+    /// Default state of the sorted transparent pass: alpha blending, depth test on,
+    /// depth writes off to avoid transparent-on-transparent occlusion.
+    pub const TRANSPARENT: Self = Self {
+        blend: BlendMode::Alpha,
+        depth_test: true,
+        depth_write: false,
+        cull: CullMode::None,
+    };
+
+    /// Default state of the GUI pass: alpha blending, no depth test.
+    pub const GUI: Self = Self {
+        blend: BlendMode::Alpha,
+        depth_test: false,
+        depth_write: true,
+        cull: CullMode::None,
+    };
+
+    /// Sets the blend mode (builder pattern).
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Enables or disables the depth test (builder pattern).
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.depth_test = depth_test;
+        self
+    }
+
+    /// Enables or disables depth writes (builder pattern).
+    pub fn with_depth_write(mut self, depth_write: bool) -> Self {
+        self.depth_write = depth_write;
+        self
+    }
+
+    /// Sets the face culling mode (builder pattern).
+    pub fn with_cull(mut self, cull: CullMode) -> Self {
+        self.cull = cull;
+        self
+    }
+
+    /// Sets all four pieces of GL state.
+    pub(crate) fn apply(&self) {
+        unsafe {
+            match self.blend.gl_factors() {
+                Some((src, dst)) => {
+                    gl::Enable(gl::BLEND);
+                    gl::BlendFunc(src, dst);
+                }
+                None => gl::Disable(gl::BLEND),
+            }
+
+            if self.depth_test {
+                gl::Enable(gl::DEPTH_TEST);
+            } else {
+                gl::Disable(gl::DEPTH_TEST);
+            }
+            gl::DepthMask(if self.depth_write { gl::TRUE } else { gl::FALSE });
+
+            match self.cull {
+                CullMode::None => gl::Disable(gl::CULL_FACE),
+                CullMode::Back => {
+                    gl::Enable(gl::CULL_FACE);
+                    gl::CullFace(gl::BACK);
+                }
+                CullMode::Front => {
+                    gl::Enable(gl::CULL_FACE);
+                    gl::CullFace(gl::FRONT);
+                }
+            }
+        }
+    }
+}
+
+impl Default for RenderState {
+    fn default() -> Self {
+        Self::OPAQUE
+    }
+}
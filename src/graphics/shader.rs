@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::ptr;
 use std::ffi::CString;
@@ -9,44 +9,176 @@ use nalgebra_glm as glm;
 pub struct Shader {
     pub(crate) id: u32,
     uniform_cache: RefCell<HashMap<String, i32>>,
+    warn_missing: bool,
+    warned_missing: RefCell<HashSet<String>>,
 }
 
+/// Error returned by the fallible shader constructors.
+#[derive(Debug)]
+pub enum ShaderError {
+    /// A shader source file could not be read.
+    Io(std::io::Error),
+    /// A stage failed to compile; `stage` is the GL shader kind (e.g. `gl::FRAGMENT_SHADER`).
+    Compile { stage: u32, log: String },
+    /// The program failed to link.
+    Link(String),
+}
+
+impl std::fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderError::Io(e) => write!(f, "Failed to read shader source: {e}"),
+            ShaderError::Compile { log, .. } => write!(f, "Shader compilation failed:\n{log}"),
+            ShaderError::Link(log) => write!(f, "Program linking failed:\n{log}"),
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
 impl Shader {
     /// Compiles vertex and fragment shaders from file paths and links them into a program.
+    /// Panics on failure; see [`Self::try_new`].
     pub fn new(vertex_path: &str, fragment_path: &str) -> Self {
-        let vertex_code =
-            fs::read_to_string(vertex_path).expect("Failed to read vertex shader");
-        let fragment_code =
-            fs::read_to_string(fragment_path).expect("Failed to read fragment shader");
-        Self::from_source(&vertex_code, &fragment_code)
+        Self::try_new(vertex_path, fragment_path).unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Compiles vertex and fragment shaders from GLSL source strings and links them into a program.
+    /// Panics on failure; see [`Self::try_from_source`].
     pub fn from_source(vertex_src: &str, fragment_src: &str) -> Self {
+        Self::try_from_source(vertex_src, fragment_src).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Self::new`], but returns read, compile, and link failures (with the info log)
+    /// instead of panicking, e.g. to keep the last working shader during hot reload.
+    pub fn try_new(vertex_path: &str, fragment_path: &str) -> Result<Self, ShaderError> {
+        let vertex_code = fs::read_to_string(vertex_path).map_err(ShaderError::Io)?;
+        let fragment_code = fs::read_to_string(fragment_path).map_err(ShaderError::Io)?;
+        Self::try_from_source(&vertex_code, &fragment_code)
+    }
+
+    /// Like [`Self::from_source`], but returns compile and link failures instead of panicking.
+    pub fn try_from_source(vertex_src: &str, fragment_src: &str) -> Result<Self, ShaderError> {
+        Self::link(&[(vertex_src, gl::VERTEX_SHADER), (fragment_src, gl::FRAGMENT_SHADER)])
+    }
+
+    /// Compiles vertex, geometry, and fragment shaders from GLSL source and links them into a
+    /// program, e.g. for point-sprite expansion. Panics on failure.
+    pub fn from_source_with_geometry(vertex_src: &str, geometry_src: &str, fragment_src: &str) -> Self {
+        Self::try_from_source_with_geometry(vertex_src, geometry_src, fragment_src)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Self::from_source_with_geometry`], but returns failures instead of panicking.
+    pub fn try_from_source_with_geometry(
+        vertex_src: &str,
+        geometry_src: &str,
+        fragment_src: &str,
+    ) -> Result<Self, ShaderError> {
+        Self::link(&[
+            (vertex_src, gl::VERTEX_SHADER),
+            (geometry_src, gl::GEOMETRY_SHADER),
+            (fragment_src, gl::FRAGMENT_SHADER),
+        ])
+    }
+
+    /// Compiles a compute shader from GLSL source into its own program. Run it with [`Self::dispatch`].
+    /// Panics on failure.
+    pub fn compute_from_source(compute_src: &str) -> Self {
+        Self::try_compute_from_source(compute_src).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Self::compute_from_source`], but returns failures instead of panicking.
+    pub fn try_compute_from_source(compute_src: &str) -> Result<Self, ShaderError> {
+        Self::link(&[(compute_src, gl::COMPUTE_SHADER)])
+    }
+
+    /// Binds this (compute) program and launches `x * y * z` work groups. Issue a
+    /// `glMemoryBarrier` before reading what the shader wrote.
+    pub fn dispatch(&self, x: u32, y: u32, z: u32) {
+        self.use_program();
+        unsafe { gl::DispatchCompute(x, y, z) };
+    }
+
+    /// Compiles each `(source, kind)` stage and links them into a program, cleaning up every
+    /// GL object created on failure.
+    fn link(stages: &[(&str, u32)]) -> Result<Self, ShaderError> {
         unsafe {
-            let vertex = compile_shader(vertex_src, gl::VERTEX_SHADER);
-            let fragment = compile_shader(fragment_src, gl::FRAGMENT_SHADER);
+            let mut shaders = Vec::with_capacity(stages.len());
+            for &(source, kind) in stages {
+                match compile_shader(source, kind) {
+                    Ok(shader) => shaders.push(shader),
+                    Err(e) => {
+                        for shader in shaders {
+                            gl::DeleteShader(shader);
+                        }
+                        return Err(e);
+                    }
+                }
+            }
 
             let id = gl::CreateProgram();
-            gl::AttachShader(id, vertex);
-            gl::AttachShader(id, fragment);
+            for &shader in &shaders {
+                gl::AttachShader(id, shader);
+            }
             gl::LinkProgram(id);
-            check_program_link_errors(id);
+            let linked = check_program_link_errors(id);
 
-            gl::DeleteShader(vertex);
-            gl::DeleteShader(fragment);
+            for shader in shaders {
+                gl::DeleteShader(shader);
+            }
 
-            Self { id, uniform_cache: RefCell::new(HashMap::new()) }
+            if let Err(e) = linked {
+                gl::DeleteProgram(id);
+                return Err(e);
+            }
+
+            Ok(Self {
+                id,
+                uniform_cache: RefCell::new(HashMap::new()),
+                warn_missing: false,
+                warned_missing: RefCell::new(HashSet::new()),
+            })
         }
     }
 
+    /// Recompiles and relinks from file paths in place, for hot reload. On success the old
+    /// program is deleted and the uniform cache cleared; on failure this shader is left untouched,
+    /// so handles and materials referring to it keep working with the last good program.
+    pub fn reload(&mut self, vertex_path: &str, fragment_path: &str) -> Result<(), ShaderError> {
+        let mut reloaded = Self::try_new(vertex_path, fragment_path)?;
+        reloaded.warn_missing = self.warn_missing;
+        // Dropping the replaced value deletes the old program
+        *self = reloaded;
+        Ok(())
+    }
+
     /// Binds this shader program for subsequent draw calls.
     #[inline]
     pub fn use_program(&self) {
         unsafe { gl::UseProgram(self.id) };
     }
 
+    /// Enables a debug warning on stderr (once per name) when a setter targets a uniform that
+    /// doesn't exist or was optimized out. Off by default.
+    pub fn set_warn_missing(&mut self, warn: bool) {
+        self.warn_missing = warn;
+    }
+
+    /// Returns `true` if the program has an active uniform named `name`. Never warns.
+    pub fn has_uniform(&self, name: &str) -> bool {
+        self.cached_location(name) >= 0
+    }
+
     fn uniform_location(&self, name: &str) -> i32 {
+        let loc = self.cached_location(name);
+        if loc < 0 && self.warn_missing && self.warned_missing.borrow_mut().insert(name.to_string()) {
+            eprintln!("Shader {}: uniform `{}` not found (misspelled or optimized out)", self.id, name);
+        }
+        loc
+    }
+
+    fn cached_location(&self, name: &str) -> i32 {
         if let Some(&loc) = self.uniform_cache.borrow().get(name) {
             return loc;
         }
@@ -119,11 +251,58 @@ impl Shader {
             gl::Uniform1i(self.uniform_location(name), value);
         }
     }
+
+    /// Sets a `uint` uniform.
+    pub fn set_uint(&self, name: &str, value: u32) {
+        unsafe {
+            gl::Uniform1ui(self.uniform_location(name), value);
+        }
+    }
+
+    /// Sets an `ivec3` uniform.
+    pub fn set_ivec3(&self, name: &str, v: &glm::IVec3) {
+        unsafe {
+            gl::Uniform3i(self.uniform_location(name), v.x, v.y, v.z);
+        }
+    }
+
+    /// Sets a `uvec3` uniform.
+    pub fn set_uvec3(&self, name: &str, v: &glm::UVec3) {
+        unsafe {
+            gl::Uniform3ui(self.uniform_location(name), v.x, v.y, v.z);
+        }
+    }
+
+    /// Sets a `vec3[]` uniform starting at element 0.
+    pub fn set_vec3_array(&self, name: &str, values: &[glm::Vec3]) {
+        unsafe {
+            gl::Uniform3fv(
+                self.uniform_location(name),
+                values.len() as i32,
+                values.as_ptr() as *const f32,
+            );
+        }
+    }
+
+    /// Sets a `mat4[]` uniform starting at element 0.
+    pub fn set_mat4_array(&self, name: &str, values: &[glm::Mat4]) {
+        unsafe {
+            gl::UniformMatrix4fv(
+                self.uniform_location(name),
+                values.len() as i32,
+                gl::FALSE,
+                values.as_ptr() as *const f32,
+            );
+        }
+    }
 }
 
-unsafe fn compile_shader(source: &str, kind: u32) -> u32 {
+unsafe fn compile_shader(source: &str, kind: u32) -> Result<u32, ShaderError> {
+    let c_str = CString::new(source).map_err(|_| ShaderError::Compile {
+        stage: kind,
+        log: "source contains NUL byte".to_string(),
+    })?;
     let shader = gl::CreateShader(kind);
-    let c_str = CString::new(source).unwrap();
 
     gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
     gl::CompileShader(shader);
@@ -143,16 +322,14 @@ unsafe fn compile_shader(source: &str, kind: u32) -> u32 {
             buffer.as_mut_ptr() as *mut _,
         );
 
-        panic!(
-            "Shader compilation failed:\n{}",
-            String::from_utf8_lossy(&buffer)
-        );
+        gl::DeleteShader(shader);
+        return Err(ShaderError::Compile { stage: kind, log: info_log_to_string(&buffer) });
     }
 
-    shader
+    Ok(shader)
 }
 
-unsafe fn check_program_link_errors(program: u32) {
+unsafe fn check_program_link_errors(program: u32) -> Result<(), ShaderError> {
     let mut success = 0;
     gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
 
@@ -168,11 +345,16 @@ unsafe fn check_program_link_errors(program: u32) {
             buffer.as_mut_ptr() as *mut _,
         );
 
-        panic!(
-            "Program linking failed:\n{}",
-            String::from_utf8_lossy(&buffer)
-        );
+        return Err(ShaderError::Link(info_log_to_string(&buffer)));
     }
+
+    Ok(())
+}
+
+/// Converts a NUL-terminated GL info log into a `String`.
+fn info_log_to_string(buffer: &[u8]) -> String {
+    let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+    String::from_utf8_lossy(&buffer[..end]).into_owned()
 }
 
 impl Drop for Shader {
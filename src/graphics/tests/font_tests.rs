@@ -0,0 +1,203 @@
+use std::sync::OnceLock;
+use crate::graphics::font::{pack_shelves, Font, FontAtlas, ASCII_PRINTABLE};
+use crate::graphics::texture::texture::Texture;
+
+const PIX32: &[u8] = include_bytes!("../../../assets/fonts/Pix32.ttf");
+
+#[test]
+fn test_default_range_covers_printable_ascii() {
+    let atlas = FontAtlas::rasterize(PIX32, 24.0, &[ASCII_PRINTABLE]);
+
+    assert!(atlas.glyphs.contains_key(&'A'));
+    assert!(atlas.glyphs.contains_key(&'~'));
+    assert!(!atlas.glyphs.contains_key(&'\n'));
+}
+
+#[test]
+fn test_characters_missing_from_font_are_skipped() {
+    // Private-use plane 16 is not in Pix32, so asking for it must not add empty glyphs
+    let atlas = FontAtlas::rasterize(PIX32, 24.0, &[0x10FFF0..=0x10FFFD, 0x41..=0x41]);
+
+    assert!(atlas.glyphs.contains_key(&'A'));
+    assert_eq!(atlas.glyphs.len(), 1);
+}
+
+#[test]
+fn test_fallback_box_is_visible() {
+    let atlas = FontAtlas::rasterize(PIX32, 24.0, &[ASCII_PRINTABLE]);
+    let fallback = &atlas.fallback;
+
+    assert!(fallback.width > 0.0 && fallback.height > 0.0);
+    assert!(fallback.advance > fallback.width);
+
+    // The box outline is drawn into the atlas at the fallback's UV origin
+    let x = (fallback.uv_rect.min.x * atlas.width as f32).round() as usize;
+    let y = (fallback.uv_rect.min.y * atlas.height as f32).round() as usize;
+    assert_eq!(atlas.pixels[y * atlas.width + x], 255);
+}
+
+fn overlaps(a: (usize, usize, usize, usize), b: (usize, usize, usize, usize)) -> bool {
+    a.0 < b.0 + b.2 && b.0 < a.0 + a.2 && a.1 < b.1 + b.3 && b.1 < a.1 + a.3
+}
+
+#[test]
+fn test_pack_shelves_wraps_and_never_overlaps() {
+    let sizes: Vec<(usize, usize)> = (0..200).map(|i| (5 + i % 13, 8 + i % 7)).collect();
+    let packing = pack_shelves(&sizes, 64);
+
+    assert!(packing.width <= 64);
+    let rects: Vec<_> = packing.positions.iter().zip(&sizes).map(|(&(x, y), &(w, h))| (x, y, w, h)).collect();
+    for (i, &a) in rects.iter().enumerate() {
+        assert!(a.0 + a.2 <= packing.width && a.1 + a.3 <= packing.height);
+        for &b in &rects[i + 1..] {
+            assert!(!overlaps(a, b), "{a:?} overlaps {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_pack_shelves_keeps_one_pixel_padding() {
+    let packing = pack_shelves(&[(4, 4), (4, 4)], 64);
+    assert_eq!(packing.positions, vec![(0, 0), (5, 0)]);
+    assert_eq!((packing.width, packing.height), (10, 5));
+}
+
+#[test]
+fn test_glyph_rects_do_not_overlap_in_atlas() {
+    let atlas = FontAtlas::rasterize(PIX32, 24.0, &[ASCII_PRINTABLE]);
+    let (w, h) = (atlas.width as f32, atlas.height as f32);
+
+    let rects: Vec<_> = atlas.glyphs.values().chain(std::iter::once(&atlas.fallback))
+        .filter(|g| g.width > 0.0 && g.height > 0.0)
+        .map(|g| {
+            let x = (g.uv_rect.min.x * w).round() as usize;
+            let y = (g.uv_rect.min.y * h).round() as usize;
+            (x, y, g.width as usize, g.height as usize)
+        })
+        .collect();
+
+    assert!(atlas.width <= 1024);
+    for (i, &a) in rects.iter().enumerate() {
+        for &b in &rects[i + 1..] {
+            assert!(!overlaps(a, b), "{a:?} overlaps {b:?}");
+        }
+    }
+}
+
+/// A font over the Pix32 atlas with a placeholder texture (id 0 is never passed to GL).
+/// The atlas is rasterized once; parsing the font dominates test time.
+fn test_font() -> Font {
+    static ATLAS: OnceLock<FontAtlas> = OnceLock::new();
+    let atlas = ATLAS.get_or_init(|| FontAtlas::rasterize(PIX32, 24.0, &[ASCII_PRINTABLE]));
+    let texture = Texture { id: 0, width: atlas.width as u32, height: atlas.height as u32, target: gl::TEXTURE_2D };
+    let mut font = Font::new_from_texture(texture, atlas.glyphs.clone(), 24.0);
+    font.fallback = Some(atlas.fallback.clone());
+    font.kerning = atlas.kerning.clone();
+    font.ascent = atlas.ascent;
+    font
+}
+
+#[test]
+fn test_measure_single_line_sums_advances() {
+    let font = test_font();
+    let expected: f32 = "Hi!".chars().map(|c| font.get_glyph(c).unwrap().advance).sum();
+
+    assert_eq!(font.measure("Hi!"), (expected, 24.0));
+}
+
+#[test]
+fn test_measure_uses_widest_line() {
+    let font = test_font();
+    let (long_width, _) = font.measure("Hello");
+
+    assert_eq!(font.measure("Hi\nHello\n"), (long_width, 72.0));
+}
+
+#[test]
+fn test_measure_empty_text() {
+    assert_eq!(test_font().measure(""), (0.0, 0.0));
+}
+
+#[test]
+fn test_wrap_text_breaks_at_spaces() {
+    let font = test_font();
+    let max_width = font.measure("aaaa bbbb").0;
+
+    let wrapped = font.wrap_text("aaaa bbbb cccc", max_width);
+
+    assert_eq!(wrapped, "aaaa bbbb\ncccc");
+    for line in wrapped.lines() {
+        assert!(font.measure(line).0 <= max_width);
+    }
+}
+
+#[test]
+fn test_wrap_text_hard_breaks_long_words() {
+    let font = test_font();
+    let max_width = font.measure("aaa").0;
+
+    assert_eq!(font.wrap_text("aaaaaaa", max_width), "aaa\naaa\na");
+}
+
+#[test]
+fn test_wrap_text_keeps_explicit_newlines() {
+    let font = test_font();
+    assert_eq!(font.wrap_text("ab\ncd", 1000.0), "ab\ncd");
+}
+
+#[test]
+fn test_kerning_pair_adjusts_advance() {
+    let mut font = test_font();
+    let plain = font.measure("AV").0;
+
+    font.kerning.insert(('A', 'V'), -3.0);
+
+    assert_eq!(font.measure("AV").0, plain - 3.0);
+    // Only the exact ordered pair is kerned
+    assert_eq!(font.measure("VA").0, plain);
+}
+
+#[test]
+fn test_letter_and_line_spacing_scale_layout() {
+    let mut font = test_font();
+    let (width, height) = font.measure("ab\ncd");
+
+    font.letter_spacing = 2.0;
+    font.line_spacing = 1.5;
+
+    let (spaced_width, spaced_height) = font.measure("ab\ncd");
+    assert!((spaced_width - width * 2.0).abs() < 1e-4);
+    assert!((spaced_height - height * 1.5).abs() < 1e-4);
+}
+
+/// Returns `(top, bottom)` of each glyph quad in a single line of text.
+fn quad_extents(font: &Font, text: &str) -> Vec<(f32, f32)> {
+    font.create_vertices(text).chunks(6).map(|quad| (quad[0].position[1], quad[2].position[1])).collect()
+}
+
+#[test]
+fn test_glyphs_share_baseline() {
+    let font = test_font();
+    let a = font.get_glyph('a').unwrap();
+    let g = font.get_glyph('g').unwrap();
+    let quads = quad_extents(&font, "ag");
+
+    // Top + offset_y lands on the same baseline for both glyphs
+    let baseline_a = quads[0].0 + a.offset_y;
+    let baseline_g = quads[1].0 + g.offset_y;
+    assert!((baseline_a - baseline_g).abs() < 1e-4);
+    assert!((baseline_a - font.ascent).abs() < 1e-4);
+
+    // Each glyph extends below the baseline by exactly its descent (height - offset_y)
+    assert!((quads[0].1 - baseline_a - (a.height - a.offset_y)).abs() < 1e-4);
+    assert!((quads[1].1 - baseline_g - (g.height - g.offset_y)).abs() < 1e-4);
+    assert!(quads[1].1 >= quads[0].1);
+}
+
+#[test]
+fn test_text_starts_below_origin() {
+    let font = test_font();
+    for (top, _) in quad_extents(&font, "Hg") {
+        assert!(top >= 0.0);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::graphics::gpu_mesh::{GpuMesh, DRAW_LINES};
+use crate::graphics::vertex::VertexPosUv;
+
+// Empty meshes never touch GL, so the capacity checks run without a context.
+
+#[test]
+fn test_empty_mesh_has_no_capacity() {
+    let mesh = GpuMesh::from_vertices::<VertexPosUv>(&[]);
+    assert_eq!(mesh.capacity(), 0);
+}
+
+#[test]
+#[should_panic(expected = "exceeds mesh capacity")]
+fn test_update_sub_past_capacity_panics() {
+    let mut mesh = GpuMesh::from_vertices::<VertexPosUv>(&[]);
+    let vertex = VertexPosUv { position: [0.0; 3], uv: [0.0; 2] };
+    mesh.update_sub(0, &[vertex]);
+}
+
+#[test]
+fn test_empty_mesh_getters() {
+    let mesh = GpuMesh::from_vertices::<VertexPosUv>(&[]).with_draw_mode(DRAW_LINES);
+
+    assert_eq!(mesh.vertex_count(), 0);
+    assert_eq!(mesh.element_count(), 0);
+    assert!(mesh.is_empty());
+    assert_eq!(mesh.draw_mode(), DRAW_LINES);
+}
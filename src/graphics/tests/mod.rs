@@ -1 +1,10 @@
-pub mod uv_rect_tests;
\ No newline at end of file
+pub mod uv_rect_tests;
+pub mod shader_tests;
+pub mod render_state_tests;
+pub mod gpu_mesh_tests;
+pub mod texture_tests;
+pub mod texture_array_tests;
+pub mod texture_3d_tests;
+pub mod texture_atlas_tests;
+pub mod font_tests;
+pub mod vertex_tests;
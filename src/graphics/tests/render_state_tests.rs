@@ -0,0 +1,29 @@
+use crate::core::handle::Handle;
+use crate::graphics::material::Material;
+use crate::graphics::render_state::{BlendMode, CullMode, RenderState};
+
+#[test]
+fn test_pass_defaults() {
+    assert_eq!(RenderState::default(), RenderState::OPAQUE);
+    assert_eq!(RenderState::OPAQUE.blend, BlendMode::Opaque);
+    assert_eq!(RenderState::TRANSPARENT.blend, BlendMode::Alpha);
+    assert_eq!(RenderState::GUI.cull, CullMode::None);
+}
+
+#[test]
+fn test_blend_factors() {
+    assert_eq!(BlendMode::Opaque.gl_factors(), None);
+    assert_eq!(BlendMode::Alpha.gl_factors(), Some((gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA)));
+    assert_eq!(BlendMode::Additive.gl_factors(), Some((gl::SRC_ALPHA, gl::ONE)));
+}
+
+#[test]
+fn test_material_render_state_override() {
+    let material = Material::new(Handle::new(0));
+    assert_eq!(material.render_state, None);
+
+    let glow = RenderState::TRANSPARENT.with_blend(BlendMode::Additive).with_cull(CullMode::Back);
+    let material = Material::new(Handle::new(0)).with_render_state(glow);
+    assert_eq!(material.render_state, Some(glow));
+    assert_eq!(glow.blend, BlendMode::Additive);
+}
@@ -0,0 +1,29 @@
+use crate::graphics::shader::{Shader, ShaderError};
+
+#[test]
+fn test_try_new_reports_missing_file() {
+    let result = Shader::try_new("does/not/exist.vert", "does/not/exist.frag");
+    assert!(matches!(result, Err(ShaderError::Io(_))));
+}
+
+#[test]
+fn test_nul_byte_in_source_is_compile_error() {
+    // Rejected before any GL call, so no context is needed
+    let result = Shader::try_from_source("#version 450 core\0", "void main() {}");
+    match result {
+        Err(ShaderError::Compile { stage, log }) => {
+            assert_eq!(stage, gl::VERTEX_SHADER);
+            assert_eq!(log, "source contains NUL byte");
+        }
+        _ => panic!("expected a compile error"),
+    }
+}
+
+#[test]
+fn test_error_display_includes_info_log() {
+    let error = ShaderError::Compile { stage: gl::FRAGMENT_SHADER, log: "0:3: 'vec5' : undeclared".to_string() };
+    assert_eq!(error.to_string(), "Shader compilation failed:\n0:3: 'vec5' : undeclared");
+
+    let error = ShaderError::Link("missing main".to_string());
+    assert_eq!(error.to_string(), "Program linking failed:\nmissing main");
+}
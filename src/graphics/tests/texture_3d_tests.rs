@@ -0,0 +1,19 @@
+use crate::graphics::texture::texture_3d::check_region;
+use crate::graphics::texture::TextureError;
+
+#[test]
+fn test_check_region_accepts_full_volume() {
+    assert!(check_region((4, 4, 4), (0, 0, 0), (4, 4, 4), 4 * 4 * 4 * 4).is_ok());
+}
+
+#[test]
+fn test_check_region_rejects_out_of_bounds() {
+    let result = check_region((4, 4, 4), (2, 0, 0), (3, 1, 1), 3 * 4);
+    assert!(matches!(result, Err(TextureError::RegionOutOfBounds { offset: (2, 0, 0), size: (3, 1, 1) })));
+}
+
+#[test]
+fn test_check_region_rejects_short_data() {
+    let result = check_region((4, 4, 4), (1, 1, 1), (2, 2, 2), 16);
+    assert!(matches!(result, Err(TextureError::DataLength { expected: 32, actual: 16 })));
+}
@@ -0,0 +1,75 @@
+use image::{DynamicImage, Rgba, RgbaImage};
+use crate::graphics::texture::texture_array::{atlas_tile_origin, check_layer_sizes, slice_atlas_grid, TextureArray};
+use crate::graphics::texture::TextureError;
+
+#[test]
+fn test_check_layer_sizes_accepts_matching_images() {
+    let images = vec![RgbaImage::new(16, 16), RgbaImage::new(16, 16)];
+    assert_eq!(check_layer_sizes(&images).unwrap(), (16, 16));
+}
+
+#[test]
+fn test_check_layer_sizes_reports_mismatched_layer() {
+    let images = vec![RgbaImage::new(16, 16), RgbaImage::new(16, 16), RgbaImage::new(32, 16)];
+
+    let err = check_layer_sizes(&images).unwrap_err();
+    assert!(matches!(err, TextureError::SizeMismatch { layer: 2, expected: (16, 16), actual: (32, 16) }));
+    assert_eq!(err.to_string(), "Texture size mismatch for array layer 2: expected 16x16, got 32x16");
+}
+
+#[test]
+fn test_from_files_rejects_empty_list() {
+    assert!(matches!(TextureArray::from_files(&[]), Err(TextureError::Empty)));
+}
+
+#[test]
+fn test_from_files_reports_missing_file() {
+    assert!(matches!(TextureArray::from_files(&["does/not/exist.png"]), Err(TextureError::Io(_))));
+}
+
+#[test]
+fn test_slice_atlas_grid_is_row_major_from_top_left() {
+    // 2x2 grid of 1x1 tiles, each pixel's red channel is its index in reading order
+    let mut atlas = RgbaImage::new(2, 2);
+    atlas.put_pixel(0, 0, Rgba([0, 0, 0, 255]));
+    atlas.put_pixel(1, 0, Rgba([1, 0, 0, 255]));
+    atlas.put_pixel(0, 1, Rgba([2, 0, 0, 255]));
+    atlas.put_pixel(1, 1, Rgba([3, 0, 0, 255]));
+
+    let tiles = slice_atlas_grid(&DynamicImage::ImageRgba8(atlas), (1, 1)).unwrap();
+
+    let reds: Vec<u8> = tiles.iter().map(|t| t.get_pixel(0, 0)[0]).collect();
+    assert_eq!(reds, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_slice_atlas_grid_rejects_partial_tiles() {
+    let atlas = DynamicImage::ImageRgba8(RgbaImage::new(40, 32));
+    assert!(matches!(slice_atlas_grid(&atlas, (16, 16)), Err(TextureError::AtlasNotDivisible { .. })));
+}
+
+/// Validation fails before any GL call, so a placeholder array is enough.
+fn unallocated_array(width: u32, height: u32) -> TextureArray {
+    TextureArray { id: 0, width, height, layers: 1 }
+}
+
+#[test]
+fn test_try_set_layer_reports_missing_file() {
+    let array = unallocated_array(16, 16);
+    assert!(matches!(array.try_set_layer(0, "does/not/exist.png"), Err(TextureError::Io(_))));
+}
+
+#[test]
+fn test_try_set_layer_from_atlas_rejects_wrong_tile_size() {
+    let array = unallocated_array(16, 16);
+    let result = array.try_set_layer_from_atlas(3, "does/not/exist.png", (8, 8), 0, 0);
+    assert!(matches!(result, Err(TextureError::SizeMismatch { layer: 3, expected: (16, 16), actual: (8, 8) })));
+}
+
+#[test]
+fn test_atlas_tile_origin_checks_bounds_and_overflow() {
+    assert_eq!(atlas_tile_origin((64, 32), (16, 16), (3, 1)).unwrap(), (48, 16));
+    assert!(matches!(atlas_tile_origin((64, 32), (16, 16), (4, 0)), Err(TextureError::TileOutOfBounds { .. })));
+    assert!(matches!(atlas_tile_origin((64, 32), (16, 16), (u32::MAX, 0)), Err(TextureError::TileOutOfBounds { .. })));
+    assert!(matches!(atlas_tile_origin((64, 32), (16, u32::MAX), (0, 1)), Err(TextureError::TileOutOfBounds { .. })));
+}
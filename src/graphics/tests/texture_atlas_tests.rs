@@ -0,0 +1,36 @@
+use crate::graphics::texture::texture_atlas::{tile_coords_for_index, tile_uv_rect};
+use nalgebra_glm as glm;
+
+#[test]
+fn test_tile_uv_rect_non_square_atlas() {
+    // 4x2 grid of 16px tiles
+    let rect = tile_uv_rect((16, 16), (64, 32), 1, 1);
+    assert_eq!(rect.min, glm::vec2(0.25, 0.5));
+    assert_eq!(rect.max, glm::vec2(0.5, 1.0));
+}
+
+#[test]
+fn test_tile_coords_for_index_is_row_major() {
+    let tile_size = (16, 16);
+    let atlas_size = (64, 32);
+
+    assert_eq!(tile_coords_for_index(tile_size, atlas_size, 0), Some((0, 0)));
+    assert_eq!(tile_coords_for_index(tile_size, atlas_size, 3), Some((3, 0)));
+    assert_eq!(tile_coords_for_index(tile_size, atlas_size, 4), Some((0, 1)));
+    assert_eq!(tile_coords_for_index(tile_size, atlas_size, 6), Some((2, 1)));
+}
+
+#[test]
+fn test_tile_coords_for_index_without_columns() {
+    assert_eq!(tile_coords_for_index((0, 16), (64, 32), 3), None);
+    assert_eq!(tile_coords_for_index((128, 16), (64, 32), 0), None);
+}
+
+#[test]
+fn test_half_texel_inset_on_non_square_atlas() {
+    let rect = tile_uv_rect((16, 16), (64, 32), 0, 0).inset(0.5 / 64.0, 0.5 / 32.0);
+    assert!((rect.min.x - 0.5 / 64.0).abs() < 1e-6);
+    assert!((rect.min.y - 0.5 / 32.0).abs() < 1e-6);
+    assert!((rect.max.x - 15.5 / 64.0).abs() < 1e-6);
+    assert!((rect.max.y - 15.5 / 32.0).abs() < 1e-6);
+}
@@ -0,0 +1,40 @@
+use crate::graphics::texture::texture::{PixelFormat, Texture, TextureParams, FILTER_LINEAR, FILTER_NEAREST, FILTER_NEAREST_MIPMAP_NEAREST, WRAP_CLAMP_TO_EDGE, WRAP_REPEAT};
+
+#[test]
+fn test_default_params_match_pixel_art_preset() {
+    let params = TextureParams::default();
+
+    assert_eq!(params.min_filter, FILTER_NEAREST_MIPMAP_NEAREST);
+    assert_eq!(params.mag_filter, FILTER_NEAREST);
+    assert_eq!((params.wrap_s, params.wrap_t), (WRAP_REPEAT, WRAP_REPEAT));
+    assert!(params.generate_mipmaps);
+}
+
+#[test]
+fn test_linear_clamped_has_no_mipmap_filter() {
+    let params = TextureParams::linear_clamped();
+
+    assert_eq!((params.min_filter, params.mag_filter), (FILTER_LINEAR, FILTER_LINEAR));
+    assert_eq!((params.wrap_s, params.wrap_t), (WRAP_CLAMP_TO_EDGE, WRAP_CLAMP_TO_EDGE));
+    assert!(!params.generate_mipmaps);
+}
+
+#[test]
+fn test_pixel_format_channels() {
+    assert_eq!(PixelFormat::R8.channels(), 1);
+    assert_eq!(PixelFormat::RG8.channels(), 2);
+    assert_eq!(PixelFormat::RGB8.channels(), 3);
+    assert_eq!(PixelFormat::RGBA8.channels(), 4);
+}
+
+#[test]
+fn test_pixel_format_gl_formats() {
+    assert_eq!(PixelFormat::R8.gl_formats(), (gl::R8, gl::RED));
+    assert_eq!(PixelFormat::RGBA8.gl_formats(), (gl::RGBA8, gl::RGBA));
+}
+
+#[test]
+#[should_panic(expected = "expected 16 bytes")]
+fn test_from_bytes_format_rejects_short_buffer() {
+    Texture::from_bytes_format(&[0; 8], 2, 2, PixelFormat::RGBA8);
+}
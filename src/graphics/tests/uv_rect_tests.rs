@@ -7,3 +7,48 @@ fn test_uv_rect_full() {
     assert_eq!(rect.min, glm::vec2(0.0, 0.0));
     assert_eq!(rect.max, glm::vec2(1.0, 1.0));
 }
+
+#[test]
+fn test_uv_rect_inset() {
+    let rect = UvRect::full().inset(0.25, 0.125);
+    assert_eq!(rect.min, glm::vec2(0.25, 0.125));
+    assert_eq!(rect.max, glm::vec2(0.75, 0.875));
+}
+
+#[test]
+fn test_uv_rect_flip_h_swaps_x() {
+    let rect = UvRect { min: glm::vec2(0.25, 0.5), max: glm::vec2(0.5, 0.75) }.flip_h();
+    assert_eq!(rect.min, glm::vec2(0.5, 0.5));
+    assert_eq!(rect.max, glm::vec2(0.25, 0.75));
+}
+
+#[test]
+fn test_uv_rect_flip_v_swaps_y() {
+    let rect = UvRect { min: glm::vec2(0.25, 0.5), max: glm::vec2(0.5, 0.75) }.flip_v();
+    assert_eq!(rect.min, glm::vec2(0.25, 0.75));
+    assert_eq!(rect.max, glm::vec2(0.5, 0.5));
+}
+
+#[test]
+fn test_uv_rect_rotated_90() {
+    let corners = UvRect::full().rotated_90();
+    // The image's top-left ends up in the quad's top-right corner
+    assert_eq!(corners[2], glm::vec2(0.0, 1.0));
+    assert_eq!(corners[0], glm::vec2(1.0, 0.0));
+}
+
+#[test]
+fn test_uv_rect_sub_rect() {
+    let rect = UvRect { min: glm::vec2(0.5, 0.0), max: glm::vec2(1.0, 0.5) }.sub_rect(0.5, 0.0, 1.0, 0.5);
+    assert_eq!(rect.min, glm::vec2(0.75, 0.0));
+    assert_eq!(rect.max, glm::vec2(1.0, 0.25));
+}
+
+#[test]
+fn test_uv_rect_lerp() {
+    let a = UvRect { min: glm::vec2(0.0, 0.0), max: glm::vec2(0.5, 0.5) };
+    let b = UvRect { min: glm::vec2(0.5, 0.5), max: glm::vec2(1.0, 1.0) };
+    let mid = a.lerp(&b, 0.5);
+    assert_eq!(mid.min, glm::vec2(0.25, 0.25));
+    assert_eq!(mid.max, glm::vec2(0.75, 0.75));
+}
@@ -0,0 +1,58 @@
+use crate::graphics::vertex::{Vertex, VertexPosColor, VertexPosNormalUv, VertexVoxelPacked};
+
+#[test]
+fn test_pos_color_layout() {
+    let layout = VertexPosColor::layout();
+    assert_eq!(layout.stride, 16);
+    assert_eq!(layout.attributes[1].offset, 12);
+    assert!(layout.attributes[1].normalized);
+}
+
+#[test]
+fn test_voxel_packed_round_trip() {
+    let vertex = VertexVoxelPacked::new([31, 0, 17], [4, 9], 5, 200, 15);
+
+    assert_eq!(vertex.position(), [31, 0, 17]);
+    assert_eq!(vertex.uv(), [4, 9]);
+    assert_eq!(vertex.face(), 5);
+    assert_eq!(vertex.texture_layer(), 200);
+    assert_eq!(vertex.light(), 15);
+}
+
+#[test]
+fn test_voxel_packed_is_quarter_of_normal_vertex() {
+    assert_eq!(VertexVoxelPacked::layout().stride, 8);
+    assert_eq!(VertexPosNormalUv::layout().stride, 32);
+    assert!(VertexVoxelPacked::layout().attributes.iter().all(|a| a.is_integer));
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use crate::graphics::vertex::{self, Vertex};
+
+    #[repr(C)]
+    #[derive(Vertex)]
+    struct Particle {
+        position: [f32; 3],
+        #[attr(normalized)]
+        color: [u8; 4],
+        #[attr(location = 5)]
+        life: f32,
+        layer: u32,
+    }
+
+    #[test]
+    fn test_derived_layout_matches_fields() {
+        let layout = Particle::layout();
+        assert_eq!(layout.stride, size_of::<Particle>());
+
+        let attrs = layout.attributes;
+        assert_eq!(attrs.len(), 4);
+        assert_eq!((attrs[0].location, attrs[0].size, attrs[0].offset), (0, 3, 0));
+        assert_eq!((attrs[1].gl_type, attrs[1].offset), (vertex::UNSIGNED_BYTE, 12));
+        assert!(attrs[1].normalized && !attrs[1].is_integer);
+        assert_eq!((attrs[2].location, attrs[2].offset), (5, 16));
+        assert!(attrs[3].is_integer);
+        assert_eq!(attrs[3].location, 3);
+    }
+}
@@ -1,4 +1,68 @@
 pub mod texture;
 pub mod texture_3d;
 pub mod texture_atlas;
-pub mod texture_array;
\ No newline at end of file
+pub mod texture_array;
+
+/// Errors returned by the fallible texture loaders and uploads.
+#[derive(Debug)]
+pub enum TextureError {
+    /// The image file could not be read.
+    Io(std::io::Error),
+    /// The image file could not be decoded.
+    Decode(image::ImageError),
+    /// Layer `layer` is `actual` pixels but the texture expects `expected`.
+    SizeMismatch { layer: u32, expected: (u32, u32), actual: (u32, u32) },
+    /// The requested atlas tile lies (partly) outside the atlas image.
+    TileOutOfBounds { tile: (u32, u32), tile_size: (u32, u32), atlas: (u32, u32) },
+    /// The atlas size is not a whole number of tiles.
+    AtlasNotDivisible { atlas: (u32, u32), tile_size: (u32, u32) },
+    /// A 3D upload region extends past the texture bounds.
+    RegionOutOfBounds { offset: (u32, u32, u32), size: (u32, u32, u32) },
+    /// The data slice holds `actual` bytes but the upload needs `expected`.
+    DataLength { expected: usize, actual: usize },
+    /// No images were given.
+    Empty,
+}
+
+impl std::fmt::Display for TextureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextureError::Io(e) => write!(f, "Failed to read texture: {e}"),
+            TextureError::Decode(e) => write!(f, "Failed to decode texture: {e}"),
+            TextureError::SizeMismatch { layer, expected, actual } => write!(
+                f,
+                "Texture size mismatch for array layer {}: expected {}x{}, got {}x{}",
+                layer, expected.0, expected.1, actual.0, actual.1
+            ),
+            TextureError::TileOutOfBounds { tile, tile_size, atlas } => write!(
+                f,
+                "Atlas tile out of bounds: tile ({}, {}), size {}x{}, atlas {}x{}",
+                tile.0, tile.1, tile_size.0, tile_size.1, atlas.0, atlas.1
+            ),
+            TextureError::AtlasNotDivisible { atlas, tile_size } => write!(
+                f,
+                "Atlas {}x{} is not a whole number of {}x{} tiles",
+                atlas.0, atlas.1, tile_size.0, tile_size.1
+            ),
+            TextureError::RegionOutOfBounds { offset, size } => write!(
+                f,
+                "Region at {:?} of size {:?} exceeds the texture bounds",
+                offset, size
+            ),
+            TextureError::DataLength { expected, actual } => {
+                write!(f, "Expected {expected} bytes of texture data, got {actual}")
+            }
+            TextureError::Empty => write!(f, "No images given for texture array"),
+        }
+    }
+}
+
+impl std::error::Error for TextureError {}
+
+/// Opens an image file, splitting I/O failures from decode failures.
+pub(crate) fn open_image(path: &str) -> Result<image::DynamicImage, TextureError> {
+    image::open(path).map_err(|e| match e {
+        image::ImageError::IoError(e) => TextureError::Io(e),
+        e => TextureError::Decode(e),
+    })
+}
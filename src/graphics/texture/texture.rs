@@ -1,8 +1,101 @@
 use image::GenericImageView;
 use nalgebra_glm as glm;
 
-/// A 2D OpenGL texture.
-#[derive(Clone, Copy)]
+// Re-export GL filter and wrap constants so downstream crates don't need the `gl` crate.
+pub const FILTER_NEAREST: u32 = gl::NEAREST;
+pub const FILTER_LINEAR: u32 = gl::LINEAR;
+pub const FILTER_NEAREST_MIPMAP_NEAREST: u32 = gl::NEAREST_MIPMAP_NEAREST;
+pub const FILTER_LINEAR_MIPMAP_NEAREST: u32 = gl::LINEAR_MIPMAP_NEAREST;
+pub const FILTER_NEAREST_MIPMAP_LINEAR: u32 = gl::NEAREST_MIPMAP_LINEAR;
+pub const FILTER_LINEAR_MIPMAP_LINEAR: u32 = gl::LINEAR_MIPMAP_LINEAR;
+pub const WRAP_REPEAT: u32 = gl::REPEAT;
+pub const WRAP_MIRRORED_REPEAT: u32 = gl::MIRRORED_REPEAT;
+pub const WRAP_CLAMP_TO_EDGE: u32 = gl::CLAMP_TO_EDGE;
+
+/// Sampling parameters applied when a texture is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureParams {
+    /// Minification filter, e.g. [`FILTER_NEAREST_MIPMAP_NEAREST`]. Mipmap filters need `generate_mipmaps`.
+    pub min_filter: u32,
+    /// Magnification filter, [`FILTER_NEAREST`] or [`FILTER_LINEAR`].
+    pub mag_filter: u32,
+    /// Horizontal wrap mode, e.g. [`WRAP_REPEAT`].
+    pub wrap_s: u32,
+    /// Vertical wrap mode.
+    pub wrap_t: u32,
+    /// Whether to build the mipmap chain after upload.
+    pub generate_mipmaps: bool,
+}
+
+impl TextureParams {
+    /// Linear filtering without mipmaps, clamped at the edges (UI sprites, skyboxes).
+    pub fn linear_clamped() -> Self {
+        Self {
+            min_filter: FILTER_LINEAR,
+            mag_filter: FILTER_LINEAR,
+            wrap_s: WRAP_CLAMP_TO_EDGE,
+            wrap_t: WRAP_CLAMP_TO_EDGE,
+            generate_mipmaps: false,
+        }
+    }
+
+    /// Applies these parameters to the texture bound to `TEXTURE_2D`.
+    unsafe fn apply(&self) {
+        if self.generate_mipmaps {
+            gl::GenerateMipmap(gl::TEXTURE_2D);
+        }
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, self.wrap_s as i32);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, self.wrap_t as i32);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, self.min_filter as i32);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, self.mag_filter as i32);
+    }
+}
+
+impl Default for TextureParams {
+    /// Pixel-art preset used by [`Texture::from_file`]: nearest filtering with mipmaps, repeating.
+    fn default() -> Self {
+        Self {
+            min_filter: FILTER_NEAREST_MIPMAP_NEAREST,
+            mag_filter: FILTER_NEAREST,
+            wrap_s: WRAP_REPEAT,
+            wrap_t: WRAP_REPEAT,
+            generate_mipmaps: true,
+        }
+    }
+}
+
+/// Channel layout of 8-bit-per-channel pixel data passed to [`Texture::from_bytes_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    R8,
+    RG8,
+    RGB8,
+    RGBA8,
+}
+
+impl PixelFormat {
+    /// Bytes per pixel.
+    pub fn channels(self) -> usize {
+        match self {
+            PixelFormat::R8 => 1,
+            PixelFormat::RG8 => 2,
+            PixelFormat::RGB8 => 3,
+            PixelFormat::RGBA8 => 4,
+        }
+    }
+
+    /// Returns the `(internal_format, format)` pair for `glTexImage2D`.
+    pub(crate) fn gl_formats(self) -> (u32, u32) {
+        match self {
+            PixelFormat::R8 => (gl::R8, gl::RED),
+            PixelFormat::RG8 => (gl::RG8, gl::RG),
+            PixelFormat::RGB8 => (gl::RGB8, gl::RGB),
+            PixelFormat::RGBA8 => (gl::RGBA8, gl::RGBA),
+        }
+    }
+}
+
+/// A 2D OpenGL texture. Owns its GL object, which is deleted on drop.
 pub struct Texture {
     pub(crate) id: u32,
     /// Texture width in pixels.
@@ -21,8 +114,13 @@ impl Texture {
         }
     }
 
-    /// Loads an RGBA texture from an image file.
+    /// Loads an RGBA texture from an image file with the default (pixel-art) [`TextureParams`].
     pub fn from_file(path: &str) -> Self {
+        Self::from_file_with_params(path, TextureParams::default())
+    }
+
+    /// Loads an RGBA texture from an image file with the given filtering and wrap modes.
+    pub fn from_file_with_params(path: &str, params: TextureParams) -> Self {
         let img = image::open(path)
             .expect("Failed to load texture")
             .flipv();
@@ -47,12 +145,7 @@ impl Texture {
                 data.as_ptr() as *const _,
             );
 
-            gl::GenerateMipmap(gl::TEXTURE_2D);
-
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::REPEAT as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::REPEAT as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST_MIPMAP_NEAREST as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::NEAREST as i32);
+            params.apply();
         }
 
         Self { id, width, height, target: gl::TEXTURE_2D }
@@ -60,22 +153,36 @@ impl Texture {
 
     /// Creates a single-channel (RED) texture from raw pixel bytes.
     pub fn from_bytes(pixels: &[u8], width: u32, height: u32) -> Self {
+        // fontdue gives us 1 byte per pixel (Grayscale).
+        // We upload it as RED so the shader can use .r as the alpha/intensity.
+        Self::from_bytes_format(pixels, width, height, PixelFormat::R8)
+    }
+
+    /// Creates a texture from tightly packed, bottom-row-first pixel bytes in the given format,
+    /// e.g. procedurally generated RGBA noise. Uses nearest filtering without mipmaps.
+    ///
+    /// # Panics
+    /// If `pixels` holds fewer than `width * height * format.channels()` bytes.
+    pub fn from_bytes_format(pixels: &[u8], width: u32, height: u32, format: PixelFormat) -> Self {
+        let expected = width as usize * height as usize * format.channels();
+        assert!(pixels.len() >= expected, "expected {expected} bytes of pixel data, got {}", pixels.len());
+
+        let (internal_format, data_format) = format.gl_formats();
         let mut id = 0;
         unsafe {
             gl::GenTextures(1, &mut id);
             gl::BindTexture(gl::TEXTURE_2D, id);
 
-            // fontdue gives us 1 byte per pixel (Grayscale).
-            // We upload it as RED so the shader can use .r as the alpha/intensity.
+            // Rows are tightly packed; the default alignment of 4 breaks 1-3 channel widths
             gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
             gl::TexImage2D(
                 gl::TEXTURE_2D,
                 0,
-                gl::RED as i32,
+                internal_format as i32,
                 width as i32,
                 height as i32,
                 0,
-                gl::RED,
+                data_format,
                 gl::UNSIGNED_BYTE,
                 pixels.as_ptr() as *const _,
             );
@@ -87,3 +194,12 @@ impl Texture {
     }
 }
 
+impl Drop for Texture {
+    fn drop(&mut self) {
+        if self.id != 0 {
+            unsafe {
+                gl::DeleteTextures(1, &self.id);
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
-/// A 3D OpenGL texture (used for lightmaps).
+use crate::graphics::texture::TextureError;
+
+/// A 3D OpenGL texture (used for lightmaps). Always RGBA8: every upload takes
+/// [`Texture3D::CHANNELS`] bytes per texel, X-major then Y then Z.
 pub struct Texture3D {
     pub(crate) id: u32,
     /// Texture width in texels.
@@ -10,7 +13,10 @@ pub struct Texture3D {
 }
 
 impl Texture3D {
-    /// Creates an empty 3D texture with RGB8 format and linear filtering.
+    /// Bytes per texel (R, G, B, A) expected by every upload.
+    pub const CHANNELS: usize = 4;
+
+    /// Creates an empty 3D texture with RGBA8 format and linear filtering.
     pub fn new(width: u32, height: u32, depth: u32) -> Self {
         let mut id = 0;
         unsafe {
@@ -40,22 +46,47 @@ impl Texture3D {
     }
 
     /// Re-uploads the full 3D texture data.
-    /// Data should be a flat slice of bytes in RGBA order.
+    /// Data should be a flat slice of bytes in RGBA order. Panics on a length mismatch; see [`Self::try_update`].
     pub fn update(&self, data: &[u8]) {
+        self.try_update(data).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Like [`Self::update`], but returns an error if `data` doesn't cover the whole volume.
+    pub fn try_update(&self, data: &[u8]) -> Result<(), TextureError> {
+        self.try_update_region((0, 0, 0), (self.width, self.height, self.depth), data)
+    }
+
+    /// Uploads RGBA data into a sub-box of the texture starting at `offset` (in texels).
+    /// Data should be `size.0 * size.1 * size.2` RGBA texels, X-major like [`Self::update`].
+    /// Panics on failure; see [`Self::try_update_region`].
+    pub fn update_region(&self, offset: (u32, u32, u32), size: (u32, u32, u32), data: &[u8]) {
+        self.try_update_region(offset, size, data).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Like [`Self::update_region`], but returns an error if the region leaves the texture or
+    /// `data` is too short.
+    pub fn try_update_region(
+        &self,
+        offset: (u32, u32, u32),
+        size: (u32, u32, u32),
+        data: &[u8],
+    ) -> Result<(), TextureError> {
+        check_region((self.width, self.height, self.depth), offset, size, data.len())?;
         unsafe {
             gl::BindTexture(gl::TEXTURE_3D, self.id);
             gl::TexSubImage3D(
                 gl::TEXTURE_3D,
                 0,
-                0, 0, 0,
-                self.width as i32,
-                self.height as i32,
-                self.depth as i32,
+                offset.0 as i32, offset.1 as i32, offset.2 as i32,
+                size.0 as i32,
+                size.1 as i32,
+                size.2 as i32,
                 gl::RGBA,
                 gl::UNSIGNED_BYTE,
                 data.as_ptr() as *const _,
             );
         }
+        Ok(())
     }
 
     /// Binds this 3D texture to the given texture unit slot.
@@ -74,3 +105,21 @@ impl Drop for Texture3D {
         }
     }
 }
+
+/// Checks that an RGBA upload of `size` texels at `offset` fits in `dims` and `data_len` covers it.
+pub(crate) fn check_region(
+    dims: (u32, u32, u32),
+    offset: (u32, u32, u32),
+    size: (u32, u32, u32),
+    data_len: usize,
+) -> Result<(), TextureError> {
+    let fits = |o: u32, s: u32, d: u32| o.checked_add(s).is_some_and(|end| end <= d);
+    if !(fits(offset.0, size.0, dims.0) && fits(offset.1, size.1, dims.1) && fits(offset.2, size.2, dims.2)) {
+        return Err(TextureError::RegionOutOfBounds { offset, size });
+    }
+    let expected = size.0 as usize * size.1 as usize * size.2 as usize * Texture3D::CHANNELS;
+    if data_len < expected {
+        return Err(TextureError::DataLength { expected, actual: data_len });
+    }
+    Ok(())
+}
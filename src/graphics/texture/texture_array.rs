@@ -1,4 +1,6 @@
-use image::GenericImageView;
+use image::{GenericImageView, RgbaImage};
+use crate::graphics::texture::{open_image, TextureError};
+
 
 /// An OpenGL 2D texture array for layered textures (e.g. voxel block faces).
 pub struct TextureArray {
@@ -41,34 +43,25 @@ impl TextureArray {
         Self { id, width, height, layers }
     }
 
-    /// Loads an image file into a specific layer.
+    /// Loads an image file into a specific layer. Panics on failure; see [`Self::try_set_layer`].
     pub fn set_layer(&self, layer: u32, path: &str) {
-        let img = image::open(path)
-            .expect("Failed to load texture for array")
-            .flipv();
+        self.try_set_layer(layer, path).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Loads an image file into a specific layer, returning an error if the file is missing,
+    /// can't be decoded, or doesn't match the array's layer size.
+    pub fn try_set_layer(&self, layer: u32, path: &str) -> Result<(), TextureError> {
+        let img = open_image(path)?.flipv();
         let (w, h) = img.dimensions();
         if w != self.width || h != self.height {
-            panic!("Texture size mismatch for array layer {}: expected {}x{}, got {}x{}", layer, self.width, self.height, w, h);
-        }
-        let data = img.to_rgba8();
-
-        unsafe {
-            gl::BindTexture(gl::TEXTURE_2D_ARRAY, self.id);
-            gl::TexSubImage3D(
-                gl::TEXTURE_2D_ARRAY,
-                0,
-                0, 0, layer as i32,
-                self.width as i32,
-                self.height as i32,
-                1,
-                gl::RGBA,
-                gl::UNSIGNED_BYTE,
-                data.as_ptr() as *const _,
-            );
+            return Err(TextureError::SizeMismatch { layer, expected: (self.width, self.height), actual: (w, h) });
         }
+        self.upload_layer(layer, &img.to_rgba8());
+        Ok(())
     }
 
     /// Copies a tile from a texture atlas into a specific layer.
+    /// Panics on failure; see [`Self::try_set_layer_from_atlas`].
     pub fn set_layer_from_atlas(
         &self,
         layer: u32,
@@ -77,40 +70,64 @@ impl TextureArray {
         tile_x: u32,
         tile_y: u32,
     ) {
+        self.try_set_layer_from_atlas(layer, path, tile_size, tile_x, tile_y)
+            .unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Copies a tile from a texture atlas into a specific layer, returning an error if the tile
+    /// size doesn't match the array, the tile lies outside the atlas, or the file can't be loaded.
+    pub fn try_set_layer_from_atlas(
+        &self,
+        layer: u32,
+        path: &str,
+        tile_size: (u32, u32),
+        tile_x: u32,
+        tile_y: u32,
+    ) -> Result<(), TextureError> {
         if tile_size.0 != self.width || tile_size.1 != self.height {
-            panic!(
-                "Tile size mismatch for array layer {}: expected {}x{}, got {}x{}",
-                layer,
-                self.width,
-                self.height,
-                tile_size.0,
-                tile_size.1
-            );
+            return Err(TextureError::SizeMismatch { layer, expected: (self.width, self.height), actual: tile_size });
         }
 
-        let img = image::open(path).expect("Failed to load texture atlas for array");
-        let (atlas_w, atlas_h) = img.dimensions();
-        let x = tile_x * tile_size.0;
-        let y = tile_y * tile_size.1;
-
-        if x + tile_size.0 > atlas_w || y + tile_size.1 > atlas_h {
-            panic!(
-                "Atlas tile out of bounds for layer {}: tile ({}, {}), size {}x{}, atlas {}x{}",
-                layer,
-                tile_x,
-                tile_y,
-                tile_size.0,
-                tile_size.1,
-                atlas_w,
-                atlas_h
-            );
-        }
+        let img = open_image(path)?;
+        let (x, y) = atlas_tile_origin(img.dimensions(), tile_size, (tile_x, tile_y))?;
 
         let tile = img
             .crop_imm(x, y, tile_size.0, tile_size.1)
             .flipv()
             .to_rgba8();
+        self.upload_layer(layer, &tile);
+        Ok(())
+    }
+
+    /// Creates an array with one layer per image file, sized from the first image, and
+    /// generates mipmaps. All images are loaded and validated before any GL object is created.
+    pub fn from_files(paths: &[&str]) -> Result<Self, TextureError> {
+        let images = paths
+            .iter()
+            .map(|path| open_image(path).map(|img| img.flipv().to_rgba8()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_images(&images)
+    }
+
+    /// Creates an array from a grid atlas, one layer per `tile_size` tile in row-major order
+    /// starting at the top-left, and generates mipmaps.
+    pub fn from_atlas_grid(path: &str, tile_size: (u32, u32)) -> Result<Self, TextureError> {
+        let img = open_image(path)?;
+        let tiles = slice_atlas_grid(&img, tile_size)?;
+        Self::from_images(&tiles)
+    }
 
+    fn from_images(images: &[RgbaImage]) -> Result<Self, TextureError> {
+        let (width, height) = check_layer_sizes(images)?;
+        let array = Self::new(width, height, images.len() as u32);
+        for (layer, image) in images.iter().enumerate() {
+            array.upload_layer(layer as u32, image);
+        }
+        array.generate_mipmaps();
+        Ok(array)
+    }
+
+    fn upload_layer(&self, layer: u32, data: &RgbaImage) {
         unsafe {
             gl::BindTexture(gl::TEXTURE_2D_ARRAY, self.id);
             gl::TexSubImage3D(
@@ -122,7 +139,7 @@ impl TextureArray {
                 1,
                 gl::RGBA,
                 gl::UNSIGNED_BYTE,
-                tile.as_ptr() as *const _,
+                data.as_ptr() as *const _,
             );
         }
     }
@@ -142,4 +159,51 @@ impl TextureArray {
             gl::BindTexture(gl::TEXTURE_2D_ARRAY, self.id);
         }
     }
-}
\ No newline at end of file
+}
+
+/// Returns the shared layer size, or an error if `images` is empty or any size differs from the first.
+pub(crate) fn check_layer_sizes(images: &[RgbaImage]) -> Result<(u32, u32), TextureError> {
+    let expected = images.first().ok_or(TextureError::Empty)?.dimensions();
+    for (layer, image) in images.iter().enumerate() {
+        if image.dimensions() != expected {
+            return Err(TextureError::SizeMismatch { layer: layer as u32, expected, actual: image.dimensions() });
+        }
+    }
+    Ok(expected)
+}
+
+/// Cuts an atlas into `tile_size` tiles, row-major from the top-left, each flipped for GL.
+pub(crate) fn slice_atlas_grid(
+    img: &image::DynamicImage,
+    tile_size: (u32, u32),
+) -> Result<Vec<RgbaImage>, TextureError> {
+    let (atlas_w, atlas_h) = img.dimensions();
+    if tile_size.0 == 0 || tile_size.1 == 0 || atlas_w % tile_size.0 != 0 || atlas_h % tile_size.1 != 0 {
+        return Err(TextureError::AtlasNotDivisible { atlas: (atlas_w, atlas_h), tile_size });
+    }
+
+    let mut tiles = Vec::new();
+    for row in 0..atlas_h / tile_size.1 {
+        for col in 0..atlas_w / tile_size.0 {
+            tiles.push(
+                img.crop_imm(col * tile_size.0, row * tile_size.1, tile_size.0, tile_size.1)
+                    .flipv()
+                    .to_rgba8(),
+            );
+        }
+    }
+    Ok(tiles)
+}
+
+/// Pixel origin of `tile` in an atlas of `atlas` pixels, or `TileOutOfBounds` if the tile
+/// (or the arithmetic to find it) falls outside the atlas.
+pub(crate) fn atlas_tile_origin(atlas: (u32, u32), tile_size: (u32, u32), tile: (u32, u32)) -> Result<(u32, u32), TextureError> {
+    let origin = |index: u32, size: u32, extent: u32| {
+        let start = index.checked_mul(size)?;
+        (start.checked_add(size)? <= extent).then_some(start)
+    };
+    match (origin(tile.0, tile_size.0, atlas.0), origin(tile.1, tile_size.1, atlas.1)) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(TextureError::TileOutOfBounds { tile, tile_size, atlas }),
+    }
+}
@@ -1,5 +1,6 @@
 use nalgebra_glm as glm;
 use crate::graphics::texture::texture::Texture;
+use crate::graphics::uv_rect::UvRect;
 
 /// A 2D texture subdivided into uniform tiles.
 pub struct TextureAtlas {
@@ -30,15 +31,43 @@ impl TextureAtlas {
     }
 
     /// Returns the UV rectangle for the tile at grid position `(x, y)`.
-    pub fn uv_rect(&self, x: u32, y: u32) -> crate::graphics::uv_rect::UvRect {
-        let u0 = (x * self.tile_size.0) as f32 / self.atlas_size.0 as f32;
-        let v0 = (y * self.tile_size.1) as f32 / self.atlas_size.1 as f32;
-        let u1 = ((x + 1) * self.tile_size.0) as f32 / self.atlas_size.0 as f32;
-        let v1 = ((y + 1) * self.tile_size.1) as f32 / self.atlas_size.1 as f32;
-
-        crate::graphics::uv_rect::UvRect {
-            min: glm::vec2(u0, v0),
-            max: glm::vec2(u1, v1),
-        }
+    pub fn uv_rect(&self, x: u32, y: u32) -> UvRect {
+        tile_uv_rect(self.tile_size, self.atlas_size, x, y)
+    }
+
+    /// Like [`Self::uv_rect`], but shrunk by `inset_px` atlas pixels on every side so filtering
+    /// and mipmapping don't sample neighbouring tiles. `0.5` (half a texel) is usually enough.
+    pub fn uv_rect_inset(&self, x: u32, y: u32, inset_px: f32) -> UvRect {
+        self.uv_rect(x, y).inset(
+            inset_px / self.atlas_size.0 as f32,
+            inset_px / self.atlas_size.1 as f32,
+        )
+    }
+
+    /// Returns the UV rectangle for the `index`-th tile, counting row-major across the atlas,
+    /// or `None` if the tile width is zero or the atlas is narrower than one tile.
+    pub fn uv_rect_for_index(&self, index: u32) -> Option<UvRect> {
+        let (x, y) = tile_coords_for_index(self.tile_size, self.atlas_size, index)?;
+        Some(self.uv_rect(x, y))
+    }
+}
+
+/// UV bounds of the tile at grid position `(x, y)`.
+pub(crate) fn tile_uv_rect(tile_size: (u32, u32), atlas_size: (u32, u32), x: u32, y: u32) -> UvRect {
+    let u0 = (x * tile_size.0) as f32 / atlas_size.0 as f32;
+    let v0 = (y * tile_size.1) as f32 / atlas_size.1 as f32;
+    let u1 = ((x + 1) * tile_size.0) as f32 / atlas_size.0 as f32;
+    let v1 = ((y + 1) * tile_size.1) as f32 / atlas_size.1 as f32;
+
+    UvRect {
+        min: glm::vec2(u0, v0),
+        max: glm::vec2(u1, v1),
     }
-}
\ No newline at end of file
+}
+
+/// Grid position of the `index`-th tile, row-major with `atlas_size.0 / tile_size.0` columns,
+/// or `None` if that leaves no columns.
+pub(crate) fn tile_coords_for_index(tile_size: (u32, u32), atlas_size: (u32, u32), index: u32) -> Option<(u32, u32)> {
+    let columns = atlas_size.0.checked_div(tile_size.0).filter(|&c| c > 0)?;
+    Some((index % columns, index / columns))
+}
@@ -17,4 +17,63 @@ impl UvRect {
             max: glm::vec2(1.0, 1.0),
         }
     }
-}
\ No newline at end of file
+
+    /// Returns this rectangle shrunk by `du` horizontally and `dv` vertically on every side.
+    pub fn inset(&self, du: f32, dv: f32) -> Self {
+        Self {
+            min: glm::vec2(self.min.x + du, self.min.y + dv),
+            max: glm::vec2(self.max.x - du, self.max.y - dv),
+        }
+    }
+
+    /// Mirrors the rectangle horizontally by swapping `min.x` and `max.x`.
+    pub fn flip_h(&self) -> Self {
+        Self {
+            min: glm::vec2(self.max.x, self.min.y),
+            max: glm::vec2(self.min.x, self.max.y),
+        }
+    }
+
+    /// Mirrors the rectangle vertically by swapping `min.y` and `max.y`.
+    pub fn flip_v(&self) -> Self {
+        Self {
+            min: glm::vec2(self.min.x, self.max.y),
+            max: glm::vec2(self.max.x, self.min.y),
+        }
+    }
+
+    /// Returns the UVs for a quad's corners in bottom-left, bottom-right, top-right, top-left order.
+    pub fn corners(&self) -> [glm::Vec2; 4] {
+        [
+            glm::vec2(self.min.x, self.min.y),
+            glm::vec2(self.max.x, self.min.y),
+            glm::vec2(self.max.x, self.max.y),
+            glm::vec2(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Returns corner UVs (same order as [`Self::corners`]) that show the region rotated
+    /// 90° clockwise. A rotation can't be expressed as a `min`/`max` pair, hence the corners.
+    pub fn rotated_90(&self) -> [glm::Vec2; 4] {
+        let [bl, br, tr, tl] = self.corners();
+        [br, tr, tl, bl]
+    }
+
+    /// Returns the part of this rectangle between the fractions `(u0, v0)` and `(u1, v1)`
+    /// of its size, e.g. `sub_rect(0.5, 0.0, 1.0, 1.0)` is the right half. Follows flips.
+    pub fn sub_rect(&self, u0: f32, v0: f32, u1: f32, v1: f32) -> Self {
+        let size = self.max - self.min;
+        Self {
+            min: self.min + glm::vec2(size.x * u0, size.y * v0),
+            max: self.min + glm::vec2(size.x * u1, size.y * v1),
+        }
+    }
+
+    /// Linearly interpolates both corners towards `other` (`t = 0` is `self`, `t = 1` is `other`).
+    pub fn lerp(&self, other: &UvRect, t: f32) -> Self {
+        Self {
+            min: glm::lerp(&self.min, &other.min, t),
+            max: glm::lerp(&self.max, &other.max, t),
+        }
+    }
+}
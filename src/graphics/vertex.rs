@@ -5,6 +5,14 @@ pub const FLOAT: GLenum = gl::FLOAT;
 pub const UNSIGNED_INT: GLenum = gl::UNSIGNED_INT;
 pub const INT: GLenum = gl::INT;
 pub const UNSIGNED_BYTE: GLenum = gl::UNSIGNED_BYTE;
+pub const BYTE: GLenum = gl::BYTE;
+pub const UNSIGNED_SHORT: GLenum = gl::UNSIGNED_SHORT;
+pub const SHORT: GLenum = gl::SHORT;
+
+/// Derives [`Vertex`] from a `#[repr(C)]` struct, one attribute per field, with offsets
+/// computed from the real field layout. Fields take `#[attr(location = N, normalized)]`.
+#[cfg(feature = "derive")]
+pub use voxxel_derive::Vertex;
 
 /// Describes one vertex attribute (location, component count, type, offset).
 pub struct VertexAttribute {
@@ -112,3 +120,118 @@ impl Vertex for VertexPosNormalUv {
         }
     }
 }
+
+/// Vertex with 3D position and an RGBA color, e.g. for debug lines and particles.
+/// The color reaches the shader as a normalized `vec4` in [0, 1].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct VertexPosColor {
+    /// XYZ position.
+    pub position: [f32; 3],
+    /// RGBA color, 0-255 per channel.
+    pub color: [u8; 4],
+}
+
+impl Vertex for VertexPosColor {
+    fn layout() -> VertexLayout {
+        VertexLayout {
+            stride: size_of::<Self>(),
+            attributes: &[
+                VertexAttribute {
+                    location: 0,
+                    size: 3,
+                    gl_type: gl::FLOAT,
+                    normalized: false,
+                    is_integer: false,
+                    offset: 0,
+                },
+                VertexAttribute {
+                    location: 1,
+                    size: 4,
+                    gl_type: gl::UNSIGNED_BYTE,
+                    normalized: true,
+                    is_integer: false,
+                    offset: 12,
+                },
+            ],
+        }
+    }
+}
+
+/// Compact chunk vertex in the layout the built-in voxel shader (`vertex.glsl`) reads as
+/// `aPacked` and `aLayer`, 8 bytes instead of the 32 of [`VertexPosNormalUv`].
+///
+/// `packed`: bits 0-4 x, 5-9 y, 10-14 z, 15-19 u, 20-24 v, 25-27 face (0-5: +Z, -Z, +X, -X, +Y, -Y).
+/// `layer`: bits 0-7 light level (ignored by the built-in shader), bits 24-31 texture array layer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VertexVoxelPacked {
+    /// Chunk-local position, tiling UV, and face.
+    pub packed: u32,
+    /// Light level and texture layer.
+    pub layer: u32,
+}
+
+impl VertexVoxelPacked {
+    /// Packs a vertex. Position and UV components are masked to 5 bits (0-31), `face` to 3 bits.
+    pub fn new(position: [u32; 3], uv: [u32; 2], face: u32, layer: u8, light: u8) -> Self {
+        let packed = (position[0] & 31)
+            | (position[1] & 31) << 5
+            | (position[2] & 31) << 10
+            | (uv[0] & 31) << 15
+            | (uv[1] & 31) << 20
+            | (face & 7) << 25;
+        Self { packed, layer: (layer as u32) << 24 | light as u32 }
+    }
+
+    /// Returns the chunk-local position.
+    pub fn position(&self) -> [u32; 3] {
+        [self.packed & 31, (self.packed >> 5) & 31, (self.packed >> 10) & 31]
+    }
+
+    /// Returns the tiling UV.
+    pub fn uv(&self) -> [u32; 2] {
+        [(self.packed >> 15) & 31, (self.packed >> 20) & 31]
+    }
+
+    /// Returns the face index.
+    pub fn face(&self) -> u32 {
+        (self.packed >> 25) & 7
+    }
+
+    /// Returns the texture array layer.
+    pub fn texture_layer(&self) -> u8 {
+        (self.layer >> 24) as u8
+    }
+
+    /// Returns the light level.
+    pub fn light(&self) -> u8 {
+        self.layer as u8
+    }
+}
+
+impl Vertex for VertexVoxelPacked {
+    fn layout() -> VertexLayout {
+        VertexLayout {
+            stride: size_of::<Self>(),
+            attributes: &[
+                VertexAttribute {
+                    location: 0,
+                    size: 1,
+                    gl_type: gl::UNSIGNED_INT,
+                    normalized: false,
+                    is_integer: true,
+                    offset: 0,
+                },
+                VertexAttribute {
+                    location: 1,
+                    size: 1,
+                    gl_type: gl::UNSIGNED_INT,
+                    normalized: false,
+                    is_integer: true,
+                    offset: 4,
+                },
+            ],
+        }
+    }
+}
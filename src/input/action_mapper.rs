@@ -6,33 +6,45 @@ use crate::input::input_source::InputSource;
 /// Maps game-defined action enums to physical inputs and tracks their state.
 pub struct ActionMapper<A: Eq + Hash + Clone> {
     bindings: HashMap<A, Vec<InputSource>>,
+    axis_bindings: HashMap<A, Vec<(InputSource, InputSource)>>,
     active_states: HashMap<A, bool>,
     pressed_states: HashMap<A, bool>,
+    axis_values: HashMap<A, f32>,
 }
 
 impl<A: Eq + Hash + Clone> ActionMapper<A> {
     /// Creates an empty action mapper with no bindings.
     pub fn new() -> Self {Self{
         bindings: HashMap::new(),
+        axis_bindings: HashMap::new(),
         active_states: HashMap::new(),
         pressed_states: HashMap::new(),
+        axis_values: HashMap::new(),
     }}
     /// Reads current input state and updates all action states. Call once per frame.
     pub fn update(&mut self, input: &Input) {
         for (action, sources) in &self.bindings {
-            let is_down = sources.iter().any(|s| match s {
-                InputSource::Key(k) => input.is_key_down(*k),
-                InputSource::Mouse(m) => input.is_mouse_down(*m),
-            });
+            let is_down = sources.iter().any(|s| is_source_down(input, s));
 
             let is_pressed = sources.iter().any(|s| match s {
                 InputSource::Key(k) => input.is_key_pressed(*k),
                 InputSource::Mouse(m) => input.is_mouse_pressed(*m),
+                InputSource::GamepadButton(b) => input.is_gamepad_button_pressed(*b),
+                InputSource::GamepadAxis(a, d) => {
+                    d.magnitude(input.gamepad_axis(*a)) > 0.0 && d.magnitude(input.previous_gamepad_axis(*a)) == 0.0
+                }
             });
 
             self.active_states.insert(action.clone(), is_down);
             self.pressed_states.insert(action.clone(), is_pressed);
         }
+
+        for (axis, pairs) in &self.axis_bindings {
+            let value: f32 = pairs.iter()
+                .map(|(positive, negative)| source_value(input, positive) - source_value(input, negative))
+                .sum();
+            self.axis_values.insert(axis.clone(), value.clamp(-1.0, 1.0));
+        }
     }
 
     /// Returns `true` if any input bound to the action is currently held down.
@@ -45,11 +57,74 @@ impl<A: Eq + Hash + Clone> ActionMapper<A> {
         *self.pressed_states.get(action).unwrap_or(&false)
     }
 
+    /// Returns the axis value in [-1, 1]: 1 when only a positive input is held, -1 when only a negative one is.
+    /// Analog gamepad sources contribute their deflection past the deadzone.
+    pub fn axis_value(&self, axis: &A) -> f32 {
+        *self.axis_values.get(axis).unwrap_or(&0.0)
+    }
+
+    /// Binds a positive/negative input pair to a 1D axis (e.g. D and A for strafing).
+    /// Multiple pairs can be bound to one axis; the sum is clamped to [-1, 1].
+    pub fn bind_axis(&mut self, axis: A, positive: InputSource, negative: InputSource) {
+        self.axis_bindings
+            .entry(axis)
+            .or_default()
+            .push((positive, negative));
+    }
+
+    /// Removes every source (and axis pair) bound to the action and clears its state.
+    pub fn unbind(&mut self, action: &A) {
+        self.bindings.remove(action);
+        self.axis_bindings.remove(action);
+        self.active_states.remove(action);
+        self.pressed_states.remove(action);
+        self.axis_values.remove(action);
+    }
+
+    /// Replaces all sources bound to the action with `source`.
+    pub fn rebind(&mut self, action: A, source: InputSource) {
+        self.unbind(&action);
+        self.bind(action, source);
+    }
+
+    /// Returns the sources currently bound to the action (empty if unbound).
+    pub fn bindings_for(&self, action: &A) -> &[InputSource] {
+        self.bindings.get(action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns a copy of all button bindings, e.g. for saving a control scheme.
+    /// Axis bindings are not included.
+    pub fn export_bindings(&self) -> Vec<(A, Vec<InputSource>)> {
+        self.bindings.iter().map(|(a, s)| (a.clone(), s.clone())).collect()
+    }
+
+    /// Replaces all button bindings with `data` (as produced by [`Self::export_bindings`])
+    /// and clears button state. Axis bindings are kept.
+    pub fn import_bindings(&mut self, data: Vec<(A, Vec<InputSource>)>) {
+        self.bindings = data.into_iter().collect();
+        self.active_states.clear();
+        self.pressed_states.clear();
+    }
+
     /// Binds a physical input source to an action. Multiple sources can be bound to one action.
     pub fn bind(&mut self, action: A, source: InputSource) {
         self.bindings
             .entry(action)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(source);
     }
-}
\ No newline at end of file
+}
+
+fn is_source_down(input: &Input, source: &InputSource) -> bool {
+    source_value(input, source) > 0.0
+}
+
+/// 0 or 1 for digital sources, the deflection in [0, 1] for analog ones.
+fn source_value(input: &Input, source: &InputSource) -> f32 {
+    match source {
+        InputSource::Key(k) => input.is_key_down(*k) as i32 as f32,
+        InputSource::Mouse(m) => input.is_mouse_down(*m) as i32 as f32,
+        InputSource::GamepadButton(b) => input.is_gamepad_button_down(*b) as i32 as f32,
+        InputSource::GamepadAxis(a, d) => d.magnitude(input.gamepad_axis(*a)),
+    }
+}
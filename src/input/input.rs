@@ -1,14 +1,24 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use sdl2::controller::{Axis, Button};
 use sdl2::keyboard::Scancode;
 use sdl2::mouse::MouseButton;
 
-/// Tracks keyboard and mouse state across frames for edge detection.
+/// Tracks keyboard, mouse, and gamepad state across frames for edge detection.
 pub struct Input {
     current_keys: HashSet<Scancode>,
     previous_keys: HashSet<Scancode>,
     current_mouse: HashSet<MouseButton>,
     previous_mouse: HashSet<MouseButton>,
     mouse_delta: (f32, f32),
+    mouse_position: (f32, f32),
+    mouse_captured: bool,
+    scroll_delta: f32,
+    text_input: String,
+    current_gamepad: HashSet<Button>,
+    previous_gamepad: HashSet<Button>,
+    gamepad_axes: HashMap<Axis, f32>,
+    previous_gamepad_axes: HashMap<Axis, f32>,
+    gamepad_deadzone: f32,
 }
 
 impl Input {
@@ -20,6 +30,15 @@ impl Input {
             previous_mouse: HashSet::new(),
             current_mouse: HashSet::new(),
             mouse_delta: (0.0, 0.0),
+            mouse_position: (0.0, 0.0),
+            mouse_captured: true,
+            scroll_delta: 0.0,
+            text_input: String::new(),
+            current_gamepad: HashSet::new(),
+            previous_gamepad: HashSet::new(),
+            gamepad_axes: HashMap::new(),
+            previous_gamepad_axes: HashMap::new(),
+            gamepad_deadzone: 0.2,
         }
     }
 
@@ -29,7 +48,12 @@ impl Input {
         self.current_keys = self.previous_keys.clone();
         std::mem::swap(&mut self.previous_mouse, &mut self.current_mouse);
         self.current_mouse = self.previous_mouse.clone();
+        std::mem::swap(&mut self.previous_gamepad, &mut self.current_gamepad);
+        self.current_gamepad = self.previous_gamepad.clone();
+        self.previous_gamepad_axes.clone_from(&self.gamepad_axes);
         self.mouse_delta = (0.0, 0.0);
+        self.scroll_delta = 0.0;
+        self.text_input.clear();
     }
 
     /// Records a key press or release. Called by the engine from event polling.
@@ -71,11 +95,109 @@ impl Input {
     }
 
     /// Accumulates mouse movement for this frame. Called by the engine from event polling.
+    /// Ignored while the cursor is released.
     pub fn add_mouse_delta(&mut self, x: f32, y: f32) {
+        if !self.mouse_captured {
+            return;
+        }
         self.mouse_delta.0 += x;
         self.mouse_delta.1 += y;
     }
 
     /// Returns the accumulated mouse delta `(dx, dy)` for this frame.
     pub fn get_mouse_delta(&self) -> (f32, f32) {self.mouse_delta}
-}
\ No newline at end of file
+
+    /// Sets whether the cursor is captured; releasing it discards this frame's delta.
+    /// Called by the engine when applying `EngineContext::set_cursor_captured`.
+    pub(crate) fn set_mouse_captured(&mut self, captured: bool) {
+        self.mouse_captured = captured;
+        if !captured {
+            self.mouse_delta = (0.0, 0.0);
+        }
+    }
+
+    /// Returns `true` if the cursor is captured (relative mouse mode).
+    pub fn is_mouse_captured(&self) -> bool {self.mouse_captured}
+
+    /// Records the absolute cursor position in window pixels. Called by the engine from event polling.
+    pub fn set_mouse_position(&mut self, x: f32, y: f32) {
+        self.mouse_position = (x, y);
+    }
+
+    /// Returns the last known cursor position `(x, y)` in window pixels, origin top-left.
+    pub fn get_mouse_position(&self) -> (f32, f32) {self.mouse_position}
+
+    /// Accumulates vertical scroll-wheel movement for this frame. Called by the engine from event polling.
+    pub fn add_scroll_delta(&mut self, y: f32) {
+        self.scroll_delta += y;
+    }
+
+    /// Returns the accumulated scroll delta for this frame (positive = away from the user).
+    pub fn get_scroll_delta(&self) -> f32 {self.scroll_delta}
+
+    /// Appends typed text for this frame. Called by the engine from `TextInput` events,
+    /// which may carry several characters (IME commits, fast typing) of any UTF-8 width.
+    pub fn push_text(&mut self, s: &str) {
+        self.text_input.push_str(s);
+    }
+
+    /// Returns the text typed this frame, in order. Cleared by [`Self::update`].
+    pub fn take_text(&self) -> &str {&self.text_input}
+
+    /// Records a gamepad button press or release. Called by the engine from event polling.
+    pub fn set_gamepad_button(&mut self, button: Button, is_pressed: bool) {
+        if is_pressed {
+            self.current_gamepad.insert(button);
+        } else {
+            self.current_gamepad.remove(&button);
+        }
+    }
+
+    /// Returns `true` if the gamepad button is currently held down.
+    pub fn is_gamepad_button_down(&self, button: Button) -> bool {
+        self.current_gamepad.contains(&button)
+    }
+
+    /// Returns `true` if the gamepad button was pressed this frame (edge-triggered).
+    pub fn is_gamepad_button_pressed(&self, button: Button) -> bool {
+        self.current_gamepad.contains(&button) && !self.previous_gamepad.contains(&button)
+    }
+
+    /// Records a raw gamepad axis value. Called by the engine from event polling.
+    pub fn set_gamepad_axis(&mut self, axis: Axis, value: i16) {
+        self.gamepad_axes.insert(axis, (value as f32 / i16::MAX as f32).clamp(-1.0, 1.0));
+    }
+
+    /// Returns the axis value in [-1, 1] (triggers: [0, 1]) with the deadzone removed and
+    /// the remaining range rescaled, so output starts at 0 just past the deadzone.
+    pub fn gamepad_axis(&self, axis: Axis) -> f32 {
+        self.apply_deadzone(*self.gamepad_axes.get(&axis).unwrap_or(&0.0))
+    }
+
+    /// Like [`Self::gamepad_axis`], but for the previous frame.
+    pub(crate) fn previous_gamepad_axis(&self, axis: Axis) -> f32 {
+        self.apply_deadzone(*self.previous_gamepad_axes.get(&axis).unwrap_or(&0.0))
+    }
+
+    /// Sets the analog deadzone as a fraction of full deflection (default 0.2).
+    pub fn set_gamepad_deadzone(&mut self, deadzone: f32) {
+        self.gamepad_deadzone = deadzone.clamp(0.0, 0.99);
+    }
+
+    /// Returns the analog deadzone.
+    pub fn gamepad_deadzone(&self) -> f32 {self.gamepad_deadzone}
+
+    /// Releases all gamepad buttons and centers all axes. Called when the controller disconnects.
+    pub(crate) fn clear_gamepad(&mut self) {
+        self.current_gamepad.clear();
+        self.gamepad_axes.clear();
+    }
+
+    fn apply_deadzone(&self, value: f32) -> f32 {
+        let magnitude = value.abs();
+        if magnitude <= self.gamepad_deadzone {
+            return 0.0;
+        }
+        value.signum() * (magnitude - self.gamepad_deadzone) / (1.0 - self.gamepad_deadzone)
+    }
+}
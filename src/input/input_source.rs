@@ -1,11 +1,112 @@
+use sdl2::controller::{Axis, Button};
 use sdl2::keyboard::Scancode;
 use sdl2::mouse::MouseButton;
 
 /// A physical input that can be bound to a game action.
+///
+/// With the `serde` feature, keys serialize as SDL scancode numbers (USB HID usage IDs)
+/// and mouse buttons as SDL button indices, both stable across runs and platforms. Gamepad
+/// buttons and axes use SDL's `SDL_GameControllerButton` / `SDL_GameControllerAxis` indices.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "InputSourceRepr", try_from = "InputSourceRepr"))]
 pub enum InputSource {
     /// A keyboard scancode.
     Key(Scancode),
     /// A mouse button.
     Mouse(MouseButton),
-}
\ No newline at end of file
+    /// A gamepad button.
+    GamepadButton(Button),
+    /// One direction of a gamepad analog axis; active past the deadzone.
+    GamepadAxis(Axis, AxisDirection),
+}
+
+/// Which half of an analog axis an [`InputSource::GamepadAxis`] reads.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AxisDirection {
+    /// Right / down on sticks; pulled on triggers.
+    Positive,
+    /// Left / up on sticks.
+    Negative,
+}
+
+impl AxisDirection {
+    /// Returns the magnitude of `value` in this direction (0 if it points the other way).
+    pub fn magnitude(self, value: f32) -> f32 {
+        match self {
+            AxisDirection::Positive => value.max(0.0),
+            AxisDirection::Negative => (-value).max(0.0),
+        }
+    }
+}
+
+/// Stable on-disk form of [`InputSource`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum InputSourceRepr {
+    Key(i32),
+    Mouse(u8),
+    GamepadButton(u8),
+    GamepadAxis(u8, bool),
+}
+
+#[cfg(feature = "serde")]
+impl From<InputSource> for InputSourceRepr {
+    fn from(source: InputSource) -> Self {
+        match source {
+            InputSource::Key(k) => InputSourceRepr::Key(k as i32),
+            InputSource::Mouse(m) => InputSourceRepr::Mouse(m as u8),
+            InputSource::GamepadButton(b) => InputSourceRepr::GamepadButton(b as u8),
+            InputSource::GamepadAxis(a, d) => InputSourceRepr::GamepadAxis(a as u8, d == AxisDirection::Positive),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<InputSourceRepr> for InputSource {
+    type Error = String;
+
+    fn try_from(repr: InputSourceRepr) -> Result<Self, Self::Error> {
+        match repr {
+            // `Scancode::from_i32` transmutes, so only pass it values SDL defines
+            InputSourceRepr::Key(code) if is_defined_scancode(code) => Scancode::from_i32(code)
+                .map(InputSource::Key)
+                .ok_or_else(|| format!("unknown scancode {code}")),
+            InputSourceRepr::Key(code) => Err(format!("unknown scancode {code}")),
+            InputSourceRepr::Mouse(button) => match MouseButton::from_ll(button) {
+                MouseButton::Unknown => Err(format!("unknown mouse button {button}")),
+                m => Ok(InputSource::Mouse(m)),
+            },
+            InputSourceRepr::GamepadButton(index) => GAMEPAD_BUTTONS.get(index as usize)
+                .map(|&b| InputSource::GamepadButton(b))
+                .ok_or_else(|| format!("unknown gamepad button {index}")),
+            InputSourceRepr::GamepadAxis(index, positive) => GAMEPAD_AXES.get(index as usize)
+                .map(|&a| {
+                    let direction = if positive { AxisDirection::Positive } else { AxisDirection::Negative };
+                    InputSource::GamepadAxis(a, direction)
+                })
+                .ok_or_else(|| format!("unknown gamepad axis {index}")),
+        }
+    }
+}
+
+/// Ranges of `SDL_Scancode` values defined by SDL 2 (excluding `UNKNOWN` and `NUM_SCANCODES`).
+#[cfg(feature = "serde")]
+fn is_defined_scancode(code: i32) -> bool {
+    matches!(code, 4..=129 | 133..=164 | 176..=221 | 224..=231 | 257..=290)
+}
+
+/// Gamepad buttons in `SDL_GameControllerButton` order.
+#[cfg(feature = "serde")]
+const GAMEPAD_BUTTONS: [Button; 21] = [
+    Button::A, Button::B, Button::X, Button::Y, Button::Back, Button::Guide, Button::Start,
+    Button::LeftStick, Button::RightStick, Button::LeftShoulder, Button::RightShoulder,
+    Button::DPadUp, Button::DPadDown, Button::DPadLeft, Button::DPadRight, Button::Misc1,
+    Button::Paddle1, Button::Paddle2, Button::Paddle3, Button::Paddle4, Button::Touchpad,
+];
+
+/// Gamepad axes in `SDL_GameControllerAxis` order.
+#[cfg(feature = "serde")]
+const GAMEPAD_AXES: [Axis; 6] = [
+    Axis::LeftX, Axis::LeftY, Axis::RightX, Axis::RightY, Axis::TriggerLeft, Axis::TriggerRight,
+];
@@ -1,3 +1,6 @@
 pub mod input;
 pub mod action_mapper;
-pub mod input_source;
\ No newline at end of file
+pub mod input_source;
+
+#[cfg(test)]
+mod tests;
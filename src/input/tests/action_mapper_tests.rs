@@ -0,0 +1,158 @@
+use sdl2::keyboard::Scancode;
+use crate::input::action_mapper::ActionMapper;
+use crate::input::input::Input;
+use crate::input::input_source::{AxisDirection, InputSource};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+    Strafe,
+}
+
+fn mapper() -> ActionMapper<Action> {
+    let mut mapper = ActionMapper::new();
+    mapper.bind(Action::Jump, InputSource::Key(Scancode::Space));
+    mapper.bind_axis(Action::Strafe, InputSource::Key(Scancode::D), InputSource::Key(Scancode::A));
+    mapper
+}
+
+#[test]
+fn test_axis_value_follows_held_keys() {
+    let mut mapper = mapper();
+    let mut input = Input::new();
+
+    mapper.update(&input);
+    assert_eq!(mapper.axis_value(&Action::Strafe), 0.0);
+
+    input.set_key(Scancode::D, true);
+    mapper.update(&input);
+    assert_eq!(mapper.axis_value(&Action::Strafe), 1.0);
+
+    input.set_key(Scancode::A, true);
+    mapper.update(&input);
+    assert_eq!(mapper.axis_value(&Action::Strafe), 0.0);
+
+    input.set_key(Scancode::D, false);
+    mapper.update(&input);
+    assert_eq!(mapper.axis_value(&Action::Strafe), -1.0);
+}
+
+#[test]
+fn test_axes_and_buttons_share_an_enum() {
+    let mut mapper = mapper();
+    mapper.bind_axis(Action::Strafe, InputSource::Key(Scancode::Right), InputSource::Key(Scancode::Left));
+    let mut input = Input::new();
+    input.set_key(Scancode::Space, true);
+    input.set_key(Scancode::D, true);
+    input.set_key(Scancode::Right, true);
+
+    mapper.update(&input);
+
+    assert!(mapper.is_active(&Action::Jump));
+    assert!(!mapper.is_active(&Action::Strafe));
+    // Two positive pairs held still clamp to 1
+    assert_eq!(mapper.axis_value(&Action::Strafe), 1.0);
+    assert_eq!(mapper.axis_value(&Action::Jump), 0.0);
+}
+
+#[test]
+fn test_rebind_replaces_sources() {
+    let mut mapper = mapper();
+    mapper.bind(Action::Jump, InputSource::Key(Scancode::W));
+    assert_eq!(mapper.bindings_for(&Action::Jump).len(), 2);
+
+    mapper.rebind(Action::Jump, InputSource::Key(Scancode::J));
+
+    assert_eq!(mapper.bindings_for(&Action::Jump), &[InputSource::Key(Scancode::J)]);
+    let mut input = Input::new();
+    input.set_key(Scancode::Space, true);
+    mapper.update(&input);
+    assert!(!mapper.is_active(&Action::Jump));
+}
+
+#[test]
+fn test_unbind_clears_sources_and_state() {
+    let mut mapper = mapper();
+    let mut input = Input::new();
+    input.set_key(Scancode::Space, true);
+    mapper.update(&input);
+    assert!(mapper.is_pressed(&Action::Jump));
+
+    mapper.unbind(&Action::Jump);
+
+    assert!(mapper.bindings_for(&Action::Jump).is_empty());
+    assert!(!mapper.is_active(&Action::Jump));
+    assert!(!mapper.is_pressed(&Action::Jump));
+}
+
+#[test]
+fn test_export_import_round_trip() {
+    let mut original = mapper();
+    original.bind(Action::Jump, InputSource::Mouse(sdl2::mouse::MouseButton::Right));
+
+    let mut restored: ActionMapper<Action> = ActionMapper::new();
+    restored.import_bindings(original.export_bindings());
+
+    assert_eq!(restored.bindings_for(&Action::Jump), original.bindings_for(&Action::Jump));
+    assert!(restored.bindings_for(&Action::Strafe).is_empty());
+}
+
+#[test]
+fn test_gamepad_sources_drive_actions_and_analog_axes() {
+    use sdl2::controller::{Axis, Button};
+
+    let mut mapper = mapper();
+    mapper.bind(Action::Jump, InputSource::GamepadButton(Button::A));
+    mapper.bind_axis(
+        Action::Strafe,
+        InputSource::GamepadAxis(Axis::LeftX, AxisDirection::Positive),
+        InputSource::GamepadAxis(Axis::LeftX, AxisDirection::Negative),
+    );
+    let mut input = Input::new();
+    input.set_gamepad_deadzone(0.0);
+    input.set_gamepad_button(Button::A, true);
+    input.set_gamepad_axis(Axis::LeftX, i16::MIN / 2);
+
+    mapper.update(&input);
+
+    assert!(mapper.is_pressed(&Action::Jump));
+    assert!((mapper.axis_value(&Action::Strafe) + 0.5).abs() < 1e-3);
+}
+
+#[cfg(feature = "serde")]
+mod serde_format {
+    use sdl2::keyboard::Scancode;
+    use sdl2::mouse::MouseButton;
+    use crate::input::input_source::InputSource;
+
+    #[test]
+    fn test_input_source_uses_stable_codes() {
+        let json = serde_json::to_string(&[InputSource::Key(Scancode::A), InputSource::Mouse(MouseButton::Left)]).unwrap();
+        assert_eq!(json, r#"[{"Key":4},{"Mouse":1}]"#);
+
+        let parsed: Vec<InputSource> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, vec![InputSource::Key(Scancode::A), InputSource::Mouse(MouseButton::Left)]);
+    }
+
+    #[test]
+    fn test_gamepad_sources_round_trip() {
+        use sdl2::controller::{Axis, Button};
+        use crate::input::input_source::AxisDirection;
+
+        let sources = vec![
+            InputSource::GamepadButton(Button::Touchpad),
+            InputSource::GamepadAxis(Axis::TriggerRight, AxisDirection::Positive),
+            InputSource::GamepadAxis(Axis::LeftY, AxisDirection::Negative),
+        ];
+        let json = serde_json::to_string(&sources).unwrap();
+        assert_eq!(json, r#"[{"GamepadButton":20},{"GamepadAxis":[5,true]},{"GamepadAxis":[1,false]}]"#);
+        assert_eq!(serde_json::from_str::<Vec<InputSource>>(&json).unwrap(), sources);
+    }
+
+    #[test]
+    fn test_unknown_codes_rejected() {
+        assert!(serde_json::from_str::<InputSource>(r#"{"Key":100000}"#).is_err());
+        assert!(serde_json::from_str::<InputSource>(r#"{"Key":2}"#).is_err());
+        assert!(serde_json::from_str::<InputSource>(r#"{"Mouse":0}"#).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use sdl2::controller::{Axis, Button};
+use crate::input::input::Input;
+
+#[test]
+fn test_text_input_accumulates_multibyte_and_multi_char_events() {
+    let mut input = Input::new();
+    input.push_text("a");
+    input.push_text("é€");
+    input.push_text("日本");
+
+    assert_eq!(input.take_text(), "aé€日本");
+    assert_eq!(input.take_text().chars().count(), 5);
+}
+
+#[test]
+fn test_per_frame_state_cleared_on_update() {
+    let mut input = Input::new();
+    input.push_text("hi");
+    input.add_scroll_delta(1.5);
+    input.add_scroll_delta(-0.5);
+    assert_eq!(input.get_scroll_delta(), 1.0);
+
+    input.update();
+
+    assert_eq!(input.take_text(), "");
+    assert_eq!(input.get_scroll_delta(), 0.0);
+}
+
+#[test]
+fn test_mouse_position_persists_across_frames() {
+    let mut input = Input::new();
+    input.set_mouse_position(120.0, 45.0);
+    input.add_mouse_delta(3.0, -2.0);
+
+    input.update();
+
+    assert_eq!(input.get_mouse_position(), (120.0, 45.0));
+    assert_eq!(input.get_mouse_delta(), (0.0, 0.0));
+}
+
+#[test]
+fn test_released_cursor_does_not_accumulate_delta() {
+    let mut input = Input::new();
+    input.add_mouse_delta(4.0, 4.0);
+
+    input.set_mouse_captured(false);
+    assert_eq!(input.get_mouse_delta(), (0.0, 0.0));
+    input.add_mouse_delta(10.0, -3.0);
+    assert_eq!(input.get_mouse_delta(), (0.0, 0.0));
+
+    input.set_mouse_captured(true);
+    input.add_mouse_delta(1.0, 2.0);
+    assert_eq!(input.get_mouse_delta(), (1.0, 2.0));
+}
+
+#[test]
+fn test_gamepad_button_edges() {
+    let mut input = Input::new();
+    input.set_gamepad_button(Button::A, true);
+    assert!(input.is_gamepad_button_pressed(Button::A));
+
+    input.update();
+    assert!(input.is_gamepad_button_down(Button::A));
+    assert!(!input.is_gamepad_button_pressed(Button::A));
+}
+
+#[test]
+fn test_gamepad_axis_deadzone() {
+    let mut input = Input::new();
+    input.set_gamepad_deadzone(0.25);
+
+    input.set_gamepad_axis(Axis::LeftX, (i16::MAX as f32 * 0.2) as i16);
+    assert_eq!(input.gamepad_axis(Axis::LeftX), 0.0);
+
+    input.set_gamepad_axis(Axis::LeftX, i16::MIN);
+    assert_eq!(input.gamepad_axis(Axis::LeftX), -1.0);
+
+    // Halfway between the deadzone and full deflection
+    input.set_gamepad_axis(Axis::LeftX, (i16::MAX as f32 * 0.625) as i16);
+    assert!((input.gamepad_axis(Axis::LeftX) - 0.5).abs() < 1e-3);
+}
@@ -0,0 +1,2 @@
+pub mod input_tests;
+pub mod action_mapper_tests;
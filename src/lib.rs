@@ -1,3 +1,7 @@
+// Lets `#[derive(Vertex)]` output (which names `::voxxel_engine`) compile inside this crate too.
+#[cfg(feature = "derive")]
+extern crate self as voxxel_engine;
+
 pub mod camera;
 pub mod input;
 pub mod engine;
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use crate::lighting::lighting_world::{LightingWorld, OpacityWorld};
+use crate::lighting::propagation::{propagate, unpropagate, unpropagate_sky};
+use crate::physics::coordinates::Coordinates;
+
+/// Sky light settings for [`LightEngine`]: columns between `min_y` and `max_y` are lit from the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkyLight {
+    /// Light color at the top of an unobstructed column.
+    pub color: [u8; 3],
+    /// Lowest Y a sky column is traced down to.
+    pub min_y: i32,
+    /// Y where sky columns start.
+    pub max_y: i32,
+}
+
+/// Edit-level facade over the propagation functions for interactive worlds.
+///
+/// Tracks placed light sources, re-propagates only what each edit can affect, and accumulates
+/// the bounds of every changed voxel so only that region of the lightmap needs re-uploading
+/// (see `Texture3D::update_region`). Edits that remove light use [`unpropagate`] and
+/// [`unpropagate_sky`], so they only visit the voxels the old light reached, then refill them
+/// from the surrounding light, the sky columns above them, and the tracked sources. Block light
+/// is assumed to come only from tracked sources, and removal needs `attenuation > 0`: with no
+/// falloff a light's neighbours are as bright as it is, so nothing tells them apart from other light.
+pub struct LightEngine {
+    sources: HashMap<Coordinates, [u8; 3]>,
+    attenuation: u8,
+    sky: Option<SkyLight>,
+    dirty: Option<(Coordinates, Coordinates)>,
+}
+
+impl LightEngine {
+    /// Creates an engine with no sources, attenuating light by `attenuation` per block.
+    pub fn new(attenuation: u8) -> Self {
+        Self { sources: HashMap::new(), attenuation, sky: None, dirty: None }
+    }
+
+    /// Enables sky light handling for opacity edits (builder style).
+    pub fn with_sky(mut self, sky: SkyLight) -> Self {
+        self.sky = Some(sky);
+        self
+    }
+
+    /// Returns the tracked light sources and their colors.
+    pub fn sources(&self) -> &HashMap<Coordinates, [u8; 3]> {
+        &self.sources
+    }
+
+    /// Inclusive min/max bounds of every voxel whose light changed since the last [`Self::take_dirty`].
+    pub fn dirty_bounds(&self) -> Option<(Coordinates, Coordinates)> {
+        self.dirty
+    }
+
+    /// Returns and clears the dirty bounds, typically after re-uploading that region.
+    pub fn take_dirty(&mut self) -> Option<(Coordinates, Coordinates)> {
+        self.dirty.take()
+    }
+
+    /// Places (or recolors) a light source and propagates it.
+    pub fn place_light<W: LightingWorld>(&mut self, world: &mut W, coords: Coordinates, color: [u8; 3]) {
+        if self.sources.contains_key(&coords) {
+            self.remove_light(world, coords);
+        }
+        self.sources.insert(coords, color);
+        self.reseed_sources(world);
+    }
+
+    /// Removes a light source, clears the light it contributed, and refills from other sources.
+    pub fn remove_light<W: LightingWorld>(&mut self, world: &mut W, coords: Coordinates) {
+        if self.sources.remove(&coords).is_none() {
+            return;
+        }
+        let attenuation = self.attenuation;
+        let cleared = self.track_changes(world, |world| unpropagate(world, &[coords], attenuation));
+        self.relight_sky(world, cleared);
+        self.reseed_sources(world);
+    }
+
+    /// Changes a block's opacity (block placed or broken) and updates light around it.
+    pub fn set_opacity<W: OpacityWorld>(&mut self, world: &mut W, coords: Coordinates, opacity: u8) {
+        let old_opacity = world.get_opacity(coords);
+        if opacity == old_opacity {
+            return;
+        }
+        world.set_opacity(coords, opacity);
+        let attenuation = self.attenuation;
+        let sky = self.sky;
+
+        if opacity > old_opacity {
+            // Block placed: remove the light that passed through it and its shadow column,
+            // then refill from whatever still reaches the cleared voxels
+            let cleared = self.track_changes(world, |world| {
+                unpropagate(world, &[coords], attenuation);
+                if let Some(sky) = sky {
+                    unpropagate_sky(world, coords, sky.color, attenuation);
+                }
+            });
+            self.relight_sky(world, cleared);
+        } else {
+            // Block broken: light can only grow, flooding in from lit neighbours and the sky column
+            self.relight_sky(world, Some((coords, coords)));
+            let mut tracked = self.tracking(world);
+            let lit: Vec<Coordinates> = coords.neighbors().into_iter()
+                .filter(|&n| tracked.get_light(n) != [0, 0, 0])
+                .collect();
+            propagate(&mut tracked, &lit, attenuation);
+        }
+
+        self.reseed_sources(world);
+    }
+
+    /// Runs `edit` on the dirty-tracked world and returns the bounds of the voxels it changed.
+    fn track_changes<W: LightingWorld>(
+        &mut self,
+        world: &mut W,
+        edit: impl FnOnce(&mut DirtyTracking<'_, W>),
+    ) -> Option<(Coordinates, Coordinates)> {
+        let mut changed = None;
+        edit(&mut DirtyTracking { world, dirty: &mut changed });
+        if let Some((min, max)) = changed {
+            let mut tracked = self.tracking(world);
+            tracked.grow_dirty(min);
+            tracked.grow_dirty(max);
+        }
+        changed
+    }
+
+    /// Re-lights the sky columns over the XZ footprint of `bounds`, keeping any brighter light
+    /// already there, and spreads the gain. Sky light doesn't attenuate going down, which the
+    /// refill of a removal can't reproduce, so every removal finishes with this.
+    fn relight_sky<W: LightingWorld>(&mut self, world: &mut W, bounds: Option<(Coordinates, Coordinates)>) {
+        let (Some(sky), Some((min, max))) = (self.sky, bounds) else {
+            return;
+        };
+        let attenuation = self.attenuation;
+        let mut tracked = self.tracking(world);
+        let mut seeds = Vec::new();
+        for x in min.x..=max.x {
+            for z in min.z..=max.z {
+                let mut light = sky.color;
+                for y in (sky.min_y..=sky.max_y).rev() {
+                    let c = Coordinates::new(x, y, z);
+                    let opacity = tracked.get_opacity(c);
+                    if opacity == 255 {
+                        break;
+                    }
+                    light = light.map(|l| l.saturating_sub(opacity));
+                    if light == [0, 0, 0] {
+                        break;
+                    }
+                    let current = tracked.get_light(c);
+                    let merged = [0, 1, 2].map(|i| current[i].max(light[i]));
+                    if merged != current {
+                        tracked.set_light(c, merged);
+                        seeds.push(c);
+                    }
+                }
+            }
+        }
+        propagate(&mut tracked, &seeds, attenuation);
+    }
+
+    /// Restores every tracked source to at least its own color and spreads any gain.
+    fn reseed_sources<W: LightingWorld>(&mut self, world: &mut W) {
+        let attenuation = self.attenuation;
+        let sources: Vec<(Coordinates, [u8; 3])> = self.sources.iter().map(|(&c, &l)| (c, l)).collect();
+        let mut tracked = self.tracking(world);
+        let mut seeds = Vec::new();
+        for (coords, color) in sources {
+            let current = tracked.get_light(coords);
+            let merged = [current[0].max(color[0]), current[1].max(color[1]), current[2].max(color[2])];
+            if merged != current {
+                tracked.set_light(coords, merged);
+            }
+            seeds.push(coords);
+        }
+        propagate(&mut tracked, &seeds, attenuation);
+    }
+
+    fn tracking<'a, W: LightingWorld>(&'a mut self, world: &'a mut W) -> DirtyTracking<'a, W> {
+        DirtyTracking { world, dirty: &mut self.dirty }
+    }
+}
+
+/// Forwards to the wrapped world while growing the dirty bounds on every light write.
+struct DirtyTracking<'a, W: LightingWorld> {
+    world: &'a mut W,
+    dirty: &'a mut Option<(Coordinates, Coordinates)>,
+}
+
+impl<W: LightingWorld> LightingWorld for DirtyTracking<'_, W> {
+    fn get_opacity(&self, cords: Coordinates) -> u8 {
+        self.world.get_opacity(cords)
+    }
+
+    fn get_light(&self, cords: Coordinates) -> [u8; 3] {
+        self.world.get_light(cords)
+    }
+
+    fn set_light(&mut self, cords: Coordinates, color: [u8; 3]) {
+        self.world.set_light(cords, color);
+        self.grow_dirty(cords);
+    }
+
+    fn get_sky_light(&self, cords: Coordinates) -> u8 {
+        self.world.get_sky_light(cords)
+    }
+
+    fn set_sky_light(&mut self, cords: Coordinates, intensity: u8) {
+        self.world.set_sky_light(cords, intensity);
+        self.grow_dirty(cords);
+    }
+}
+
+impl<W: LightingWorld> DirtyTracking<'_, W> {
+    fn grow_dirty(&mut self, cords: Coordinates) {
+        *self.dirty = Some(match *self.dirty {
+            None => (cords, cords),
+            Some((min, max)) => (
+                Coordinates::new(min.x.min(cords.x), min.y.min(cords.y), min.z.min(cords.z)),
+                Coordinates::new(max.x.max(cords.x), max.y.max(cords.y), max.z.max(cords.z)),
+            ),
+        });
+    }
+}
@@ -7,4 +7,20 @@ pub trait LightingWorld {
     fn get_opacity(&self, cords: Coordinates) -> u8;
     fn get_light(&self, cords: Coordinates) -> [u8; 3];
     fn set_light(&mut self, cords: Coordinates, color: [u8; 3]);
-}
\ No newline at end of file
+
+    /// Returns the sky light accessibility at the given coordinates, for worlds that keep it
+    /// in its own channel (like the alpha of a [`Lightmap`](crate::lighting::lightmap::Lightmap)).
+    /// Defaults to 0.
+    fn get_sky_light(&self, _cords: Coordinates) -> u8 {
+        0
+    }
+
+    /// Sets the sky light accessibility; does nothing by default.
+    fn set_sky_light(&mut self, _cords: Coordinates, _intensity: u8) {}
+}
+
+/// A [`LightingWorld`] whose block opacity can be edited, used by `LightEngine::set_opacity`.
+pub trait OpacityWorld: LightingWorld {
+    /// Sets the opacity of the block at the given coordinates.
+    fn set_opacity(&mut self, cords: Coordinates, opacity: u8);
+}
@@ -1,5 +1,15 @@
 use crate::graphics::texture::texture_3d::Texture3D;
 
+/// A box of lightmap texels copied out for a partial upload.
+pub struct LightmapRegion {
+    /// Texel offset of the box inside the lightmap.
+    pub offset: (u32, u32, u32),
+    /// Box size in texels.
+    pub size: (u32, u32, u32),
+    /// Tightly packed RGBA bytes, X-major like [`Lightmap::as_bytes`].
+    pub bytes: Vec<u8>,
+}
+
 /// CPU-side 3D light data for a chunk.
 /// Stores Block Light (RGB) and Sky Light Accessibility (A) per voxel.
 pub struct Lightmap {
@@ -24,6 +34,41 @@ impl Lightmap {
         }
     }
 
+    /// Sets the block light color; shorthand for [`Self::set_block_light`].
+    pub fn set(&mut self, x: u32, y: u32, z: u32, color: [u8; 3]) {
+        self.set_block_light(x, y, z, color);
+    }
+
+    /// Gets the block light color; shorthand for [`Self::get_block_light`].
+    pub fn get(&self, x: u32, y: u32, z: u32) -> [u8; 3] {
+        self.get_block_light(x, y, z)
+    }
+
+    /// Like [`Self::get`], but returns `None` instead of panicking when the coordinates are
+    /// outside the lightmap (e.g. a neighbour of a voxel on the chunk edge).
+    pub fn get_checked(&self, x: i32, y: i32, z: i32) -> Option<[u8; 3]> {
+        let (x, y, z) = self.checked_coords(x, y, z)?;
+        Some(self.get_block_light(x, y, z))
+    }
+
+    /// Like [`Self::set`], but ignores coordinates outside the lightmap. Returns `true` if the
+    /// voxel was written.
+    pub fn set_checked(&mut self, x: i32, y: i32, z: i32, color: [u8; 3]) -> bool {
+        match self.checked_coords(x, y, z) {
+            Some((x, y, z)) => {
+                self.set_block_light(x, y, z, color);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the coordinates as `u32`s if they're inside the lightmap.
+    fn checked_coords(&self, x: i32, y: i32, z: i32) -> Option<(u32, u32, u32)> {
+        let (x, y, z) = (u32::try_from(x).ok()?, u32::try_from(y).ok()?, u32::try_from(z).ok()?);
+        (x < self.width && y < self.height && z < self.depth).then_some((x, y, z))
+    }
+
     /// Sets the BLOCK light (RGB) only. Preserves the current SKY light (A).
     pub fn set_block_light(&mut self, x: u32, y: u32, z: u32, color: [u8; 3]) {
         let idx = self.index(x, y, z);
@@ -54,6 +99,19 @@ impl Lightmap {
         self.data[self.index(x, y, z)]
     }
 
+    /// Returns the light a voxel receives at the given time of day: sky accessibility scaled
+    /// by `sky_color` and `day_factor` (0.0 = night, 1.0 = noon), maxed per channel with block
+    /// light, so torches keep their brightness as the sky dims. Mirrors the built-in
+    /// `fragment.glsl`, which does the same on the GPU from the uploaded RGBA texels (with
+    /// `RenderEnvironment::sky_intensity` as the day factor), so the channels stay separate in
+    /// [`Self::to_texture_3d`] and only a shader uniform changes as the day passes.
+    pub fn combine(&self, x: u32, y: u32, z: u32, sky_color: [u8; 3], day_factor: f32) -> [u8; 3] {
+        let [r, g, b, sky] = self.get_raw(x, y, z);
+        let scale = sky as f32 / 255.0 * day_factor.clamp(0.0, 1.0);
+        let sky_light = |c: u8| (c as f32 * scale).round() as u8;
+        [r.max(sky_light(sky_color[0])), g.max(sky_light(sky_color[1])), b.max(sky_light(sky_color[2]))]
+    }
+
     /// Resets all voxels to zero (black and dark).
     pub fn clear(&mut self) {
         self.data.fill([0, 0, 0, 0]);
@@ -61,7 +119,6 @@ impl Lightmap {
 
     /// Creates a new GPU 3D texture from this lightmap data.
     pub fn to_texture_3d(&self) -> Texture3D {
-        // IMPORTANT: Ensure your Texture3D::new implementation accepts RGBA8
         let tex = Texture3D::new(self.width, self.height, self.depth);
         tex.update(self.as_bytes());
         tex
@@ -72,13 +129,122 @@ impl Lightmap {
         texture.update(self.as_bytes());
     }
 
-    /// Returns the raw data as a flat byte slice (for direct GL upload).
+    /// Re-uploads only the inclusive box `min..=max` (e.g. [`LightEngine`](crate::lighting::light_engine::LightEngine)
+    /// dirty bounds converted to local coordinates) into the same place in `texture`.
+    /// The box is clamped to the lightmap; an empty box uploads nothing.
+    pub fn upload_region_to(&self, texture: &Texture3D, min: (u32, u32, u32), max: (u32, u32, u32)) {
+        if let Some(region) = self.region_bytes(min, max) {
+            texture.update_region(region.offset, region.size, &region.bytes);
+        }
+    }
+
+    /// Copies the inclusive box `min..=max` (clamped to the lightmap) into a tightly packed
+    /// RGBA buffer, or returns `None` if the box is empty.
+    pub fn region_bytes(&self, min: (u32, u32, u32), max: (u32, u32, u32)) -> Option<LightmapRegion> {
+        let max = (
+            max.0.min(self.width.checked_sub(1)?),
+            max.1.min(self.height.checked_sub(1)?),
+            max.2.min(self.depth.checked_sub(1)?),
+        );
+        if min.0 > max.0 || min.1 > max.1 || min.2 > max.2 {
+            return None;
+        }
+        let size = (max.0 - min.0 + 1, max.1 - min.1 + 1, max.2 - min.2 + 1);
+
+        let bytes = self.as_bytes();
+        let row_len = size.0 as usize * Texture3D::CHANNELS;
+        let mut region = Vec::with_capacity(row_len * size.1 as usize * size.2 as usize);
+        for z in min.2..=max.2 {
+            for y in min.1..=max.1 {
+                let start = self.index(min.0, y, z) * Texture3D::CHANNELS;
+                region.extend_from_slice(&bytes[start..start + row_len]);
+            }
+        }
+        Some(LightmapRegion { offset: min, size, bytes: region })
+    }
+
+    /// Creates a GPU texture of `(width + 2, height + 2, depth + 2)` texels holding this
+    /// lightmap plus a one-voxel border copied from its neighbours (see [`Self::bordered_bytes`]),
+    /// so linear filtering at chunk edges blends with the real neighbouring light instead of
+    /// clamping. Sample it with UVs from [`Self::bordered_uv`].
+    pub fn to_texture_3d_bordered(&self, neighbors: &[Option<&Lightmap>; 6]) -> Texture3D {
+        let tex = Texture3D::new(self.width + 2, self.height + 2, self.depth + 2);
+        tex.update(&self.bordered_bytes(neighbors));
+        tex
+    }
+
+    /// Re-uploads this lightmap and its neighbour border to a texture made by
+    /// [`Self::to_texture_3d_bordered`].
+    pub fn upload_bordered_to(&self, texture: &Texture3D, neighbors: &[Option<&Lightmap>; 6]) {
+        texture.update(&self.bordered_bytes(neighbors));
+    }
+
+    /// Returns RGBA bytes for the volume grown by one voxel on every side. Face border texels
+    /// come from the same-sized neighbour across that face, in `-X, +X, -Y, +Y, -Z, +Z` order
+    /// (as [`ChunkFace`](crate::lighting::propagation::ChunkFace)); edge and corner texels, and
+    /// faces without a neighbour, repeat this lightmap's nearest voxel.
+    pub fn bordered_bytes(&self, neighbors: &[Option<&Lightmap>; 6]) -> Vec<u8> {
+        let (w, h, d) = (self.width as i32, self.height as i32, self.depth as i32);
+        if w == 0 || h == 0 || d == 0 {
+            return Vec::new();
+        }
+        let mut bytes = Vec::with_capacity(((w + 2) * (h + 2) * (d + 2)) as usize * Texture3D::CHANNELS);
+        for z in -1..=d {
+            for y in -1..=h {
+                for x in -1..=w {
+                    bytes.extend_from_slice(&self.border_texel([x, y, z], neighbors));
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Maps a position in voxel units (voxel `i` spans `i..i + 1`) to UVs in a bordered texture.
+    ///
+    /// Shaders should use `uv = (local + 1.0) / (size + 2.0)`: the `+ 1.0` skips the border and
+    /// a voxel center `i + 0.5` lands exactly on its texel center, so linear sampling between
+    /// centers interpolates neighbouring voxels, across chunk edges included.
+    pub fn bordered_uv(&self, local: [f32; 3]) -> [f32; 3] {
+        [
+            (local[0] + 1.0) / (self.width + 2) as f32,
+            (local[1] + 1.0) / (self.height + 2) as f32,
+            (local[2] + 1.0) / (self.depth + 2) as f32,
+        ]
+    }
+
+    /// RGBA of one texel of the bordered volume at `pos` (in `-1..=size` per axis).
+    fn border_texel(&self, pos: [i32; 3], neighbors: &[Option<&Lightmap>; 6]) -> [u8; 4] {
+        let size = [self.width as i32, self.height as i32, self.depth as i32];
+        let clamped = |p: [i32; 3], size: [i32; 3]| {
+            [0, 1, 2].map(|a| p[a].clamp(0, size[a] - 1) as u32)
+        };
+
+        // Only face texels (one axis outside) take a neighbour's voxel
+        let mut outside = (0..3).filter(|&a| pos[a] < 0 || pos[a] >= size[a]);
+        if let (Some(axis), None) = (outside.next(), outside.next()) {
+            let positive = pos[axis] >= size[axis];
+            if let Some(neighbor) = neighbors[axis * 2 + positive as usize] {
+                let n_size = [neighbor.width as i32, neighbor.height as i32, neighbor.depth as i32];
+                if n_size.iter().all(|&s| s > 0) {
+                    let mut p = pos;
+                    p[axis] = if positive { 0 } else { n_size[axis] - 1 };
+                    let [x, y, z] = clamped(p, n_size);
+                    return neighbor.get_raw(x, y, z);
+                }
+            }
+        }
+        let [x, y, z] = clamped(pos, size);
+        self.get_raw(x, y, z)
+    }
+
+    /// Returns the raw data as a flat byte slice (for direct GL upload): RGBA, matching
+    /// [`Texture3D::CHANNELS`], so the length is `width * height * depth * 4`.
     pub fn as_bytes(&self) -> &[u8] {
         // Safety: [u8; 4] has no padding, so the cast is valid.
         unsafe {
             std::slice::from_raw_parts(
                 self.data.as_ptr() as *const u8,
-                self.data.len() * 4, // 4 bytes per voxel now!
+                self.data.len() * Texture3D::CHANNELS,
             )
         }
     }
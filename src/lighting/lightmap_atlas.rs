@@ -0,0 +1,117 @@
+use nalgebra_glm as glm;
+use crate::graphics::texture::texture_3d::Texture3D;
+use crate::lighting::lightmap::Lightmap;
+
+/// Shader uniform holding the UV offset of a chunk's slot inside the atlas.
+pub const LIGHTMAP_OFFSET_UNIFORM: &str = "u_LightmapOffset";
+/// Shader uniform holding the UV size of one slot inside the atlas.
+pub const LIGHTMAP_SCALE_UNIFORM: &str = "u_LightmapScale";
+
+/// Packs many equally-sized chunk lightmaps into a single shared [`Texture3D`].
+///
+/// Slots are laid out on a 3D grid (row-major: X, then Y, then Z). The atlas texture is
+/// bound once (e.g. as a material texture) and each chunk draw only sets
+/// [`LIGHTMAP_OFFSET_UNIFORM`] and [`LIGHTMAP_SCALE_UNIFORM`], so the shader samples
+/// `offset + local_uv * scale`.
+///
+/// Capacity limits: each axis of [`Self::texture_size`] must not exceed
+/// `GL_MAX_3D_TEXTURE_SIZE` (at least 2048 on OpenGL 4.5), and the texture uses
+/// 4 bytes per texel. A 16x16x16 grid of 32³ chunks is 512³ texels, i.e. 512 MiB.
+/// With linear filtering, the outermost half texel of a slot blends with its neighbour.
+pub struct LightmapAtlas {
+    slot_size: (u32, u32, u32),
+    grid: (u32, u32, u32),
+    next_slot: u32,
+    free_slots: Vec<u32>,
+}
+
+impl LightmapAtlas {
+    /// Creates an atlas layout with `grid` slots per axis, each `slot_size` voxels.
+    pub fn new(slot_size: (u32, u32, u32), grid: (u32, u32, u32)) -> Self {
+        Self {
+            slot_size,
+            grid,
+            next_slot: 0,
+            free_slots: Vec::new(),
+        }
+    }
+
+    /// Size of each slot in voxels.
+    pub fn slot_size(&self) -> (u32, u32, u32) {
+        self.slot_size
+    }
+
+    /// Total number of slots the atlas can hold.
+    pub fn capacity(&self) -> u32 {
+        self.grid.0 * self.grid.1 * self.grid.2
+    }
+
+    /// Dimensions of the backing 3D texture in texels.
+    pub fn texture_size(&self) -> (u32, u32, u32) {
+        (
+            self.slot_size.0 * self.grid.0,
+            self.slot_size.1 * self.grid.1,
+            self.slot_size.2 * self.grid.2,
+        )
+    }
+
+    /// Creates the empty backing GPU texture for this atlas.
+    pub fn create_texture(&self) -> Texture3D {
+        let (w, h, d) = self.texture_size();
+        Texture3D::new(w, h, d)
+    }
+
+    /// Reserves a free slot, or returns `None` if the atlas is full.
+    pub fn allocate(&mut self) -> Option<u32> {
+        if let Some(slot) = self.free_slots.pop() {
+            return Some(slot);
+        }
+        if self.next_slot >= self.capacity() {
+            return None;
+        }
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        Some(slot)
+    }
+
+    /// Returns a slot to the pool so it can be reused by another chunk.
+    pub fn release(&mut self, slot: u32) {
+        self.free_slots.push(slot);
+    }
+
+    /// Returns the slot's position on the atlas grid.
+    pub fn slot_grid_position(&self, slot: u32) -> (u32, u32, u32) {
+        let x = slot % self.grid.0;
+        let y = (slot / self.grid.0) % self.grid.1;
+        let z = slot / (self.grid.0 * self.grid.1);
+        (x, y, z)
+    }
+
+    /// Returns the texel offset of the slot's minimum corner in the atlas texture.
+    pub fn slot_texel_offset(&self, slot: u32) -> (u32, u32, u32) {
+        let (x, y, z) = self.slot_grid_position(slot);
+        (x * self.slot_size.0, y * self.slot_size.1, z * self.slot_size.2)
+    }
+
+    /// Returns the value for [`LIGHTMAP_OFFSET_UNIFORM`] when drawing with this slot.
+    pub fn slot_uv_offset(&self, slot: u32) -> glm::Vec3 {
+        let (ox, oy, oz) = self.slot_texel_offset(slot);
+        let (w, h, d) = self.texture_size();
+        glm::vec3(ox as f32 / w as f32, oy as f32 / h as f32, oz as f32 / d as f32)
+    }
+
+    /// Returns the value for [`LIGHTMAP_SCALE_UNIFORM`]; the same for every slot.
+    pub fn uv_scale(&self) -> glm::Vec3 {
+        glm::vec3(1.0 / self.grid.0 as f32, 1.0 / self.grid.1 as f32, 1.0 / self.grid.2 as f32)
+    }
+
+    /// Uploads a chunk lightmap into its slot. The lightmap must match [`Self::slot_size`].
+    pub fn upload(&self, texture: &Texture3D, slot: u32, lightmap: &Lightmap) {
+        assert_eq!(
+            (lightmap.width, lightmap.height, lightmap.depth),
+            self.slot_size,
+            "Lightmap size does not match atlas slot size"
+        );
+        texture.update_region(self.slot_texel_offset(slot), self.slot_size, lightmap.as_bytes());
+    }
+}
@@ -1,5 +1,7 @@
+pub mod light_engine;
 pub mod lightmap;
+pub mod lightmap_atlas;
 pub mod propagation;
 mod lighting_world;
-pub use lighting_world::LightingWorld;
+pub use lighting_world::{LightingWorld, OpacityWorld};
 mod tests;
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use crate::lighting::lighting_world::LightingWorld;
+use crate::lighting::lightmap::Lightmap;
 use crate::physics::coordinates::Coordinates;
 
 /// Propagates block light from point sources using BFS flood fill.
@@ -11,9 +12,45 @@ pub fn propagate(
     seeds: &[Coordinates],
     attenuation: u8,
 ) {
-    let mut queue: VecDeque<Coordinates> = VecDeque::from(seeds.to_vec());
+    flood(world, seeds, [attenuation; 3], u32::MAX);
+}
+
+/// Like [`propagate`], but each RGB channel drops by its own amount per block, e.g. water
+/// absorbing red faster than blue. Block opacity is still added to every channel.
+pub fn propagate_rgb(
+    world: &mut dyn LightingWorld,
+    seeds: &[Coordinates],
+    attenuation: [u8; 3],
+) {
+    flood(world, seeds, attenuation, u32::MAX);
+}
+
+/// Like [`propagate`], but light stops spreading `max_radius` steps (face-adjacent moves,
+/// i.e. Manhattan distance around obstacles) from the nearest seed, however bright it still
+/// is. Bounds the work for bright lights in large open areas, especially with low attenuation.
+pub fn propagate_limited(
+    world: &mut dyn LightingWorld,
+    seeds: &[Coordinates],
+    attenuation: u8,
+    max_radius: u32,
+) {
+    flood(world, seeds, [attenuation; 3], max_radius);
+}
+
+/// The BFS behind [`propagate`], [`propagate_rgb`] and [`propagate_limited`].
+fn flood(
+    world: &mut dyn LightingWorld,
+    seeds: &[Coordinates],
+    attenuation: [u8; 3],
+    max_radius: u32,
+) {
+    // Queue stores (Position, Steps from its seed)
+    let mut queue: VecDeque<(Coordinates, u32)> = seeds.iter().map(|&c| (c, 0)).collect();
 
-    while let Some(source_cords) = queue.pop_front() {
+    while let Some((source_cords, distance)) = queue.pop_front() {
+        if distance >= max_radius {
+            continue;
+        }
         let current = world.get_light(source_cords);
 
         for neighbour_cords in source_cords.neighbors() {
@@ -23,11 +60,10 @@ pub fn propagate(
                 continue;
             }
 
-            let total_att = attenuation.saturating_add(opacity);
             let attenuated = [
-                current[0].saturating_sub(total_att),
-                current[1].saturating_sub(total_att),
-                current[2].saturating_sub(total_att),
+                current[0].saturating_sub(attenuation[0].saturating_add(opacity)),
+                current[1].saturating_sub(attenuation[1].saturating_add(opacity)),
+                current[2].saturating_sub(attenuation[2].saturating_add(opacity)),
             ];
 
             // If the light level is 0 return
@@ -45,51 +81,236 @@ pub fn propagate(
                     neighbor[2].max(attenuated[2]),
                 ];
                 world.set_light(neighbour_cords, merged);
-                queue.push_back(neighbour_cords);
+                queue.push_back((neighbour_cords, distance + 1));
+            }
+        }
+    }
+}
+
+/// Block light sources of one chunk as `(position, color)` pairs.
+pub type LightSources = Vec<(Coordinates, [u8; 3])>;
+
+/// Lights many independent chunks in parallel: each chunk's `(position, color)` sources are
+/// written into it (keeping any brighter light already there) and spread with [`propagate`].
+///
+/// Chunks are split across the available CPU cores with scoped threads. Every chunk is treated
+/// as a closed volume, so light does not cross into neighbouring chunks here; seed the seams
+/// afterwards with [`propagate_with_borders`] if they need to match.
+pub fn propagate_chunks<W: LightingWorld + Send>(
+    chunks: &mut [(W, LightSources)],
+    attenuation: u8,
+) {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let per_thread = chunks.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        for group in chunks.chunks_mut(per_thread) {
+            scope.spawn(move || {
+                for (world, sources) in group {
+                    seed_sources(world, sources);
+                    let seeds: Vec<Coordinates> = sources.iter().map(|&(c, _)| c).collect();
+                    propagate(world, &seeds, attenuation);
+                }
+            });
+        }
+    });
+}
+
+/// Raises each source voxel to at least its color.
+fn seed_sources(world: &mut dyn LightingWorld, sources: &[(Coordinates, [u8; 3])]) {
+    for &(coords, color) in sources {
+        let current = world.get_light(coords);
+        let merged = [current[0].max(color[0]), current[1].max(color[1]), current[2].max(color[2])];
+        if merged != current {
+            world.set_light(coords, merged);
+        }
+    }
+}
+
+/// A face of a chunk, named by the direction it points in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkFace {
+    NegX,
+    PosX,
+    NegY,
+    PosY,
+    NegZ,
+    PosZ,
+}
+
+/// Light entering a chunk through one of its boundary voxels from the neighbouring chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderLight {
+    /// Boundary voxel of the receiving chunk the light enters (its local coordinates).
+    pub coords: Coordinates,
+    /// Face of the receiving chunk the light enters through.
+    pub face: ChunkFace,
+    /// Block light of the neighbour's voxel directly across the face, before attenuation.
+    pub light: [u8; 3],
+    /// Sky light accessibility of that voxel, before attenuation.
+    pub sky: u8,
+}
+
+impl BorderLight {
+    /// Collects the light entering `face` of a chunk from `neighbor`, the same-sized chunk
+    /// lightmap on the other side of that face, both block and sky light. Neighbour voxels with
+    /// neither are skipped.
+    pub fn from_neighbor(neighbor: &Lightmap, face: ChunkFace) -> Vec<BorderLight> {
+        let (w, h, d) = (neighbor.width, neighbor.height, neighbor.depth);
+        if w == 0 || h == 0 || d == 0 {
+            return Vec::new();
+        }
+        // (neighbour voxel, receiving voxel) for a point (a, b) on the face plane
+        let map = |a: u32, b: u32| -> ((u32, u32, u32), (u32, u32, u32)) {
+            match face {
+                ChunkFace::NegX => ((w - 1, a, b), (0, a, b)),
+                ChunkFace::PosX => ((0, a, b), (w - 1, a, b)),
+                ChunkFace::NegY => ((a, h - 1, b), (a, 0, b)),
+                ChunkFace::PosY => ((a, 0, b), (a, h - 1, b)),
+                ChunkFace::NegZ => ((a, b, d - 1), (a, b, 0)),
+                ChunkFace::PosZ => ((a, b, 0), (a, b, d - 1)),
             }
+        };
+        let (extent_a, extent_b) = match face {
+            ChunkFace::NegX | ChunkFace::PosX => (h, d),
+            ChunkFace::NegY | ChunkFace::PosY => (w, d),
+            ChunkFace::NegZ | ChunkFace::PosZ => (w, h),
+        };
+
+        let mut borders = Vec::new();
+        for a in 0..extent_a {
+            for b in 0..extent_b {
+                let ((nx, ny, nz), (x, y, z)) = map(a, b);
+                let light = neighbor.get_block_light(nx, ny, nz);
+                let sky = neighbor.get_sky_light(nx, ny, nz);
+                if light != [0, 0, 0] || sky != 0 {
+                    let coords = Coordinates::new(x as i32, y as i32, z as i32);
+                    borders.push(BorderLight { coords, face, light, sky });
+                }
+            }
+        }
+        borders
+    }
+}
+
+/// Like [`propagate`] from `sources`, but also lets light flow in across chunk edges: each
+/// border light is attenuated by one step (plus the boundary voxel's opacity) into its
+/// boundary voxel, and those voxels seed the flood fill with the sources. Build `borders`
+/// from the neighbours' lightmaps with [`BorderLight::from_neighbor`].
+///
+/// Border sky light is spread the same way through the world's sky channel
+/// ([`LightingWorld::get_sky_light`]), except that, as in [`propagate_sky`], it loses nothing
+/// but opacity going straight down, so sky columns continue unbroken into the chunk below.
+pub fn propagate_with_borders(
+    world: &mut dyn LightingWorld,
+    sources: &[(Coordinates, [u8; 3])],
+    borders: &[BorderLight],
+    attenuation: u8,
+) {
+    seed_sources(world, sources);
+    let mut seeds: Vec<Coordinates> = sources.iter().map(|&(c, _)| c).collect();
+    let mut sky_seeds = Vec::new();
+
+    for border in borders {
+        let opacity = world.get_opacity(border.coords);
+        if opacity == 255 {
+            continue;
+        }
+        let total_att = attenuation.saturating_add(opacity);
+        let entering = border.light.map(|c| c.saturating_sub(total_att));
+        let current = world.get_light(border.coords);
+        if entering.iter().zip(current).any(|(&e, c)| e > c) {
+            seed_sources(world, &[(border.coords, entering)]);
+            seeds.push(border.coords);
+        }
+
+        // Entering through the top face is moving straight down
+        let sky_att = if border.face == ChunkFace::PosY { opacity } else { total_att };
+        let entering_sky = border.sky.saturating_sub(sky_att);
+        if entering_sky > world.get_sky_light(border.coords) {
+            world.set_sky_light(border.coords, entering_sky);
+            sky_seeds.push(border.coords);
         }
     }
+
+    propagate(world, &seeds, attenuation);
+    flood_sky(world, &sky_seeds, attenuation);
+}
+
+/// BFS over the sky channel: each step costs the destination's opacity plus `attenuation`,
+/// except steps straight down, which only cost opacity.
+fn flood_sky(world: &mut dyn LightingWorld, seeds: &[Coordinates], attenuation: u8) {
+    let mut queue: VecDeque<Coordinates> = seeds.iter().copied().collect();
+
+    while let Some(pos) = queue.pop_front() {
+        let current = world.get_sky_light(pos);
+        for neighbor in pos.neighbors() {
+            let opacity = world.get_opacity(neighbor);
+            if opacity == 255 {
+                continue;
+            }
+            let step = if neighbor.y < pos.y { opacity } else { attenuation.saturating_add(opacity) };
+            let level = current.saturating_sub(step);
+            if level > world.get_sky_light(neighbor) {
+                world.set_sky_light(neighbor, level);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+}
+
+/// Removes the light a destroyed source at `source` contributed and refills the cleared
+/// region from the remaining brighter light around it. Shorthand for [`unpropagate`] with
+/// a single seed.
+pub fn remove_light(
+    world: &mut dyn LightingWorld,
+    source: Coordinates,
+    attenuation: u8,
+) {
+    unpropagate(world, &[source], attenuation);
 }
 
 /// Removes light starting from the given coordinates.
 ///
-/// This will zero out light that originated from these seeds,
-/// then trigger a re-propagation from surrounding light sources.
+/// Standard two-queue removal, run per RGB channel: the seeds are zeroed, then every lit
+/// neighbour dimmer than the voxel it was reached from is zeroed too, since its light can
+/// only have come from there. Neighbours at least as bright are lit by something else and
+/// become refill seeds, which [`propagate`] then spreads back into the cleared region.
 pub fn unpropagate(
     world: &mut dyn LightingWorld,
     seeds: &[Coordinates],
     attenuation: u8,
 ) {
-    // Queue stores (Position, OldLightValue)
-    let mut removal_queue: VecDeque<(Coordinates, [u8; 3])> = VecDeque::new();
+    // Queue stores (Position, OldLightValue) for the channel being removed
+    let mut removal_queue: VecDeque<(Coordinates, u8)> = VecDeque::new();
     let mut refill_seeds: Vec<Coordinates> = Vec::new();
 
-    // 1. Initial Seeding
-    for &coords in seeds {
-        let old_light = world.get_light(coords);
-        world.set_light(coords, [0, 0, 0]);
-        removal_queue.push_back((coords, old_light));
-    }
+    for channel in 0..3 {
+        // 1. Initial Seeding
+        for &coords in seeds {
+            let mut light = world.get_light(coords);
+            if light[channel] == 0 {
+                continue;
+            }
+            removal_queue.push_back((coords, light[channel]));
+            light[channel] = 0;
+            world.set_light(coords, light);
+        }
+
+        // 2. The Siphon Pass
+        while let Some((pos, old_level)) = removal_queue.pop_front() {
+            for neighbor_pos in pos.neighbors() {
+                let mut neighbor_light = world.get_light(neighbor_pos);
+                let level = neighbor_light[channel];
+                if level == 0 {
+                    continue;
+                }
 
-    // 2. The Siphon Pass
-    while let Some((pos, old_light)) = removal_queue.pop_front() {
-        for neighbor_pos in pos.neighbors() {
-            let neighbor_light = world.get_light(neighbor_pos);
-
-            // If the neighbor has light, we need to check if it came from the block we're removing
-            if neighbor_light != [0, 0, 0] {
-                // Is this neighbor's light an attenuated version of our old light?
-                // Account for opacity of the neighbor block
-                let neighbor_opacity = world.get_opacity(neighbor_pos);
-                let total_att = attenuation.saturating_add(neighbor_opacity);
-                let is_derived = neighbor_light[0] == old_light[0].saturating_sub(total_att) &&
-                    neighbor_light[1] == old_light[1].saturating_sub(total_att) &&
-                    neighbor_light[2] == old_light[2].saturating_sub(total_att);
-
-                if is_derived {
-                    // It's part of the same light "tree", so remove it
-                    world.set_light(neighbor_pos, [0, 0, 0]);
-                    removal_queue.push_back((neighbor_pos, neighbor_light));
+                if level < old_level {
+                    // Dimmer than where we came from, so it's part of the removed light "tree"
+                    neighbor_light[channel] = 0;
+                    world.set_light(neighbor_pos, neighbor_light);
+                    removal_queue.push_back((neighbor_pos, level));
                 } else {
                     // This neighbor has light that didn't come from us!
                     // It will help refill the vacuum.
@@ -100,7 +321,7 @@ pub fn unpropagate(
     }
 
     // 3. The Refill Pass
-    // Reuse your existing propagation logic to fill the area back in
+    // Reuse the existing propagation logic to fill the area back in
     // using the light from neighbors that were unaffected.
     if !refill_seeds.is_empty() {
         propagate(world, &refill_seeds, attenuation);
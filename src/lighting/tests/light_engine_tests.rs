@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use crate::lighting::light_engine::{LightEngine, SkyLight};
+    use crate::lighting::lighting_world::{LightingWorld, OpacityWorld};
+    use crate::lighting::propagation::{propagate, propagate_sky};
+    use crate::physics::coordinates::Coordinates;
+
+    /// Dense test world with editable opacity; out of bounds is opaque and dark.
+    #[derive(Clone)]
+    struct GridWorld {
+        size: (i32, i32, i32),
+        opacity: Vec<u8>,
+        light: Vec<[u8; 3]>,
+    }
+
+    impl GridWorld {
+        fn new(w: i32, h: i32, d: i32) -> Self {
+            let len = (w * h * d) as usize;
+            Self { size: (w, h, d), opacity: vec![0; len], light: vec![[0, 0, 0]; len] }
+        }
+
+        fn index(&self, c: Coordinates) -> Option<usize> {
+            let (w, h, d) = self.size;
+            if c.x < 0 || c.y < 0 || c.z < 0 || c.x >= w || c.y >= h || c.z >= d {
+                return None;
+            }
+            Some((c.x + c.y * w + c.z * w * h) as usize)
+        }
+
+        fn clear_light(&mut self) {
+            self.light.fill([0, 0, 0]);
+        }
+    }
+
+    impl LightingWorld for GridWorld {
+        fn get_opacity(&self, cords: Coordinates) -> u8 {
+            self.index(cords).map_or(255, |i| self.opacity[i])
+        }
+
+        fn get_light(&self, cords: Coordinates) -> [u8; 3] {
+            self.index(cords).map_or([0, 0, 0], |i| self.light[i])
+        }
+
+        fn set_light(&mut self, cords: Coordinates, color: [u8; 3]) {
+            if let Some(i) = self.index(cords) {
+                self.light[i] = color;
+            }
+        }
+    }
+
+    impl OpacityWorld for GridWorld {
+        fn set_opacity(&mut self, cords: Coordinates, opacity: u8) {
+            if let Some(i) = self.index(cords) {
+                self.opacity[i] = opacity;
+            }
+        }
+    }
+
+    const ATT: u8 = 17;
+    const SKY: SkyLight = SkyLight { color: [200, 200, 255], min_y: 0, max_y: 5 };
+
+    fn c(x: i32, y: i32, z: i32) -> Coordinates {
+        Coordinates::new(x, y, z)
+    }
+
+    /// Recomputes sky light from scratch over the whole 6x6x6 grid.
+    fn full_sky_recompute(world: &mut GridWorld) {
+        world.clear_light();
+        propagate_sky(world, c(0, SKY.min_y, 0), c(5, SKY.max_y, 5), SKY.color, ATT);
+    }
+
+    fn roofed_world() -> GridWorld {
+        let mut world = GridWorld::new(6, 6, 6);
+        for x in 0..6 {
+            for z in 0..6 {
+                world.set_opacity(c(x, 4, z), 255);
+            }
+        }
+        full_sky_recompute(&mut world);
+        world
+    }
+
+    #[test]
+    fn place_then_remove_restores_prior_state() {
+        let mut world = GridWorld::new(9, 3, 3);
+        let mut engine = LightEngine::new(ATT);
+        engine.place_light(&mut world, c(0, 1, 1), [255, 0, 0]);
+        let before = world.light.clone();
+
+        engine.place_light(&mut world, c(7, 1, 1), [0, 180, 90]);
+        assert_eq!(world.get_light(c(6, 1, 1)), [153, 163, 73]);
+
+        engine.remove_light(&mut world, c(7, 1, 1));
+        assert_eq!(world.light, before);
+        assert_eq!(engine.sources().len(), 1);
+    }
+
+    #[test]
+    fn placed_light_matches_direct_propagation() {
+        let mut world = GridWorld::new(5, 5, 5);
+        let mut engine = LightEngine::new(ATT);
+        engine.place_light(&mut world, c(2, 2, 2), [255, 128, 64]);
+
+        let mut expected = GridWorld::new(5, 5, 5);
+        expected.set_light(c(2, 2, 2), [255, 128, 64]);
+        propagate(&mut expected, &[c(2, 2, 2)], ATT);
+
+        assert_eq!(world.light, expected.light);
+    }
+
+    #[test]
+    fn breaking_roof_floods_sky_like_full_recompute() {
+        let mut world = roofed_world();
+        let mut engine = LightEngine::new(ATT).with_sky(SKY);
+        assert_eq!(world.get_light(c(2, 0, 2)), [0, 0, 0]);
+
+        engine.set_opacity(&mut world, c(2, 4, 2), 0);
+
+        let mut expected = world.clone();
+        full_sky_recompute(&mut expected);
+        assert_eq!(world.light, expected.light);
+        assert_eq!(world.get_light(c(2, 0, 2)), SKY.color);
+    }
+
+    #[test]
+    fn placing_block_back_restores_shadow() {
+        let mut world = roofed_world();
+        let before = world.light.clone();
+        let mut engine = LightEngine::new(ATT).with_sky(SKY);
+
+        engine.set_opacity(&mut world, c(2, 4, 2), 0);
+        engine.set_opacity(&mut world, c(2, 4, 2), 255);
+
+        assert_eq!(world.light, before);
+    }
+
+    #[test]
+    fn dirty_bounds_cover_changed_region() {
+        let mut world = GridWorld::new(9, 9, 9);
+        let mut engine = LightEngine::new(100);
+        assert_eq!(engine.dirty_bounds(), None);
+
+        engine.place_light(&mut world, c(4, 4, 4), [255, 255, 255]);
+
+        // 255 light with attenuation 100 reaches two blocks in each direction
+        assert_eq!(engine.take_dirty(), Some((c(2, 2, 2), c(6, 6, 6))));
+        assert_eq!(engine.dirty_bounds(), None);
+    }
+
+    #[test]
+    fn removal_only_touches_voxels_the_light_reached() {
+        let sky = SkyLight { color: [200, 200, 255], min_y: 0, max_y: 11 };
+        let mut world = GridWorld::new(9, 12, 9);
+        for x in 0..9 {
+            for z in 0..9 {
+                world.set_opacity(c(x, 10, z), 255);
+            }
+        }
+        propagate_sky(&mut world, c(0, sky.min_y, 0), c(8, sky.max_y, 8), sky.color, ATT);
+        let before = world.light.clone();
+        let mut engine = LightEngine::new(ATT).with_sky(sky);
+        engine.place_light(&mut world, c(4, 8, 4), [60, 60, 60]);
+        engine.take_dirty();
+
+        engine.remove_light(&mut world, c(4, 8, 4));
+
+        // 60 light with attenuation 17 reaches three blocks; nothing below that is revisited
+        let (min, max) = engine.take_dirty().unwrap();
+        assert!(min.y >= 5 && max.y <= 9);
+        assert_eq!(world.light, before);
+    }
+
+    #[test]
+    fn roof_edits_keep_torch_light_under_it() {
+        let mut world = roofed_world();
+        let mut engine = LightEngine::new(ATT).with_sky(SKY);
+        engine.place_light(&mut world, c(2, 1, 2), [255, 40, 0]);
+        let before = world.light.clone();
+
+        engine.set_opacity(&mut world, c(2, 4, 2), 0);
+        assert_eq!(world.get_light(c(2, 1, 2)), [255, 200, 255]);
+
+        engine.set_opacity(&mut world, c(2, 4, 2), 255);
+        assert_eq!(world.light, before);
+    }
+}
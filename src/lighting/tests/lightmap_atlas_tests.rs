@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use crate::lighting::lightmap_atlas::LightmapAtlas;
+    use nalgebra_glm as glm;
+
+    #[test]
+    fn capacity_and_texture_size() {
+        let atlas = LightmapAtlas::new((32, 32, 32), (4, 2, 3));
+        assert_eq!(atlas.capacity(), 24);
+        assert_eq!(atlas.texture_size(), (128, 64, 96));
+    }
+
+    #[test]
+    fn slot_grid_position_is_row_major() {
+        let atlas = LightmapAtlas::new((16, 16, 16), (4, 3, 2));
+        assert_eq!(atlas.slot_grid_position(0), (0, 0, 0));
+        assert_eq!(atlas.slot_grid_position(1), (1, 0, 0));
+        assert_eq!(atlas.slot_grid_position(4), (0, 1, 0));   // x wraps after grid.0 = 4
+        assert_eq!(atlas.slot_grid_position(12), (0, 0, 1));  // z after grid.0 * grid.1 = 12
+        assert_eq!(atlas.slot_grid_position(23), (3, 2, 1));  // last slot
+    }
+
+    #[test]
+    fn slot_texel_offsets() {
+        let atlas = LightmapAtlas::new((32, 16, 8), (4, 3, 2));
+        assert_eq!(atlas.slot_texel_offset(0), (0, 0, 0));
+        assert_eq!(atlas.slot_texel_offset(1), (32, 0, 0));
+        assert_eq!(atlas.slot_texel_offset(5), (32, 16, 0));
+        assert_eq!(atlas.slot_texel_offset(23), (96, 32, 8));
+    }
+
+    #[test]
+    fn slot_uv_offset_and_scale() {
+        let atlas = LightmapAtlas::new((32, 32, 32), (4, 2, 1));
+        assert_eq!(atlas.uv_scale(), glm::vec3(0.25, 0.5, 1.0));
+        assert_eq!(atlas.slot_uv_offset(0), glm::vec3(0.0, 0.0, 0.0));
+        assert_eq!(atlas.slot_uv_offset(3), glm::vec3(0.75, 0.0, 0.0));
+        assert_eq!(atlas.slot_uv_offset(5), glm::vec3(0.25, 0.5, 0.0));
+
+        // A local UV of 1.0 in one slot lands exactly on the next slot's offset
+        let end_of_slot_0 = atlas.slot_uv_offset(0) + glm::vec3(1.0, 0.0, 0.0).component_mul(&atlas.uv_scale());
+        assert_eq!(end_of_slot_0, atlas.slot_uv_offset(1));
+    }
+
+    #[test]
+    fn allocate_until_full_then_reuse_released() {
+        let mut atlas = LightmapAtlas::new((8, 8, 8), (2, 1, 1));
+        assert_eq!(atlas.allocate(), Some(0));
+        assert_eq!(atlas.allocate(), Some(1));
+        assert_eq!(atlas.allocate(), None);
+
+        atlas.release(0);
+        assert_eq!(atlas.allocate(), Some(0));
+        assert_eq!(atlas.allocate(), None);
+    }
+}
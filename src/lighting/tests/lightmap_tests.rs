@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use crate::graphics::texture::texture_3d::{check_region, Texture3D};
     use crate::lighting::lightmap::Lightmap;
     use super::*;
 
@@ -88,7 +89,7 @@ mod tests {
     #[test]
     fn as_bytes_length() {
         let lm = Lightmap::new(4, 3, 2);
-        assert_eq!(lm.as_bytes().len(), 4 * 3 * 2 * 3); // w*h*d * 3 bytes per voxel
+        assert_eq!(lm.as_bytes().len(), 4 * 3 * 2 * 4); // w*h*d * 4 bytes per voxel (RGBA)
     }
 
     #[test]
@@ -97,7 +98,8 @@ mod tests {
         lm.set(0, 0, 0, [10, 20, 30]);
         lm.set(1, 0, 0, [40, 50, 60]);
         let bytes = lm.as_bytes();
-        assert_eq!(bytes, &[10, 20, 30, 40, 50, 60]);
+        // Block light in RGB, sky light (still 0) in A
+        assert_eq!(bytes, &[10, 20, 30, 0, 40, 50, 60, 0]);
     }
 
     #[test]
@@ -119,6 +121,128 @@ mod tests {
         assert_eq!(lm.get(0, 0, 0), [0, 0, 0]);
         lm.set(0, 0, 0, [42, 43, 44]);
         assert_eq!(lm.get(0, 0, 0), [42, 43, 44]);
-        assert_eq!(lm.as_bytes(), &[42, 43, 44]);
+        assert_eq!(lm.as_bytes(), &[42, 43, 44, 0]);
+    }
+
+    #[test]
+    fn region_bytes_extracts_box() {
+        let mut lm = Lightmap::new(4, 4, 4);
+        lm.set_block_light(1, 2, 3, [10, 20, 30]);
+        lm.set_block_light(2, 2, 3, [40, 50, 60]);
+        lm.set_sky_light(2, 2, 3, 70);
+
+        let region = lm.region_bytes((1, 2, 3), (2, 2, 3)).unwrap();
+        assert_eq!(region.offset, (1, 2, 3));
+        assert_eq!(region.size, (2, 1, 1));
+        assert_eq!(region.bytes, vec![10, 20, 30, 0, 40, 50, 60, 70]);
+    }
+
+    #[test]
+    fn region_bytes_rows_follow_texture_order() {
+        let mut lm = Lightmap::new(3, 3, 3);
+        lm.set_block_light(0, 0, 0, [1, 1, 1]);
+        lm.set_block_light(0, 1, 0, [2, 2, 2]);
+        lm.set_block_light(0, 0, 1, [3, 3, 3]);
+
+        let region = lm.region_bytes((0, 0, 0), (0, 1, 1)).unwrap();
+        assert_eq!(region.size, (1, 2, 2));
+        let reds: Vec<u8> = region.bytes.chunks(4).map(|texel| texel[0]).collect();
+        assert_eq!(reds, vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn region_bytes_clamps_and_rejects_empty() {
+        let lm = Lightmap::new(2, 2, 2);
+        let region = lm.region_bytes((1, 1, 1), (10, 10, 10)).unwrap();
+        assert_eq!(region.size, (1, 1, 1));
+        assert_eq!(region.bytes.len(), 4);
+
+        assert!(lm.region_bytes((2, 0, 0), (5, 1, 1)).is_none());
+    }
+
+    #[test]
+    fn as_bytes_fills_texture_3d_exactly() {
+        let (w, h, d) = (5, 3, 2);
+        let mut lm = Lightmap::new(w, h, d);
+        lm.set(4, 2, 1, [7, 8, 9]);
+        lm.set_sky_light(4, 2, 1, 10);
+
+        let bytes = lm.as_bytes();
+        assert_eq!(bytes.len(), (w * h * d) as usize * Texture3D::CHANNELS);
+        assert!(check_region((w, h, d), (0, 0, 0), (w, h, d), bytes.len()).is_ok());
+
+        // The last voxel lands at the last texel of the upload
+        let last = lm.index(4, 2, 1) * Texture3D::CHANNELS;
+        assert_eq!(&bytes[last..last + Texture3D::CHANNELS], &[7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn combine_dims_sky_but_not_block_light() {
+        let mut lm = Lightmap::new(2, 1, 1);
+        lm.set_sky_light(0, 0, 0, 255);
+        lm.set_block_light(1, 0, 0, [200, 120, 40]);
+        lm.set_sky_light(1, 0, 0, 255);
+
+        let sky = [200, 200, 255];
+        assert_eq!(lm.combine(0, 0, 0, sky, 1.0), [200, 200, 255]);
+        assert_eq!(lm.combine(0, 0, 0, sky, 0.5), [100, 100, 128]);
+        assert_eq!(lm.combine(0, 0, 0, sky, 0.0), [0, 0, 0]);
+
+        // At night the torch wins; at noon the sky does where it's brighter
+        assert_eq!(lm.combine(1, 0, 0, sky, 0.0), [200, 120, 40]);
+        assert_eq!(lm.combine(1, 0, 0, sky, 1.0), [200, 200, 255]);
+    }
+
+    #[test]
+    fn combine_scales_by_sky_access() {
+        let mut lm = Lightmap::new(1, 1, 1);
+        lm.set_sky_light(0, 0, 0, 51);
+        assert_eq!(lm.combine(0, 0, 0, [255, 255, 255], 1.0), [51, 51, 51]);
+    }
+
+    #[test]
+    fn bordered_bytes_copies_face_neighbors() {
+        let mut center = Lightmap::new(2, 2, 2);
+        center.set(0, 0, 0, [10, 10, 10]);
+        let mut left = Lightmap::new(2, 2, 2);
+        left.set(1, 0, 0, [200, 0, 0]);
+        let neighbors = [Some(&left), None, None, None, None, None];
+
+        let bytes = center.bordered_bytes(&neighbors);
+        assert_eq!(bytes.len(), 4 * 4 * 4 * Texture3D::CHANNELS);
+
+        let texel = |x: usize, y: usize, z: usize| {
+            let i = (x + y * 4 + z * 16) * Texture3D::CHANNELS;
+            [bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]
+        };
+        // Inner voxel (0, 0, 0) sits at bordered (1, 1, 1); the -X border texel next to it
+        // comes from the left neighbour's +X edge
+        assert_eq!(texel(1, 1, 1), [10, 10, 10, 0]);
+        assert_eq!(texel(0, 1, 1), [200, 0, 0, 0]);
+        // No +X neighbour: the border repeats the edge voxel
+        assert_eq!(texel(3, 1, 1), center.get_raw(1, 0, 0));
+        // Corners clamp to this lightmap
+        assert_eq!(texel(0, 0, 0), [10, 10, 10, 0]);
+    }
+
+    #[test]
+    fn bordered_uv_hits_texel_centers() {
+        let lm = Lightmap::new(32, 32, 32);
+        // Voxel 0's center is texel 1's center of the 34-texel texture
+        assert_eq!(lm.bordered_uv([0.5, 0.5, 0.5]), [1.5 / 34.0; 3]);
+        assert_eq!(lm.bordered_uv([31.5, 0.5, 0.5])[0], 32.5 / 34.0);
+    }
+
+    #[test]
+    fn checked_access_rejects_out_of_range() {
+        let mut lm = Lightmap::new(4, 3, 2);
+        assert!(lm.set_checked(3, 2, 1, [1, 2, 3]));
+        assert_eq!(lm.get_checked(3, 2, 1), Some([1, 2, 3]));
+
+        for (x, y, z) in [(4, 0, 0), (0, 3, 0), (0, 0, 2), (-1, 0, 0), (0, -1, 0), (0, 0, -1)] {
+            assert_eq!(lm.get_checked(x, y, z), None);
+            assert!(!lm.set_checked(x, y, z, [9, 9, 9]));
+        }
+        assert!(lm.as_bytes().iter().all(|&b| b != 9));
     }
 }
@@ -1,2 +1,4 @@
 pub mod propagation_tests;
-mod lightmap_tests;
\ No newline at end of file
+mod lightmap_tests;
+mod lightmap_atlas_tests;
+mod light_engine_tests;
@@ -2,7 +2,9 @@
 mod tests {
     use crate::lighting::lighting_world::LightingWorld;
     use crate::lighting::lightmap::Lightmap;
-    use crate::lighting::propagation::{propagate, propagate_sky};
+    use crate::lighting::propagation::{
+        propagate, propagate_chunks, propagate_limited, propagate_rgb, propagate_sky, propagate_with_borders, remove_light, BorderLight, ChunkFace,
+    };
     use crate::physics::coordinates::Coordinates;
 
     /// Test world that wraps a Lightmap with an opacity function.
@@ -48,6 +50,19 @@ mod tests {
                 self.lm.set(cords.x as u32, cords.y as u32, cords.z as u32, color);
             }
         }
+
+        fn get_sky_light(&self, cords: Coordinates) -> u8 {
+            if !self.in_bounds(cords) {
+                return 0;
+            }
+            self.lm.get_sky_light(cords.x as u32, cords.y as u32, cords.z as u32)
+        }
+
+        fn set_sky_light(&mut self, cords: Coordinates, intensity: u8) {
+            if self.in_bounds(cords) {
+                self.lm.set_sky_light(cords.x as u32, cords.y as u32, cords.z as u32, intensity);
+            }
+        }
     }
 
     /// Helper to seed light sources and propagate.
@@ -404,4 +419,164 @@ mod tests {
         // y=2: no further attenuation in column (opacity 0)
         assert_eq!(world.get(0, 2, 0), [150, 150, 150]);
     }
+
+    #[test]
+    fn remove_light_clears_only_source() {
+        let mut world = TestWorld::new(7, 3, 3, open);
+        seed_and_propagate(&mut world, &[(1, 1, 1, [255, 0, 0])], 17);
+
+        remove_light(&mut world, Coordinates::new(1, 1, 1), 17);
+
+        for x in 0..7 {
+            assert_eq!(world.get(x, 1, 1), [0, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn remove_light_keeps_other_sources() {
+        let mut world = TestWorld::new(9, 3, 3, open);
+        seed_and_propagate(&mut world, &[(0, 1, 1, [255, 0, 0]), (6, 1, 1, [200, 100, 0])], 17);
+
+        remove_light(&mut world, Coordinates::new(6, 1, 1), 17);
+
+        let mut expected = TestWorld::new(9, 3, 3, open);
+        seed_and_propagate(&mut expected, &[(0, 1, 1, [255, 0, 0])], 17);
+        assert_eq!(world.lm.as_bytes(), expected.lm.as_bytes());
+    }
+
+    #[test]
+    fn remove_light_refills_overlap_from_brighter_neighbor() {
+        // Two sources whose light overlaps in the middle; removing the weaker one must
+        // leave the stronger one's light intact across the shared region
+        let mut world = TestWorld::new(5, 1, 1, open);
+        seed_and_propagate(&mut world, &[(0, 0, 0, [255, 255, 255]), (4, 0, 0, [100, 100, 100])], 17);
+
+        remove_light(&mut world, Coordinates::new(4, 0, 0), 17);
+
+        assert_eq!(world.get(3, 0, 0), [204, 204, 204]);
+        assert_eq!(world.get(4, 0, 0), [187, 187, 187]);
+    }
+
+    #[test]
+    fn propagate_chunks_matches_sequential() {
+        let sources = |i: u32| vec![(Coordinates::new(i as i32 % 5, 2, 2), [255, 40 * i as u8, 0])];
+        let mut chunks: Vec<_> = (0..6)
+            .map(|i| (TestWorld::new(5, 5, 5, wall_at_x2), sources(i)))
+            .collect();
+
+        propagate_chunks(&mut chunks, 17);
+
+        for (i, (world, _)) in chunks.iter().enumerate() {
+            let mut expected = TestWorld::new(5, 5, 5, wall_at_x2);
+            let (c, color) = sources(i as u32)[0];
+            seed_and_propagate(&mut expected, &[(c.x as u32, c.y as u32, c.z as u32, color)], 17);
+            assert_eq!(world.lm.as_bytes(), expected.lm.as_bytes(), "chunk {i}");
+        }
+    }
+
+    #[test]
+    fn border_light_continues_across_seam() {
+        // Two 5-voxel chunks side by side along X; the source sits in the left one
+        let mut left = TestWorld::new(5, 1, 1, open);
+        seed_and_propagate(&mut left, &[(0, 0, 0, [255, 255, 255])], 17);
+        // Sky light falling off from an opening at the left end
+        for x in 0..5 {
+            left.lm.set_sky_light(x, 0, 0, 255 - 20 * x as u8);
+        }
+
+        let mut right = TestWorld::new(5, 1, 1, open);
+        let borders = BorderLight::from_neighbor(&left.lm, ChunkFace::NegX);
+        let expected = BorderLight { coords: Coordinates::new(0, 0, 0), face: ChunkFace::NegX, light: [187, 187, 187], sky: 175 };
+        assert_eq!(borders, vec![expected]);
+        propagate_with_borders(&mut right, &[], &borders, 17);
+
+        // Same falloff as one 10-voxel chunk
+        let mut whole = TestWorld::new(10, 1, 1, open);
+        seed_and_propagate(&mut whole, &[(0, 0, 0, [255, 255, 255])], 17);
+        for x in 0..5 {
+            assert_eq!(left.get(x, 0, 0), whole.get(x, 0, 0));
+            assert_eq!(right.get(x, 0, 0), whole.get(x + 5, 0, 0));
+            // Sky keeps dropping by the attenuation per block past the seam
+            assert_eq!(right.lm.get_sky_light(x, 0, 0), 175 - 17 * (x as u8 + 1));
+        }
+    }
+
+    #[test]
+    fn border_sky_light_continues_down_into_chunk_below() {
+        let mut above = TestWorld::new(2, 3, 1, open);
+        for x in 0..2 {
+            for y in 0..3 {
+                above.lm.set_sky_light(x, y, 0, 255);
+            }
+        }
+
+        // The chunk below has a semi-opaque leaf block in its top layer at x = 1
+        let mut below = TestWorld::new(2, 3, 1, |x, y, _| if (x, y) == (1, 2) { 40 } else { 0 });
+        let borders = BorderLight::from_neighbor(&above.lm, ChunkFace::PosY);
+        propagate_with_borders(&mut below, &[], &borders, 17);
+
+        for y in 0..3 {
+            assert_eq!(below.lm.get_sky_light(0, y, 0), 255);
+        }
+        // The leaf only costs its opacity; under it the open column beside is brighter
+        assert_eq!(below.lm.get_sky_light(1, 2, 0), 215);
+        assert_eq!(below.lm.get_sky_light(1, 1, 0), 238);
+        assert_eq!(below.lm.get_sky_light(1, 0, 0), 238);
+    }
+
+    #[test]
+    fn border_light_blocked_by_opaque_boundary() {
+        let mut left = TestWorld::new(3, 1, 1, open);
+        seed_and_propagate(&mut left, &[(2, 0, 0, [255, 0, 0])], 17);
+
+        let mut right = TestWorld::new(3, 1, 1, |x, _, _| if x == 0 { 255 } else { 0 });
+        let borders = BorderLight::from_neighbor(&left.lm, ChunkFace::NegX);
+        propagate_with_borders(&mut right, &[], &borders, 17);
+
+        assert_eq!(right.get(1, 0, 0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn propagate_limited_stops_at_radius() {
+        let mut world = TestWorld::new(9, 1, 1, open);
+        world.set_light(Coordinates::new(0, 0, 0), [255, 255, 255]);
+        propagate_limited(&mut world, &[Coordinates::new(0, 0, 0)], 0, 3);
+
+        assert_eq!(world.get(3, 0, 0), [255, 255, 255]);
+        assert_eq!(world.get(4, 0, 0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn propagate_limited_matches_unbounded_within_reach() {
+        let mut limited = TestWorld::new(5, 5, 5, wall_at_x2);
+        limited.set_light(Coordinates::new(0, 2, 2), [255, 128, 64]);
+        propagate_limited(&mut limited, &[Coordinates::new(0, 2, 2)], 17, 100);
+
+        let mut unbounded = TestWorld::new(5, 5, 5, wall_at_x2);
+        seed_and_propagate(&mut unbounded, &[(0, 2, 2, [255, 128, 64])], 17);
+        assert_eq!(limited.lm.as_bytes(), unbounded.lm.as_bytes());
+    }
+
+    #[test]
+    fn propagate_rgb_attenuates_channels_independently() {
+        // Water-like: red is absorbed much faster than blue
+        let mut world = TestWorld::new(5, 1, 1, open);
+        world.set_light(Coordinates::new(0, 0, 0), [255, 255, 255]);
+        propagate_rgb(&mut world, &[Coordinates::new(0, 0, 0)], [100, 30, 10]);
+
+        assert_eq!(world.get(1, 0, 0), [155, 225, 245]);
+        assert_eq!(world.get(2, 0, 0), [55, 195, 235]);
+        assert_eq!(world.get(3, 0, 0), [0, 165, 225]);
+    }
+
+    #[test]
+    fn propagate_rgb_uniform_matches_scalar() {
+        let mut rgb = TestWorld::new(5, 5, 5, wall_at_x2);
+        rgb.set_light(Coordinates::new(0, 2, 2), [255, 128, 64]);
+        propagate_rgb(&mut rgb, &[Coordinates::new(0, 2, 2)], [17; 3]);
+
+        let mut scalar = TestWorld::new(5, 5, 5, wall_at_x2);
+        seed_and_propagate(&mut scalar, &[(0, 2, 2, [255, 128, 64])], 17);
+        assert_eq!(rgb.lm.as_bytes(), scalar.lm.as_bytes());
+    }
 }
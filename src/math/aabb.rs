@@ -0,0 +1,50 @@
+use nalgebra_glm as glm;
+
+/// An axis-aligned bounding box given by its minimum and maximum corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// Minimum corner.
+    pub min: glm::Vec3,
+    /// Maximum corner.
+    pub max: glm::Vec3,
+}
+
+impl Aabb {
+    /// The unit cube `0..1` on every axis (a whole block cell).
+    pub const UNIT: Self = Self::new(glm::Vec3::new(0.0, 0.0, 0.0), glm::Vec3::new(1.0, 1.0, 1.0));
+
+    /// Creates a box from its minimum and maximum corners.
+    pub const fn new(min: glm::Vec3, max: glm::Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if `point` is inside the box or on its surface.
+    pub fn contains_point(&self, point: &glm::Vec3) -> bool {
+        (0..3).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+    }
+
+    /// Returns `true` if the boxes overlap; boxes that only touch count as intersecting.
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        (0..3).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+    }
+
+    /// Returns the center point.
+    pub fn center(&self) -> glm::Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the extent along each axis.
+    pub fn size(&self) -> glm::Vec3 {
+        self.max - self.min
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn merge(&self, other: &Aabb) -> Aabb {
+        Aabb::new(glm::min2(&self.min, &other.min), glm::max2(&self.max, &other.max))
+    }
+
+    /// Returns the box moved by `offset`.
+    pub fn translated(&self, offset: &glm::Vec3) -> Aabb {
+        Aabb::new(self.min + offset, self.max + offset)
+    }
+}
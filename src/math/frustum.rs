@@ -1,4 +1,5 @@
 use nalgebra_glm as glm;
+use crate::math::aabb::Aabb;
 
 /// A normalized plane in 3D space defined by a normal and signed distance.
 #[derive(Debug, Clone, Copy)]
@@ -10,19 +11,44 @@ pub struct Plane {
 }
 
 impl Plane {
-    /// Creates a plane and normalizes the normal and distance.
+    /// A degenerate plane every point is on (distance 0), so culling tests treat it as
+    /// "always inside" instead of producing NaNs.
+    pub const ALWAYS_INSIDE: Self = Self { normal: glm::Vec3::new(0.0, 0.0, 0.0), distance: 0.0 };
+
+    /// Creates a plane and normalizes the normal and distance. A (near-)zero normal, e.g.
+    /// from a malformed matrix, yields [`Self::ALWAYS_INSIDE`]; see [`Self::try_new`].
     pub fn new(normal: glm::Vec3, distance: f32) -> Self {
+        Self::try_new(normal, distance).unwrap_or(Self::ALWAYS_INSIDE)
+    }
+
+    /// Like [`Self::new`], but returns `None` if the normal is too short to normalize.
+    pub fn try_new(normal: glm::Vec3, distance: f32) -> Option<Self> {
         let length = glm::length(&normal);
-        Self {
+        if length < f32::EPSILON || !length.is_finite() {
+            return None;
+        }
+        Some(Self {
             normal: normal / length,
             distance: distance / length,
-        }
+        })
     }
 
     /// Returns the signed distance from a point to this plane.
     pub fn distance_to_point(&self, point: &glm::Vec3) -> f32 {
         glm::dot(&self.normal, point) + self.distance
     }
+
+    /// Returns the single point shared by three planes, or `None` if two of them are parallel.
+    pub fn intersection(a: &Plane, b: &Plane, c: &Plane) -> Option<glm::Vec3> {
+        let bc = glm::cross(&b.normal, &c.normal);
+        let denom = glm::dot(&a.normal, &bc);
+        if denom.abs() < f32::EPSILON {
+            return None;
+        }
+        let ca = glm::cross(&c.normal, &a.normal);
+        let ab = glm::cross(&a.normal, &b.normal);
+        Some(-(bc * a.distance + ca * b.distance + ab * c.distance) / denom)
+    }
 }
 
 /// Six-plane view frustum for visibility culling.
@@ -34,6 +60,7 @@ pub struct Frustum {
 
 impl Frustum {
     /// Extracts frustum planes from a view-projection matrix using the Gribb-Hartmann method.
+    /// Degenerate planes become [`Plane::ALWAYS_INSIDE`], so a malformed matrix culls nothing.
     pub fn from_matrix(mat: &glm::Mat4) -> Self {
         // Gribb-Hartmann extraction for OpenGL (where NDC Z is [-1, 1])
         let left = Plane::new(
@@ -67,12 +94,12 @@ impl Frustum {
     }
 
     /// Returns `true` if the axis-aligned bounding box is at least partially inside the frustum.
-    pub fn intersects_aabb(&self, min: &glm::Vec3, max: &glm::Vec3) -> bool {
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
         for plane in &self.planes {
-            let mut p = *min;
-            if plane.normal.x >= 0.0 { p.x = max.x; }
-            if plane.normal.y >= 0.0 { p.y = max.y; }
-            if plane.normal.z >= 0.0 { p.z = max.z; }
+            let mut p = aabb.min;
+            if plane.normal.x >= 0.0 { p.x = aabb.max.x; }
+            if plane.normal.y >= 0.0 { p.y = aabb.max.y; }
+            if plane.normal.z >= 0.0 { p.z = aabb.max.z; }
 
             if plane.distance_to_point(&p) < 0.0 {
                 return false;
@@ -80,4 +107,35 @@ impl Frustum {
         }
         true
     }
-}
\ No newline at end of file
+
+    /// Returns `true` if `point` is inside the frustum (on or inside every plane).
+    pub fn contains_point(&self, point: &glm::Vec3) -> bool {
+        self.planes.iter().all(|plane| plane.distance_to_point(point) >= 0.0)
+    }
+
+    /// Returns `true` if the sphere is at least partially inside the frustum, i.e. its center
+    /// is no further than `radius` outside any plane.
+    pub fn intersects_sphere(&self, center: &glm::Vec3, radius: f32) -> bool {
+        self.planes.iter().all(|plane| plane.distance_to_point(center) >= -radius)
+    }
+
+    /// Returns the eight corner points, e.g. for drawing the frustum as a wireframe:
+    /// the near quad then the far quad, each ordered bottom-left, bottom-right, top-right,
+    /// top-left. A corner whose planes don't meet (degenerate matrix) is returned as NaN.
+    pub fn corners(&self) -> [glm::Vec3; 8] {
+        let [left, right, bottom, top, near, far] = &self.planes;
+        let corner = |depth: &Plane, vertical: &Plane, horizontal: &Plane| {
+            Plane::intersection(depth, vertical, horizontal).unwrap_or(glm::Vec3::repeat(f32::NAN))
+        };
+        [
+            corner(near, bottom, left),
+            corner(near, bottom, right),
+            corner(near, top, right),
+            corner(near, top, left),
+            corner(far, bottom, left),
+            corner(far, bottom, right),
+            corner(far, top, right),
+            corner(far, top, left),
+        ]
+    }
+}
@@ -1,4 +1,6 @@
 pub mod frustum;
+pub mod aabb;
+pub mod ray;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use nalgebra_glm as glm;
+use crate::math::aabb::Aabb;
+
+/// A half-line starting at `origin` and extending along `dir`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// Start point.
+    pub origin: glm::Vec3,
+    /// Direction; distances along the ray are measured in multiples of its length.
+    pub dir: glm::Vec3,
+}
+
+impl Ray {
+    /// Creates a ray from an origin and direction.
+    pub fn new(origin: glm::Vec3, dir: glm::Vec3) -> Self {
+        Self { origin, dir }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    pub fn at(&self, t: f32) -> glm::Vec3 {
+        self.origin + self.dir * t
+    }
+
+    /// Slab test: returns the distance at which the ray enters `aabb` (0 if it starts inside),
+    /// or `None` if it misses or the box is behind the origin. Grazing a face or edge counts as a hit.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let mut t_enter = 0.0f32;
+        let mut t_exit = f32::INFINITY;
+
+        for axis in 0..3 {
+            let origin = self.origin[axis];
+            let dir = self.dir[axis];
+            if dir == 0.0 {
+                // Parallel to this slab: must already be between its planes
+                if origin < aabb.min[axis] || origin > aabb.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (aabb.min[axis] - origin) / dir;
+            let t2 = (aabb.max[axis] - origin) / dir;
+            t_enter = t_enter.max(t1.min(t2));
+            t_exit = t_exit.min(t1.max(t2));
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+        Some(t_enter)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::math::aabb::Aabb;
+use nalgebra_glm as glm;
+
+fn unit_at(x: f32, y: f32, z: f32) -> Aabb {
+    Aabb::UNIT.translated(&glm::vec3(x, y, z))
+}
+
+#[test]
+fn test_contains_point_inclusive() {
+    let aabb = Aabb::UNIT;
+
+    assert!(aabb.contains_point(&glm::vec3(0.5, 0.5, 0.5)));
+    assert!(aabb.contains_point(&glm::vec3(1.0, 0.0, 1.0)), "Corners are inside");
+    assert!(!aabb.contains_point(&glm::vec3(1.01, 0.5, 0.5)));
+}
+
+#[test]
+fn test_intersects() {
+    let a = Aabb::UNIT;
+
+    assert!(a.intersects(&unit_at(0.5, 0.5, 0.5)));
+    assert!(a.intersects(&unit_at(1.0, 0.0, 0.0)), "Touching faces intersect");
+    assert!(!a.intersects(&unit_at(1.5, 0.0, 0.0)));
+    assert!(!a.intersects(&unit_at(0.0, 0.0, -2.0)));
+}
+
+#[test]
+fn test_center_and_merge() {
+    let merged = Aabb::UNIT.merge(&unit_at(2.0, -1.0, 0.0));
+
+    assert_eq!(merged, Aabb::new(glm::vec3(0.0, -1.0, 0.0), glm::vec3(3.0, 1.0, 1.0)));
+    assert_eq!(merged.center(), glm::vec3(1.5, 0.0, 0.5));
+    assert_eq!(merged.size(), glm::vec3(3.0, 2.0, 1.0));
+}
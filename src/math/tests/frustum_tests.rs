@@ -1,3 +1,4 @@
+use crate::math::aabb::Aabb;
 use crate::math::frustum::*;
 use nalgebra_glm as glm;
 
@@ -16,7 +17,7 @@ fn test_frustum_culling_behind() {
     let min = glm::vec3(-5.0, -5.0, 5.0);
     let max = glm::vec3(5.0, 5.0, 15.0);
     
-    assert!(!frustum.intersects_aabb(&min, &max), "Box behind camera should be culled");
+    assert!(!frustum.intersects_aabb(&Aabb::new(min, max)), "Box behind camera should be culled");
 }
 
 #[test]
@@ -34,7 +35,7 @@ fn test_frustum_culling_front() {
     let min = glm::vec3(-1.0, -1.0, -10.0);
     let max = glm::vec3(1.0, 1.0, -5.0);
     
-    assert!(frustum.intersects_aabb(&min, &max), "Box in front of camera should NOT be culled");
+    assert!(frustum.intersects_aabb(&Aabb::new(min, max)), "Box in front of camera should NOT be culled");
 }
 
 #[test]
@@ -53,5 +54,87 @@ fn test_frustum_culling_sideways() {
     let min = glm::vec3(10.0, -1.0, -11.0);
     let max = glm::vec3(12.0, 1.0, -9.0);
     
-    assert!(!frustum.intersects_aabb(&min, &max), "Box to the far right should be culled");
+    assert!(!frustum.intersects_aabb(&Aabb::new(min, max)), "Box to the far right should be culled");
+}
+
+#[test]
+fn test_frustum_contains_point() {
+    // Camera at origin, looking at -Z
+    let view = glm::look_at(
+        &glm::vec3(0.0, 0.0, 0.0),
+        &glm::vec3(0.0, 0.0, -1.0),
+        &glm::vec3(0.0, 1.0, 0.0)
+    );
+    let projection = glm::perspective(1.0, 45.0f32.to_radians(), 0.1, 100.0);
+    let frustum = Frustum::from_matrix(&(projection * view));
+
+    assert!(frustum.contains_point(&glm::vec3(0.0, 0.0, -10.0)), "Point ahead should be inside");
+    assert!(!frustum.contains_point(&glm::vec3(0.0, 0.0, 10.0)), "Point behind should be outside");
+    assert!(!frustum.contains_point(&glm::vec3(0.0, 0.0, -200.0)), "Point past the far plane should be outside");
+}
+
+#[test]
+fn test_frustum_sphere_culling() {
+    // Camera at origin, looking at -Z
+    let view = glm::look_at(
+        &glm::vec3(0.0, 0.0, 0.0),
+        &glm::vec3(0.0, 0.0, -1.0),
+        &glm::vec3(0.0, 1.0, 0.0)
+    );
+    let projection = glm::perspective(1.0, 45.0f32.to_radians(), 0.1, 100.0);
+    let frustum = Frustum::from_matrix(&(projection * view));
+
+    // Center outside the right plane (half-width ~4.14 at Z=-10)
+    let center = glm::vec3(6.0, 0.0, -10.0);
+    assert!(!frustum.intersects_sphere(&center, 1.0), "Small sphere to the right should be culled");
+    assert!(frustum.intersects_sphere(&center, 3.0), "Large sphere reaching into view should NOT be culled");
+    assert!(!frustum.intersects_sphere(&glm::vec3(0.0, 0.0, 10.0), 2.0), "Sphere behind camera should be culled");
+}
+
+#[test]
+fn test_frustum_corners() {
+    // Camera at origin, looking at -Z
+    let view = glm::look_at(
+        &glm::vec3(0.0, 0.0, 0.0),
+        &glm::vec3(0.0, 0.0, -1.0),
+        &glm::vec3(0.0, 1.0, 0.0)
+    );
+    let projection = glm::perspective(1.0, 45.0f32.to_radians(), 0.1, 100.0);
+    let frustum = Frustum::from_matrix(&(projection * view));
+
+    let corners = frustum.corners();
+    let near_half = 0.1 * 22.5f32.to_radians().tan();
+    let far_half = 100.0 * 22.5f32.to_radians().tan();
+
+    for (i, corner) in corners.iter().enumerate() {
+        let (depth, half) = if i < 4 { (0.1, near_half) } else { (100.0, far_half) };
+        assert!((corner.z + depth).abs() < depth * 1e-3, "Corner {i} at wrong depth: {corner:?}");
+        assert!((corner.x.abs() - half).abs() < half * 1e-3, "Corner {i} at wrong width: {corner:?}");
+        assert!((corner.y.abs() - half).abs() < half * 1e-3, "Corner {i} at wrong height: {corner:?}");
+    }
+
+    // Near quad comes first and is closer along the view direction (-Z)
+    assert!(corners[..4].iter().all(|c| -c.z < -corners[4].z));
+    // Bottom-left, bottom-right, top-right, top-left
+    assert!(corners[0].x < 0.0 && corners[0].y < 0.0);
+    assert!(corners[2].x > 0.0 && corners[2].y > 0.0);
+}
+
+#[test]
+fn test_degenerate_plane_is_always_inside() {
+    assert!(Plane::try_new(glm::Vec3::zeros(), 1.0).is_none());
+
+    let plane = Plane::new(glm::Vec3::zeros(), 1.0);
+    let distance = plane.distance_to_point(&glm::vec3(3.0, -2.0, 7.0));
+    assert!(!distance.is_nan());
+    assert!(distance >= 0.0);
+}
+
+#[test]
+fn test_degenerate_matrix_culls_nothing() {
+    let frustum = Frustum::from_matrix(&glm::Mat4::zeros());
+
+    assert!(frustum.contains_point(&glm::vec3(1.0, 2.0, 3.0)));
+    assert!(frustum.intersects_sphere(&glm::vec3(-50.0, 0.0, 0.0), 0.5));
+    assert!(frustum.intersects_aabb(&Aabb::new(glm::vec3(0.0, 0.0, 0.0), glm::vec3(1.0, 1.0, 1.0))));
 }
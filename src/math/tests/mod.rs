@@ -1 +1,3 @@
 pub mod frustum_tests;
+pub mod aabb_tests;
+pub mod ray_tests;
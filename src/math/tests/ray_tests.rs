@@ -0,0 +1,43 @@
+use crate::math::aabb::Aabb;
+use crate::math::ray::Ray;
+use nalgebra_glm as glm;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+#[test]
+fn test_ray_hits_box_ahead() {
+    let ray = Ray::new(glm::vec3(-5.0, 0.5, 0.5), glm::vec3(1.0, 0.0, 0.0));
+
+    let t = ray.intersects_aabb(&Aabb::UNIT).unwrap();
+    assert!(approx(t, 5.0));
+    assert_eq!(ray.at(t), glm::vec3(0.0, 0.5, 0.5));
+}
+
+#[test]
+fn test_ray_misses_box_behind() {
+    let ray = Ray::new(glm::vec3(5.0, 0.5, 0.5), glm::vec3(1.0, 0.0, 0.0));
+    assert_eq!(ray.intersects_aabb(&Aabb::UNIT), None);
+}
+
+#[test]
+fn test_ray_starting_inside_returns_zero() {
+    let ray = Ray::new(glm::vec3(0.5, 0.5, 0.5), glm::vec3(0.0, -1.0, 0.0));
+    assert_eq!(ray.intersects_aabb(&Aabb::UNIT), Some(0.0));
+}
+
+#[test]
+fn test_ray_grazing_face_and_edge() {
+    // Slides along the top face
+    let along_face = Ray::new(glm::vec3(-1.0, 1.0, 0.5), glm::vec3(1.0, 0.0, 0.0));
+    assert!(along_face.intersects_aabb(&Aabb::UNIT).is_some());
+
+    // Diagonal that just touches the (1, 1) edge
+    let edge = Ray::new(glm::vec3(0.0, 2.0, 0.5), glm::vec3(1.0, -1.0, 0.0));
+    assert!(approx(edge.intersects_aabb(&Aabb::UNIT).unwrap(), 1.0));
+
+    // Parallel ray just above the box
+    let above = Ray::new(glm::vec3(-1.0, 1.01, 0.5), glm::vec3(1.0, 0.0, 0.0));
+    assert_eq!(above.intersects_aabb(&Aabb::UNIT), None);
+}
@@ -1,12 +1,90 @@
 use nalgebra_glm as glm;
+use crate::math::aabb::Aabb;
 use crate::physics::coordinates::Coordinates;
 
+/// Longest distance the default [`CollisionMap::raycast`] walks; longer (or infinite)
+/// `max_dist` values are clamped to it so a ray that hits nothing still terminates.
+pub const MAX_RAYCAST_DISTANCE: f32 = 4096.0;
+
 /// World geometry queries for collision detection.
 pub trait CollisionMap {
     /// Returns `true` if the block at the given world position is solid.
     fn is_solid_at(&self, x: f32, y: f32, z: f32) -> bool;
     /// Casts a ray from `origin` in `direction` up to `max_dist` and returns the first hit.
-    fn raycast(&self, origin: glm::Vec3, direction: glm::Vec3, max_dist: f32) -> Option<RaycastResult>;
+    ///
+    /// The default walks the block grid with an Amanatides-Woo DDA using [`Self::is_solid_at`],
+    /// at most [`MAX_RAYCAST_DISTANCE`] far. A ray starting inside a solid block hits that block
+    /// with a zero `face_normal`; a zero `direction` never hits.
+    fn raycast(&self, origin: glm::Vec3, direction: glm::Vec3, max_dist: f32) -> Option<RaycastResult> {
+        // `min` also turns a NaN distance into the cap
+        let max_dist = max_dist.min(MAX_RAYCAST_DISTANCE);
+        let length = glm::length(&direction);
+        if length == 0.0 {
+            return None;
+        }
+        let dir = direction / length;
+
+        let mut block = glm::vec3(origin.x.floor() as i32, origin.y.floor() as i32, origin.z.floor() as i32);
+        let is_solid = |b: &glm::IVec3| self.is_solid_at(b.x as f32, b.y as f32, b.z as f32);
+        if is_solid(&block) {
+            return Some(RaycastResult {
+                block_pos: Coordinates::new(block.x, block.y, block.z),
+                face_normal: glm::IVec3::zeros(),
+            });
+        }
+
+        // Per axis: step direction, ray distance to the next block boundary, and the
+        // distance between boundaries.
+        let mut step = glm::IVec3::zeros();
+        let mut t_max = glm::Vec3::repeat(f32::INFINITY);
+        let mut t_delta = glm::Vec3::repeat(f32::INFINITY);
+        for axis in 0..3 {
+            if dir[axis] > 0.0 {
+                step[axis] = 1;
+                t_max[axis] = (block[axis] as f32 + 1.0 - origin[axis]) / dir[axis];
+                t_delta[axis] = 1.0 / dir[axis];
+            } else if dir[axis] < 0.0 {
+                step[axis] = -1;
+                t_max[axis] = (origin[axis] - block[axis] as f32) / -dir[axis];
+                t_delta[axis] = -1.0 / dir[axis];
+            }
+        }
+
+        loop {
+            let axis = if t_max.x <= t_max.y && t_max.x <= t_max.z {
+                0
+            } else if t_max.y <= t_max.z {
+                1
+            } else {
+                2
+            };
+            if t_max[axis] > max_dist {
+                return None;
+            }
+
+            block[axis] += step[axis];
+            t_max[axis] += t_delta[axis];
+            if is_solid(&block) {
+                let mut face_normal = glm::IVec3::zeros();
+                face_normal[axis] = -step[axis];
+                return Some(RaycastResult {
+                    block_pos: Coordinates::new(block.x, block.y, block.z),
+                    face_normal,
+                });
+            }
+        }
+    }
+
+    /// Returns the collision boxes of the block at the given world position, in block-local
+    /// coordinates within `0..1` (e.g. a bottom slab is `0..1, 0..0.5, 0..1`).
+    /// Defaults to a full cube for solid blocks and nothing otherwise.
+    fn block_aabbs(&self, x: f32, y: f32, z: f32) -> &[Aabb] {
+        if self.is_solid_at(x, y, z) {
+            &[Aabb::UNIT]
+        } else {
+            &[]
+        }
+    }
 }
 
 /// The result of a successful raycast against the collision map.
@@ -52,3 +52,75 @@ impl Sub for Coordinates {
         }
     }
 }
+
+/// Position of a chunk in the chunk grid (one unit per chunk, not per block).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkCoord(pub Coordinates);
+
+/// Block position inside a chunk. Each axis is in `0..size` for that axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalCoord {
+    /// X offset within the chunk.
+    pub x: u32,
+    /// Y offset within the chunk.
+    pub y: u32,
+    /// Z offset within the chunk.
+    pub z: u32,
+}
+
+/// Chunk dimensions in blocks, used to split world coordinates into chunk and local parts.
+///
+/// Uses Euclidean division so negative coordinates floor correctly:
+/// with a size of 16, world `x = -1` is chunk `-1`, local `15`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkSize {
+    /// Chunk width in blocks.
+    pub x: u32,
+    /// Chunk height in blocks.
+    pub y: u32,
+    /// Chunk depth in blocks.
+    pub z: u32,
+}
+
+impl ChunkSize {
+    /// Creates a chunk size with independent dimensions per axis.
+    pub const fn new(x: u32, y: u32, z: u32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a cube-shaped chunk size.
+    pub const fn cubic(size: u32) -> Self {
+        Self { x: size, y: size, z: size }
+    }
+
+    /// Splits a world block position into the containing chunk and the offset within it.
+    pub fn world_to_chunk(&self, coords: Coordinates) -> (ChunkCoord, LocalCoord) {
+        let (sx, sy, sz) = (self.x as i32, self.y as i32, self.z as i32);
+        let chunk = Coordinates::new(
+            coords.x.div_euclid(sx),
+            coords.y.div_euclid(sy),
+            coords.z.div_euclid(sz),
+        );
+        let local = LocalCoord {
+            x: coords.x.rem_euclid(sx) as u32,
+            y: coords.y.rem_euclid(sy) as u32,
+            z: coords.z.rem_euclid(sz) as u32,
+        };
+        (ChunkCoord(chunk), local)
+    }
+
+    /// Returns the world position of the chunk's minimum corner (local `0, 0, 0`).
+    pub fn chunk_to_world_origin(&self, chunk: ChunkCoord) -> Coordinates {
+        Coordinates::new(
+            chunk.0.x * self.x as i32,
+            chunk.0.y * self.y as i32,
+            chunk.0.z * self.z as i32,
+        )
+    }
+
+    /// Recombines a chunk and local offset into a world block position.
+    pub fn local_to_world(&self, chunk: ChunkCoord, local: LocalCoord) -> Coordinates {
+        self.chunk_to_world_origin(chunk)
+            + Coordinates::new(local.x as i32, local.y as i32, local.z as i32)
+    }
+}
@@ -1,8 +1,21 @@
 use nalgebra_glm as glm;
 
+/// How the physics system treats an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BodyType {
+    /// Affected by gravity, friction, and collision response.
+    #[default]
+    Dynamic,
+    /// Moved only by explicit position/velocity sets (doors, scripted platforms);
+    /// ignores gravity and collision response but blocks dynamic bodies.
+    Kinematic,
+    /// Never moves; blocks dynamic bodies.
+    Static,
+}
+
 /// A movable axis-aligned bounding box with velocity.
 pub struct PhysicsEntity {
-    /// World-space position of the AABB minimum corner.
+    /// World-space position of the entity's anchor point (the AABB minimum corner by default).
     pub position: glm::Vec3,
     /// Current velocity in units per second.
     pub velocity: glm::Vec3,
@@ -10,6 +23,73 @@ pub struct PhysicsEntity {
     pub size: glm::Vec3, // The AABB dimensions
     /// Whether the entity is resting on a surface below it.
     pub is_grounded: bool,
+    /// Simulation model for this entity.
+    pub body_type: BodyType,
+    /// Where `position` sits relative to the AABB minimum corner, e.g. `(w / 2, 0, d / 2)`
+    /// for a feet-centered position. Defaults to zero.
+    pub anchor: glm::Vec3,
+}
+
+impl PhysicsEntity {
+    /// Creates a resting dynamic entity at `position` with the given AABB size.
+    pub fn new(position: glm::Vec3, size: glm::Vec3) -> Self {
+        Self {
+            position,
+            velocity: glm::Vec3::zeros(),
+            size,
+            is_grounded: false,
+            body_type: BodyType::Dynamic,
+            anchor: glm::Vec3::zeros(),
+        }
+    }
+
+    /// Sets the body type (builder style).
+    pub fn with_body_type(mut self, body_type: BodyType) -> Self {
+        self.body_type = body_type;
+        self
+    }
+
+    /// Sets the anchor (builder style), see [`Self::anchor`].
+    pub fn with_anchor(mut self, anchor: glm::Vec3) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Anchors `position` at the bottom center of the AABB (builder style).
+    pub fn with_feet_anchor(self) -> Self {
+        let anchor = glm::vec3(self.size.x * 0.5, 0.0, self.size.z * 0.5);
+        self.with_anchor(anchor)
+    }
+
+    /// World-space AABB minimum corner.
+    pub fn aabb_min(&self) -> glm::Vec3 {
+        self.position - self.anchor
+    }
+
+    /// World-space AABB maximum corner.
+    pub fn aabb_max(&self) -> glm::Vec3 {
+        self.aabb_min() + self.size
+    }
+
+    /// World-space center of the AABB.
+    pub fn center(&self) -> glm::Vec3 {
+        self.aabb_min() + self.size * 0.5
+    }
+
+    /// World-space bottom center of the AABB, e.g. for footsteps or placing the eye above.
+    pub fn feet(&self) -> glm::Vec3 {
+        self.aabb_min() + glm::vec3(self.size.x * 0.5, 0.0, self.size.z * 0.5)
+    }
+
+    /// Returns `true` if this entity's AABB with its minimum corner at `min` overlaps
+    /// `other`'s AABB.
+    pub(crate) fn overlaps_at(&self, min: glm::Vec3, other: &PhysicsEntity) -> bool {
+        let other_min = other.aabb_min();
+        (0..3).all(|axis| {
+            min[axis] < other_min[axis] + other.size[axis]
+                && other_min[axis] < min[axis] + self.size[axis]
+        })
+    }
 }
 
 /// Implemented by game objects that participate in physics.
@@ -1,70 +1,308 @@
 use nalgebra_glm as glm;
+use crate::math::aabb::Aabb;
 use crate::physics::collision_map::CollisionMap;
-use crate::physics::physics_entity::{KinematicBody, PhysicsEntity};
+use crate::physics::physics_entity::{BodyType, KinematicBody, PhysicsEntity};
+
+/// How far below the feet [`PhysicsSystem`] looks for ground each step.
+const GROUND_PROBE: f32 = 0.01;
+
+/// What a body ran into during one [`PhysicsSystem::step`].
+///
+/// Each `hit_*` is the sign of the movement that was blocked on that axis:
+/// `1` moving positive, `-1` moving negative, `0` no collision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CollisionInfo {
+    /// Blocked X movement.
+    pub hit_x: i8,
+    /// Blocked Y movement (`-1` is the floor, `1` the ceiling).
+    pub hit_y: i8,
+    /// Blocked Z movement.
+    pub hit_z: i8,
+    /// The body hit the floor this step after being airborne.
+    pub landed: bool,
+    /// The body bumped its head moving upward.
+    pub ceiling: bool,
+}
+
+impl CollisionInfo {
+    /// Returns `true` if any axis collided.
+    pub fn any(&self) -> bool {
+        self.hit_x != 0 || self.hit_y != 0 || self.hit_z != 0
+    }
+}
 
 /// AABB collision system with gravity and friction.
 pub struct PhysicsSystem {
     /// Downward acceleration in units per second squared.
     pub gravity: f32,
+    /// Fraction of horizontal velocity kept after one second on the ground (default 0.01).
+    pub ground_friction: f32,
+    /// Fraction of horizontal velocity kept after one second in the air (default 0.5);
+    /// higher values mean less air control is needed to keep momentum.
+    pub air_friction: f32,
+    /// Also decays vertical velocity by the current friction, e.g. for water or flying.
+    pub apply_vertical_drag: bool,
+    /// Ledge height a grounded entity walks up onto instead of stopping (0 disables).
+    pub step_height: f32,
 }
 
 impl PhysicsSystem {
+    /// Creates a system with the given gravity and the default friction settings.
+    pub fn new(gravity: f32) -> Self {
+        Self {
+            gravity,
+            ground_friction: 0.01,
+            air_friction: 0.5,
+            apply_vertical_drag: false,
+            step_height: 0.0,
+        }
+    }
+
     /// Advances physics by `dt` seconds: applies gravity, friction, and axis-by-axis collision.
-    pub fn step<T: KinematicBody, W: CollisionMap>(&self, body: &mut T, world: &W, dt: f32) {
+    /// Returns which axes collided, e.g. for footstep sounds, fall damage, or jump reset.
+    pub fn step<T: KinematicBody, W: CollisionMap>(&self, body: &mut T, world: &W, dt: f32) -> CollisionInfo {
+        self.step_with_obstacles(body, world, &[], dt)
+    }
+
+    /// Like [`Self::step`], but also collides against `obstacles` — other entities treated as
+    /// immovable solids (typically `Kinematic` and `Static` bodies such as moving platforms).
+    /// Non-dynamic bodies are left untouched: they move only by explicit position sets.
+    pub fn step_with_obstacles<T: KinematicBody, W: CollisionMap>(
+        &self,
+        body: &mut T,
+        world: &W,
+        obstacles: &[&PhysicsEntity],
+        dt: f32,
+    ) -> CollisionInfo {
         let entity = body.get_physics();
+        if entity.body_type != BodyType::Dynamic {
+            return CollisionInfo::default();
+        }
+        let was_grounded = entity.is_grounded;
 
         // Apply Gravity
         entity.velocity.y -= self.gravity * dt;
 
         // Apply Drag (Friction)
         // Horizontal friction (X and Z)
-        let friction_coeff = if entity.is_grounded { 0.01f32 } else { 0.5f32 };
+        let friction_coeff = if entity.is_grounded { self.ground_friction } else { self.air_friction };
         let friction = friction_coeff.powf(dt); // Quick way to handle frame-independent decay
         entity.velocity.x *= friction;
         entity.velocity.z *= friction;
+        if self.apply_vertical_drag {
+            entity.velocity.y *= friction;
+        }
+
+        // Collision math below works on the AABB min corner; restore the anchor afterwards
+        entity.position -= entity.anchor;
 
         // Move Axis-by-Axis
-        self.move_axis(entity, world, dt, 0); // X
-        self.move_axis(entity, world, dt, 1); // Y
-        self.move_axis(entity, world, dt, 2); // Z
+        let hit_x = self.move_axis(entity, world, obstacles, dt, 0); // X
+        let hit_y = self.move_axis(entity, world, obstacles, dt, 1); // Y
+        let hit_z = self.move_axis(entity, world, obstacles, dt, 2); // Z
+
+        // Probe below the feet every step; a resting body with zero vertical velocity
+        // never collides on Y, so the move alone can't keep the flag current.
+        entity.is_grounded = self.is_on_ground(entity, world, obstacles);
+
+        entity.position += entity.anchor;
+
+        CollisionInfo {
+            hit_x,
+            hit_y,
+            hit_z,
+            landed: entity.is_grounded && !was_grounded,
+            ceiling: hit_y > 0,
+        }
     }
 
-    fn move_axis<W: CollisionMap>(&self, entity: &mut PhysicsEntity, world: &W, dt: f32, axis: usize) {
-        if entity.velocity[axis] == 0.0 { return; }
+    /// Moves along one axis, split into sub-steps no longer than the entity's extent on that
+    /// axis (and at most one block), so a fast body can't skip over a wall between frames.
+    /// Returns the sign of the blocked movement, or 0 if the move wasn't blocked.
+    fn move_axis<W: CollisionMap>(
+        &self,
+        entity: &mut PhysicsEntity,
+        world: &W,
+        obstacles: &[&PhysicsEntity],
+        dt: f32,
+        axis: usize,
+    ) -> i8 {
+        if entity.velocity[axis] == 0.0 { return 0; }
 
         let movement = entity.velocity[axis] * dt;
+        let max_step = if entity.size[axis] > 0.0 { entity.size[axis].min(1.0) } else { 1.0 };
+        let steps = (movement.abs() / max_step).ceil().max(1.0) as u32;
+        let step = movement / steps as f32;
+
+        for _ in 0..steps {
+            let before = (entity.position, entity.velocity, entity.is_grounded);
+            if !self.move_axis_step(entity, world, obstacles, step, axis) {
+                continue;
+            }
+
+            if axis != 1 && before.2 && self.step_height > 0.0 {
+                let blocked = (entity.position, entity.velocity, entity.is_grounded);
+                (entity.position, entity.velocity, entity.is_grounded) = before;
+                if self.try_step_up(entity, world, obstacles, step, axis) {
+                    continue;
+                }
+                (entity.position, entity.velocity, entity.is_grounded) = blocked;
+            }
+            return if step > 0.0 { 1 } else { -1 };
+        }
+        0
+    }
+
+    /// Retries a blocked horizontal move `step_height` higher. Succeeds only if the raised box
+    /// has headroom, the raised move is clear, and the entity then lands on the ledge;
+    /// otherwise leaves the entity where it was and returns `false`.
+    fn try_step_up<W: CollisionMap>(
+        &self,
+        entity: &mut PhysicsEntity,
+        world: &W,
+        obstacles: &[&PhysicsEntity],
+        movement: f32,
+        axis: usize,
+    ) -> bool {
+        let mut raised = entity.position;
+        raised.y += self.step_height;
+        if self.is_blocked(entity, raised, world, obstacles) {
+            return false; // Ceiling in the way
+        }
+        raised[axis] += movement;
+        if self.is_blocked(entity, raised, world, obstacles) {
+            return false; // Wall taller than a step
+        }
+
+        let original = (entity.position, entity.velocity);
+        entity.position = raised;
+        if self.move_axis_step(entity, world, obstacles, -self.step_height, 1) {
+            return true;
+        }
+        // Nothing to stand on after the raised move
+        (entity.position, entity.velocity) = original;
+        false
+    }
+
+    /// Returns `true` if solid blocks or an obstacle top lie within [`GROUND_PROBE`] below
+    /// the entity's feet.
+    fn is_on_ground<W: CollisionMap>(&self, entity: &PhysicsEntity, world: &W, obstacles: &[&PhysicsEntity]) -> bool {
+        let mut probe_pos = entity.position;
+        probe_pos.y -= GROUND_PROBE;
+        let probe_size = glm::vec3(entity.size.x, GROUND_PROBE, entity.size.z);
+        if self.is_colliding(probe_pos, probe_size, world) {
+            return true;
+        }
+
+        obstacles.iter().any(|o| {
+            let o_min = o.aabb_min();
+            let top = o_min.y + o.size.y;
+            let overlaps = |axis: usize| {
+                entity.position[axis] < o_min[axis] + o.size[axis]
+                    && o_min[axis] < entity.position[axis] + entity.size[axis]
+            };
+            overlaps(0) && overlaps(2) && top <= entity.position.y && top >= probe_pos.y
+        })
+    }
+
+    /// Returns `true` if `entity`'s box at `pos` overlaps solid blocks or any obstacle.
+    fn is_blocked<W: CollisionMap>(
+        &self,
+        entity: &PhysicsEntity,
+        pos: glm::Vec3,
+        world: &W,
+        obstacles: &[&PhysicsEntity],
+    ) -> bool {
+        obstacles.iter().any(|o| entity.overlaps_at(pos, o)) || self.is_colliding(pos, entity.size, world)
+    }
+
+    /// Moves `movement` along `axis` if the destination is free; otherwise snaps flush against
+    /// what was hit, zeroes that velocity component, and returns `true`.
+    fn move_axis_step<W: CollisionMap>(
+        &self,
+        entity: &mut PhysicsEntity,
+        world: &W,
+        obstacles: &[&PhysicsEntity],
+        movement: f32,
+        axis: usize,
+    ) -> bool {
         let mut new_pos = entity.position;
         new_pos[axis] += movement;
 
-        if self.is_colliding(new_pos, entity.size, world) {
+        if let Some(obstacle) = obstacles.iter().find(|o| entity.overlaps_at(new_pos, o)) {
+            // Snap flush against the obstacle's facing side
+            entity.velocity[axis] = 0.0;
+            if movement > 0.0 {
+                entity.position[axis] = obstacle.aabb_min()[axis] - entity.size[axis] - 0.001;
+            } else {
+                entity.position[axis] = obstacle.aabb_max()[axis] + 0.001;
+                if axis == 1 {
+                    entity.is_grounded = true;
+                }
+            }
+            true
+        } else if let Some(edge) = self.blocking_edge(new_pos, entity.size, world, axis, movement > 0.0) {
             entity.velocity[axis] = 0.0;
 
             if movement > 0.0 {
                 // Hitting a wall in front of us (Positive direction)
-                // Snap the MAX side of our AABB to the MIN side of the block.
-                // The block's min side is (pos + size).floor()
-                let hit_block_edge = (new_pos[axis] + entity.size[axis]).floor();
-                entity.position[axis] = hit_block_edge - entity.size[axis] - 0.001;
+                // Snap the MAX side of our AABB to the nearest MIN side of what we hit.
+                entity.position[axis] = edge - entity.size[axis] - 0.001;
             } else {
                 // Hitting a wall behind us (Negative direction)
-                // Snap our MIN side to the MAX side of the block.
-                // The block's max side is new_pos.floor() + 1.0
-                let hit_block_edge = new_pos[axis].floor() + 1.0;
-                entity.position[axis] = hit_block_edge + 0.001;
+                // Snap our MIN side to the nearest MAX side of what we hit.
+                entity.position[axis] = edge + 0.001;
             }
 
             if axis == 1 && movement < 0.0 {
                 entity.is_grounded = true;
             }
+            true
         } else {
             entity.position[axis] = new_pos[axis];
             if axis == 1 && movement != 0.0 {
                 entity.is_grounded = false;
             }
+            false
         }
     }
 
     fn is_colliding<W: CollisionMap>(&self, pos: glm::Vec3, size: glm::Vec3, world: &W) -> bool {
+        self.visit_block_boxes(pos, size, world, |_, _| true)
+    }
+
+    /// Returns the blocking face along `axis` nearest the mover among the block boxes the AABB
+    /// at `pos` overlaps: the lowest box min when moving `positive`, else the highest box max.
+    fn blocking_edge<W: CollisionMap>(
+        &self,
+        pos: glm::Vec3,
+        size: glm::Vec3,
+        world: &W,
+        axis: usize,
+        positive: bool,
+    ) -> Option<f32> {
+        let mut edge: Option<f32> = None;
+        self.visit_block_boxes(pos, size, world, |min, max| {
+            edge = Some(match (edge, positive) {
+                (Some(e), true) => e.min(min[axis]),
+                (Some(e), false) => e.max(max[axis]),
+                (None, true) => min[axis],
+                (None, false) => max[axis],
+            });
+            false
+        });
+        edge
+    }
+
+    /// Calls `visit(min, max)` with the world-space box of every block collision shape the AABB
+    /// at `pos` overlaps, stopping as soon as `visit` returns `true`. Returns whether it stopped.
+    fn visit_block_boxes<W: CollisionMap>(
+        &self,
+        pos: glm::Vec3,
+        size: glm::Vec3,
+        world: &W,
+        mut visit: impl FnMut(glm::Vec3, glm::Vec3) -> bool,
+    ) -> bool {
         // Calculate the min and max bounds of the AABB
         // Note: We subtract a tiny epsilon from the max so we don't
         // collide with a block we are just "touching" the edge of.
@@ -76,17 +314,29 @@ impl PhysicsSystem {
         let max_y = (pos.y + size.y - 0.001).floor() as i32;
         let max_z = (pos.z + size.z - 0.001).floor() as i32;
 
-        // 2. Iterate through every block coordinate in that volume
+        // Iterate through every block coordinate in that volume
         for x in min_x..=max_x {
             for y in min_y..=max_y {
                 for z in min_z..=max_z {
-                    // 3. If any block in the range is solid, it's a collision
-                    if world.is_solid_at(x as f32, y as f32, z as f32) {
-                        return true;
+                    let origin = glm::vec3(x as f32, y as f32, z as f32);
+                    for shape in world.block_aabbs(x as f32, y as f32, z as f32) {
+                        let (box_min, box_max) = (origin + shape.min, origin + shape.max);
+                        // Fast path: a full cube in range always overlaps
+                        if *shape != Aabb::UNIT && !Self::overlaps(pos, size, box_min, box_max) {
+                            continue;
+                        }
+                        if visit(box_min, box_max) {
+                            return true;
+                        }
                     }
                 }
             }
         }
         false
     }
+
+    /// Box overlap with the same touching tolerance as the block range in `visit_block_boxes`.
+    fn overlaps(pos: glm::Vec3, size: glm::Vec3, box_min: glm::Vec3, box_max: glm::Vec3) -> bool {
+        (0..3).all(|axis| pos[axis] < box_max[axis] && box_min[axis] <= pos[axis] + size[axis] - 0.001)
+    }
 }
\ No newline at end of file
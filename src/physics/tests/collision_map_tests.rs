@@ -0,0 +1,73 @@
+use nalgebra_glm as glm;
+use crate::physics::collision_map::{CollisionMap, MAX_RAYCAST_DISTANCE};
+use crate::physics::coordinates::Coordinates;
+
+/// A single solid block at `(3, 0, 0)`.
+struct OneBlock;
+
+impl CollisionMap for OneBlock {
+    fn is_solid_at(&self, x: f32, y: f32, z: f32) -> bool {
+        (x, y, z) == (3.0, 0.0, 0.0)
+    }
+}
+
+#[test]
+fn test_raycast_axis_aligned_hit() {
+    let hit = OneBlock.raycast(glm::vec3(0.5, 0.5, 0.5), glm::vec3(1.0, 0.0, 0.0), 10.0).unwrap();
+
+    assert_eq!(hit.block_pos, Coordinates::new(3, 0, 0));
+    assert_eq!(hit.face_normal, glm::vec3(-1, 0, 0));
+}
+
+#[test]
+fn test_raycast_negative_direction_hits_far_face() {
+    let hit = OneBlock.raycast(glm::vec3(6.5, 0.5, 0.5), glm::vec3(-1.0, 0.0, 0.0), 10.0).unwrap();
+
+    assert_eq!(hit.block_pos, Coordinates::new(3, 0, 0));
+    assert_eq!(hit.face_normal, glm::vec3(1, 0, 0));
+}
+
+#[test]
+fn test_raycast_diagonal_enters_top_face() {
+    // Crosses x = 3 above the block, then drops in through its top
+    let hit = OneBlock.raycast(glm::vec3(2.8, 1.5, 0.5), glm::vec3(1.0, -1.0, 0.0), 10.0).unwrap();
+
+    assert_eq!(hit.block_pos, Coordinates::new(3, 0, 0));
+    assert_eq!(hit.face_normal, glm::vec3(0, 1, 0));
+}
+
+#[test]
+fn test_raycast_respects_max_dist() {
+    // The block face is 2.5 away
+    assert!(OneBlock.raycast(glm::vec3(0.5, 0.5, 0.5), glm::vec3(1.0, 0.0, 0.0), 2.0).is_none());
+    assert!(OneBlock.raycast(glm::vec3(0.5, 0.5, 0.5), glm::vec3(1.0, 0.0, 0.0), 2.6).is_some());
+}
+
+#[test]
+fn test_raycast_misses() {
+    assert!(OneBlock.raycast(glm::vec3(0.5, 0.5, 0.5), glm::vec3(0.0, 1.0, 0.0), 100.0).is_none());
+    assert!(OneBlock.raycast(glm::vec3(0.5, 0.5, 0.5), glm::Vec3::zeros(), 100.0).is_none());
+}
+
+#[test]
+fn test_raycast_unbounded_miss_terminates() {
+    let origin = glm::vec3(0.5, 0.5, 0.5);
+    assert!(OneBlock.raycast(origin, glm::vec3(0.0, 1.0, 0.3), f32::INFINITY).is_none());
+    assert!(OneBlock.raycast(origin, glm::vec3(-1.0, 0.0, 0.0), f32::MAX).is_none());
+    assert!(OneBlock.raycast(origin, glm::vec3(0.0, 0.0, 1.0), f32::NAN).is_none());
+    assert!(OneBlock.raycast(origin, glm::vec3(1.0, 0.0, 0.0), f32::INFINITY).is_some());
+}
+
+#[test]
+fn test_raycast_clamps_to_max_distance() {
+    let origin = glm::vec3(3.5 - MAX_RAYCAST_DISTANCE - 1.0, 0.5, 0.5);
+    assert!(OneBlock.raycast(origin, glm::vec3(1.0, 0.0, 0.0), f32::INFINITY).is_none());
+}
+
+#[test]
+fn test_raycast_starting_inside_block() {
+    let hit = OneBlock.raycast(glm::vec3(3.5, 0.5, 0.5), glm::vec3(0.0, 0.0, 1.0), 10.0).unwrap();
+
+    assert_eq!(hit.block_pos, Coordinates::new(3, 0, 0));
+    assert_eq!(hit.face_normal, glm::IVec3::zeros());
+}
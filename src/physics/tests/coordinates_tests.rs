@@ -0,0 +1,71 @@
+use crate::physics::coordinates::*;
+
+#[test]
+fn test_world_to_chunk_at_origin() {
+    let size = ChunkSize::cubic(16);
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(0, 0, 0));
+
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(0, 0, 0)));
+    assert_eq!(local, LocalCoord { x: 0, y: 0, z: 0 });
+}
+
+#[test]
+fn test_world_to_chunk_positive_boundary() {
+    let size = ChunkSize::cubic(16);
+
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(15, 15, 15));
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(0, 0, 0)));
+    assert_eq!(local, LocalCoord { x: 15, y: 15, z: 15 });
+
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(16, 16, 16));
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(1, 1, 1)));
+    assert_eq!(local, LocalCoord { x: 0, y: 0, z: 0 });
+}
+
+#[test]
+fn test_world_to_chunk_negative_boundary() {
+    let size = ChunkSize::cubic(16);
+
+    // -1 must floor to chunk -1, local size - 1 (not chunk 0, local -1)
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(-1, -1, -1));
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(-1, -1, -1)));
+    assert_eq!(local, LocalCoord { x: 15, y: 15, z: 15 });
+
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(-16, -16, -16));
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(-1, -1, -1)));
+    assert_eq!(local, LocalCoord { x: 0, y: 0, z: 0 });
+
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(-17, -17, -17));
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(-2, -2, -2)));
+    assert_eq!(local, LocalCoord { x: 15, y: 15, z: 15 });
+}
+
+#[test]
+fn test_non_cubic_chunk_size() {
+    let size = ChunkSize::new(16, 256, 32);
+    let (chunk, local) = size.world_to_chunk(Coordinates::new(-1, 300, 40));
+
+    assert_eq!(chunk, ChunkCoord(Coordinates::new(-1, 1, 1)));
+    assert_eq!(local, LocalCoord { x: 15, y: 44, z: 8 });
+}
+
+#[test]
+fn test_chunk_to_world_origin() {
+    let size = ChunkSize::cubic(16);
+
+    assert_eq!(size.chunk_to_world_origin(ChunkCoord(Coordinates::new(0, 0, 0))), Coordinates::new(0, 0, 0));
+    assert_eq!(size.chunk_to_world_origin(ChunkCoord(Coordinates::new(2, 0, -1))), Coordinates::new(32, 0, -16));
+    assert_eq!(size.chunk_to_world_origin(ChunkCoord(Coordinates::new(-1, -1, -1))), Coordinates::new(-16, -16, -16));
+}
+
+#[test]
+fn test_round_trip_across_origin() {
+    let size = ChunkSize::new(16, 32, 8);
+    for v in -40..40 {
+        let world = Coordinates::new(v, v * 3, -v);
+        let (chunk, local) = size.world_to_chunk(world);
+
+        assert!(local.x < size.x && local.y < size.y && local.z < size.z);
+        assert_eq!(size.local_to_world(chunk, local), world, "round trip for {:?}", world);
+    }
+}
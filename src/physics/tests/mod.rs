@@ -0,0 +1,4 @@
+pub mod coordinates_tests;
+pub mod collision_map_tests;
+pub mod physics_system_tests;
+pub mod physics_entity_tests;
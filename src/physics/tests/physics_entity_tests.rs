@@ -0,0 +1,20 @@
+use nalgebra_glm as glm;
+use crate::physics::physics_entity::PhysicsEntity;
+
+#[test]
+fn test_default_anchor_is_min_corner() {
+    let entity = PhysicsEntity::new(glm::vec3(1.0, 2.0, 3.0), glm::vec3(0.6, 1.8, 0.6));
+
+    assert_eq!(entity.aabb_min(), glm::vec3(1.0, 2.0, 3.0));
+    assert_eq!(entity.feet(), glm::vec3(1.3, 2.0, 3.3));
+    assert_eq!(entity.center(), glm::vec3(1.3, 2.9, 3.3));
+}
+
+#[test]
+fn test_feet_anchor_offsets_aabb() {
+    let entity = PhysicsEntity::new(glm::vec3(1.0, 2.0, 3.0), glm::vec3(0.6, 1.8, 0.6)).with_feet_anchor();
+
+    assert_eq!(entity.feet(), glm::vec3(1.0, 2.0, 3.0));
+    assert!(glm::distance(&entity.aabb_min(), &glm::vec3(0.7, 2.0, 2.7)) < 1e-6);
+    assert!(glm::distance(&entity.aabb_max(), &glm::vec3(1.3, 3.8, 3.3)) < 1e-6);
+}
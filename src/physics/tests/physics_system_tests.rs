@@ -0,0 +1,286 @@
+use nalgebra_glm as glm;
+use crate::math::aabb::Aabb;
+use crate::physics::collision_map::CollisionMap;
+use crate::physics::physics_entity::{BodyType, KinematicBody, PhysicsEntity};
+use crate::physics::physics_system::{CollisionInfo, PhysicsSystem};
+
+/// A world with no solid blocks.
+struct EmptyWorld;
+
+impl CollisionMap for EmptyWorld {
+    fn is_solid_at(&self, _x: f32, _y: f32, _z: f32) -> bool {
+        false
+    }
+}
+
+struct Body(PhysicsEntity);
+
+impl KinematicBody for Body {
+    fn get_physics(&mut self) -> &mut PhysicsEntity {
+        &mut self.0
+    }
+}
+
+fn system() -> PhysicsSystem {
+    PhysicsSystem::new(20.0)
+}
+
+#[test]
+fn test_kinematic_body_ignores_gravity() {
+    let mut platform = Body(
+        PhysicsEntity::new(glm::vec3(0.0, 10.0, 0.0), glm::vec3(2.0, 1.0, 2.0))
+            .with_body_type(BodyType::Kinematic),
+    );
+    system().step(&mut platform, &EmptyWorld, 0.1);
+
+    assert_eq!(platform.0.position, glm::vec3(0.0, 10.0, 0.0));
+    assert_eq!(platform.0.velocity, glm::Vec3::zeros());
+}
+
+#[test]
+fn test_dynamic_body_falls() {
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 10.0, 0.0), glm::vec3(1.0, 1.0, 1.0)));
+    system().step(&mut body, &EmptyWorld, 0.1);
+
+    assert!(body.0.position.y < 10.0);
+}
+
+#[test]
+fn test_kinematic_body_blocks_dynamic_and_is_not_pushed() {
+    let platform = PhysicsEntity::new(glm::vec3(0.0, 0.0, 0.0), glm::vec3(4.0, 1.0, 4.0))
+        .with_body_type(BodyType::Kinematic);
+    let mut player = Body(PhysicsEntity::new(glm::vec3(1.0, 1.05, 1.0), glm::vec3(0.6, 1.8, 0.6)));
+
+    for _ in 0..10 {
+        system().step_with_obstacles(&mut player, &EmptyWorld, &[&platform], 0.05);
+    }
+
+    // Player rests on top of the platform
+    assert!(player.0.is_grounded);
+    assert!((player.0.position.y - 1.001).abs() < 1e-4);
+    // Platform is unaffected
+    assert_eq!(platform.position, glm::vec3(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_kinematic_body_blocks_horizontal_movement() {
+    let wall = PhysicsEntity::new(glm::vec3(2.0, 0.0, 0.0), glm::vec3(1.0, 3.0, 3.0))
+        .with_body_type(BodyType::Static);
+    let mut player = Body(PhysicsEntity::new(glm::vec3(0.0, 0.0, 1.0), glm::vec3(0.6, 1.8, 0.6)));
+    player.0.velocity.x = 20.0;
+
+    system().step_with_obstacles(&mut player, &EmptyWorld, &[&wall], 0.1);
+
+    assert!(player.0.position.x + player.0.size.x <= wall.position.x);
+    assert_eq!(player.0.velocity.x, 0.0);
+}
+
+/// A one-block-thick wall filling the `x = 5` slab.
+struct WallWorld;
+
+impl CollisionMap for WallWorld {
+    fn is_solid_at(&self, x: f32, _y: f32, _z: f32) -> bool {
+        x.floor() == 5.0
+    }
+}
+
+#[test]
+fn test_fast_body_does_not_tunnel_through_wall() {
+    let mut bullet = Body(PhysicsEntity::new(glm::vec3(0.0, 0.0, 0.0), glm::vec3(0.6, 0.6, 0.6)));
+    bullet.0.velocity.x = 100.0;
+
+    // 10 units in one step would land well past the wall
+    system().step(&mut bullet, &WallWorld, 0.1);
+
+    assert!((bullet.0.position.x + bullet.0.size.x - (5.0 - 0.001)).abs() < 1e-4);
+    assert_eq!(bullet.0.velocity.x, 0.0);
+}
+
+#[test]
+fn test_default_friction_values() {
+    let physics = PhysicsSystem::new(9.8);
+    assert_eq!(physics.ground_friction, 0.01);
+    assert_eq!(physics.air_friction, 0.5);
+    assert!(!physics.apply_vertical_drag);
+}
+
+#[test]
+fn test_air_friction_is_configurable() {
+    let mut physics = PhysicsSystem::new(0.0);
+    physics.air_friction = 1.0;
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 10.0, 0.0), glm::vec3(1.0, 1.0, 1.0)));
+    body.0.velocity.x = 4.0;
+
+    physics.step(&mut body, &EmptyWorld, 0.5);
+
+    // No decay: full air control keeps all momentum
+    assert_eq!(body.0.velocity.x, 4.0);
+}
+
+#[test]
+fn test_vertical_drag() {
+    let mut physics = PhysicsSystem::new(0.0);
+    physics.apply_vertical_drag = true;
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 10.0, 0.0), glm::vec3(1.0, 1.0, 1.0)));
+    body.0.velocity.y = 4.0;
+
+    physics.step(&mut body, &EmptyWorld, 1.0);
+
+    assert!((body.0.velocity.y - 2.0).abs() < 1e-5);
+}
+
+/// Solid ground below `y = 1` plus, from `x = 3` on, a wall `wall_height` blocks tall.
+struct LedgeWorld {
+    wall_height: f32,
+}
+
+impl CollisionMap for LedgeWorld {
+    fn is_solid_at(&self, x: f32, y: f32, _z: f32) -> bool {
+        y < 1.0 || (x >= 3.0 && y < 1.0 + self.wall_height)
+    }
+}
+
+fn walk_into(world: &LedgeWorld) -> PhysicsEntity {
+    let mut physics = PhysicsSystem::new(20.0);
+    physics.step_height = 1.0;
+    let mut player = Body(PhysicsEntity::new(glm::vec3(1.0, 1.001, 0.2), glm::vec3(0.6, 1.8, 0.6)));
+
+    for _ in 0..40 {
+        player.0.velocity.x = 5.0;
+        physics.step(&mut player, world, 0.05);
+    }
+    player.0
+}
+
+#[test]
+fn test_steps_up_one_block_ledge() {
+    let player = walk_into(&LedgeWorld { wall_height: 1.0 });
+
+    assert!(player.position.x > 3.0);
+    assert!((player.position.y - 2.001).abs() < 1e-3);
+    assert!(player.is_grounded);
+}
+
+#[test]
+fn test_does_not_step_up_two_block_wall() {
+    let player = walk_into(&LedgeWorld { wall_height: 2.0 });
+
+    assert!((player.position.x + player.size.x - 2.999).abs() < 1e-3);
+    assert!((player.position.y - 1.001).abs() < 1e-3);
+}
+
+#[test]
+fn test_collision_info_reports_landing_then_resting() {
+    let world = LedgeWorld { wall_height: 0.0 };
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 1.2, 0.0), glm::vec3(0.6, 1.8, 0.6)));
+
+    let mut info = CollisionInfo::default();
+    for _ in 0..10 {
+        info = system().step(&mut body, &world, 0.05);
+        if info.landed {
+            break;
+        }
+    }
+    assert!(info.landed);
+    assert_eq!(info.hit_y, -1);
+    assert!(!info.ceiling);
+
+    // Already grounded: still hits the floor, but no longer counts as landing
+    let info = system().step(&mut body, &world, 0.05);
+    assert_eq!(info.hit_y, -1);
+    assert!(!info.landed);
+}
+
+#[test]
+fn test_collision_info_reports_wall_and_ceiling() {
+    let wall = PhysicsEntity::new(glm::vec3(2.0, 0.0, 0.0), glm::vec3(1.0, 3.0, 3.0))
+        .with_body_type(BodyType::Static);
+    let mut player = Body(PhysicsEntity::new(glm::vec3(0.0, 0.0, 1.0), glm::vec3(0.6, 1.8, 0.6)));
+    player.0.velocity.x = 20.0;
+    let info = system().step_with_obstacles(&mut player, &EmptyWorld, &[&wall], 0.1);
+    assert_eq!(info.hit_x, 1);
+    assert!(info.any());
+
+    let roof = PhysicsEntity::new(glm::vec3(0.0, 3.0, 0.0), glm::vec3(4.0, 1.0, 4.0))
+        .with_body_type(BodyType::Static);
+    let mut jumper = Body(PhysicsEntity::new(glm::vec3(1.0, 1.0, 1.0), glm::vec3(0.6, 1.8, 0.6)));
+    jumper.0.velocity.y = 10.0;
+    let info = system().step_with_obstacles(&mut jumper, &EmptyWorld, &[&roof], 0.1);
+    assert!(info.ceiling);
+    assert_eq!(info.hit_y, 1);
+}
+
+#[test]
+fn test_resting_body_stays_grounded() {
+    let world = LedgeWorld { wall_height: 0.0 };
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 1.001, 0.0), glm::vec3(0.6, 1.8, 0.6)));
+
+    for _ in 0..200 {
+        system().step(&mut body, &world, 1.0 / 60.0);
+        assert!(body.0.is_grounded);
+    }
+}
+
+#[test]
+fn test_grounded_without_vertical_velocity() {
+    // Zero gravity: Y never moves, so only the probe can notice the floor
+    let physics = PhysicsSystem::new(0.0);
+    let world = LedgeWorld { wall_height: 0.0 };
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.0, 1.001, 0.0), glm::vec3(0.6, 1.8, 0.6)));
+
+    physics.step(&mut body, &world, 0.05);
+    assert!(body.0.is_grounded);
+
+    body.0.position.y = 3.0;
+    physics.step(&mut body, &world, 0.05);
+    assert!(!body.0.is_grounded);
+}
+
+/// Bottom slabs (half-height boxes) covering the `y = 0` layer.
+struct SlabWorld;
+
+const BOTTOM_SLAB: [Aabb; 1] = [Aabb::new(glm::Vec3::new(0.0, 0.0, 0.0), glm::Vec3::new(1.0, 0.5, 1.0))];
+
+impl CollisionMap for SlabWorld {
+    fn is_solid_at(&self, _x: f32, y: f32, _z: f32) -> bool {
+        y.floor() == 0.0
+    }
+
+    fn block_aabbs(&self, x: f32, y: f32, z: f32) -> &[Aabb] {
+        if self.is_solid_at(x, y, z) { &BOTTOM_SLAB } else { &[] }
+    }
+}
+
+#[test]
+fn test_rests_on_slab_top() {
+    let mut body = Body(PhysicsEntity::new(glm::vec3(0.2, 2.0, 0.2), glm::vec3(0.6, 1.8, 0.6)));
+
+    for _ in 0..60 {
+        system().step(&mut body, &SlabWorld, 1.0 / 60.0);
+    }
+
+    assert!(body.0.is_grounded);
+    assert!((body.0.position.y - 0.501).abs() < 1e-4);
+}
+
+#[test]
+fn test_default_block_aabbs_is_full_cube() {
+    assert_eq!(WallWorld.block_aabbs(5.0, 0.0, 0.0), &[Aabb::UNIT]);
+    assert!(WallWorld.block_aabbs(4.0, 0.0, 0.0).is_empty());
+}
+
+#[test]
+fn test_feet_anchored_body_lands_on_ground() {
+    let world = LedgeWorld { wall_height: 1.0 };
+    // Feet-centered at x = 2.6 the AABB spans 2.3..2.9, clear of the wall at x = 3;
+    // treating the position as the min corner would put it inside the wall.
+    let mut body = Body(PhysicsEntity::new(glm::vec3(2.6, 1.5, 0.5), glm::vec3(0.6, 1.8, 0.6)).with_feet_anchor());
+
+    for _ in 0..60 {
+        system().step(&mut body, &world, 1.0 / 60.0);
+    }
+
+    assert!(body.0.is_grounded);
+    assert!((body.0.position.y - 1.001).abs() < 1e-4);
+    assert!((body.0.position.x - 2.6).abs() < 1e-4);
+}
@@ -1,5 +1,9 @@
 pub mod render_context;
 pub mod render_command;
 pub mod render_queue;
+pub mod oit;
+pub mod render_environment;
 pub(crate) mod renderer;
-mod render_environment;
\ No newline at end of file
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
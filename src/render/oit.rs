@@ -0,0 +1,200 @@
+use nalgebra_glm as glm;
+
+/// How the renderer draws the transparent queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransparencyMode {
+    /// Classic alpha blending in queue order.
+    #[default]
+    Sorted,
+    /// Weighted blended order-independent transparency: no sorting required, and
+    /// interpenetrating transparent geometry blends correctly. Transparent draws use the
+    /// built-in OIT accumulation shader unless the queue has a shader override.
+    WeightedBlended,
+}
+
+/// Constants of [`oit_weight`] and the composite, written out the same way in the built-in
+/// OIT shaders; `oit_tests` checks the shader sources against them.
+pub(crate) const WEIGHT_SCALE: f32 = 10.0;
+pub(crate) const WEIGHT_NEAR: f32 = 5.0;
+pub(crate) const WEIGHT_FAR: f32 = 200.0;
+pub(crate) const WEIGHT_RANGE: (f32, f32) = (1e-2, 3e3);
+/// Keeps divisions by accumulated values away from zero.
+pub(crate) const OIT_EPSILON: f32 = 1e-5;
+
+/// Depth/alpha weight for weighted blended OIT (McGuire & Bavoil 2013, eq. 9).
+/// `view_depth` is the positive distance along the view axis. Mirrors `oitWeight` in `oit_accum_fragment.glsl`.
+pub fn oit_weight(view_depth: f32, alpha: f32) -> f32 {
+    let z = view_depth.abs();
+    let falloff = OIT_EPSILON + (z / WEIGHT_NEAR).powi(2) + (z / WEIGHT_FAR).powi(6);
+    alpha * (WEIGHT_SCALE / falloff).clamp(WEIGHT_RANGE.0, WEIGHT_RANGE.1)
+}
+
+/// CPU reference of one pixel of the OIT buffers, following the same blend equations as the GPU path.
+#[derive(Debug, Clone, Copy)]
+pub struct OitAccumulator {
+    /// Sum of `(rgb * alpha, alpha) * weight`.
+    pub accum: glm::Vec4,
+    /// Product of `(1 - alpha)`; 1.0 means nothing was drawn.
+    pub revealage: f32,
+}
+
+impl OitAccumulator {
+    /// Creates the cleared state (accum = 0, revealage = 1).
+    pub fn new() -> Self {
+        Self { accum: glm::Vec4::zeros(), revealage: 1.0 }
+    }
+
+    /// Accumulates a straight-alpha fragment with the given weight.
+    pub fn add(&mut self, color: glm::Vec4, weight: f32) {
+        let alpha = color.w;
+        self.accum += glm::vec4(color.x * alpha, color.y * alpha, color.z * alpha, alpha) * weight;
+        self.revealage *= 1.0 - alpha;
+    }
+
+    /// Composites the accumulated fragments over `background`.
+    pub fn composite(&self, background: glm::Vec3) -> glm::Vec3 {
+        if self.revealage >= 1.0 {
+            return background;
+        }
+        let average = self.accum.xyz() / self.accum.w.max(OIT_EPSILON);
+        average * (1.0 - self.revealage) + background * self.revealage
+    }
+}
+
+impl Default for OitAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Off-screen targets for the accumulation pass: RGBA16F accum, R16F revealage, and a
+/// depth buffer the opaque depth is blitted into.
+pub(crate) struct OitTargets {
+    fbo: u32,
+    accum: u32,
+    revealage: u32,
+    depth: u32,
+    /// Empty VAO for the attribute-less full-screen triangle.
+    empty_vao: u32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+impl OitTargets {
+    /// Creates the framebuffer and attachments at the given size.
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        let mut fbo = 0;
+        let mut empty_vao = 0;
+        unsafe {
+            gl::GenFramebuffers(1, &mut fbo);
+            gl::GenVertexArrays(1, &mut empty_vao);
+        }
+        let mut targets = Self { fbo, accum: 0, revealage: 0, depth: 0, empty_vao, width: 0, height: 0 };
+        targets.resize(width, height);
+        targets
+    }
+
+    /// Recreates the attachments if the size changed.
+    pub(crate) fn resize(&mut self, width: u32, height: u32) {
+        if self.width == width && self.height == height {
+            return;
+        }
+        unsafe {
+            self.delete_attachments();
+
+            self.accum = create_color_target(gl::RGBA16F, width, height);
+            self.revealage = create_color_target(gl::R16F, width, height);
+
+            // Must match the default framebuffer's depth format for the depth blit
+            gl::GenRenderbuffers(1, &mut self.depth);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, self.depth);
+            gl::RenderbufferStorage(gl::RENDERBUFFER, gl::DEPTH_COMPONENT24, width as i32, height as i32);
+
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+            gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, self.accum, 0);
+            gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT1, gl::TEXTURE_2D, self.revealage, 0);
+            gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, gl::RENDERBUFFER, self.depth);
+            let draw_buffers = [gl::COLOR_ATTACHMENT0, gl::COLOR_ATTACHMENT1];
+            gl::DrawBuffers(2, draw_buffers.as_ptr());
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Copies the opaque depth, clears the accumulation buffers, and binds the framebuffer
+    /// with the accumulation blend state.
+    pub(crate) fn begin_accumulation(&self) {
+        let (w, h) = (self.width as i32, self.height as i32);
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, self.fbo);
+            gl::BlitFramebuffer(0, 0, w, h, 0, 0, w, h, gl::DEPTH_BUFFER_BIT, gl::NEAREST);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+
+            let zero = [0.0f32; 4];
+            let one = [1.0f32; 4];
+            gl::ClearBufferfv(gl::COLOR, 0, zero.as_ptr());
+            gl::ClearBufferfv(gl::COLOR, 1, one.as_ptr());
+
+            gl::Enable(gl::BLEND);
+            gl::BlendFunci(0, gl::ONE, gl::ONE);
+            gl::BlendFunci(1, gl::ZERO, gl::ONE_MINUS_SRC_COLOR);
+            gl::DepthMask(gl::FALSE);
+        }
+    }
+
+    /// Returns to the default framebuffer and binds the accumulation textures for compositing.
+    /// `accum_slot` / `revealage_slot` are the texture units to bind to.
+    pub(crate) fn begin_composite(&self, accum_slot: u32, revealage_slot: u32) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+            gl::Disable(gl::DEPTH_TEST);
+
+            gl::ActiveTexture(gl::TEXTURE0 + accum_slot);
+            gl::BindTexture(gl::TEXTURE_2D, self.accum);
+            gl::ActiveTexture(gl::TEXTURE0 + revealage_slot);
+            gl::BindTexture(gl::TEXTURE_2D, self.revealage);
+        }
+    }
+
+    /// Draws the full-screen triangle and restores depth state.
+    pub(crate) fn draw_fullscreen(&self) {
+        unsafe {
+            gl::BindVertexArray(self.empty_vao);
+            gl::DrawArrays(gl::TRIANGLES, 0, 3);
+            gl::BindVertexArray(0);
+            gl::Enable(gl::DEPTH_TEST);
+            gl::DepthMask(gl::TRUE);
+        }
+    }
+
+    unsafe fn delete_attachments(&mut self) {
+        if self.accum != 0 {
+            gl::DeleteTextures(1, &self.accum);
+            gl::DeleteTextures(1, &self.revealage);
+            gl::DeleteRenderbuffers(1, &self.depth);
+        }
+    }
+}
+
+impl Drop for OitTargets {
+    fn drop(&mut self) {
+        unsafe {
+            self.delete_attachments();
+            gl::DeleteFramebuffers(1, &self.fbo);
+            gl::DeleteVertexArrays(1, &self.empty_vao);
+        }
+    }
+}
+
+unsafe fn create_color_target(internal_format: u32, width: u32, height: u32) -> u32 {
+    let mut id = 0;
+    gl::GenTextures(1, &mut id);
+    gl::BindTexture(gl::TEXTURE_2D, id);
+    gl::TexStorage2D(gl::TEXTURE_2D, 1, internal_format, width as i32, height as i32);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST as i32);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::NEAREST as i32);
+    id
+}
@@ -1,9 +1,22 @@
 use crate::core::handle::Handle;
 use crate::graphics::gpu_mesh::GpuMesh;
 use crate::graphics::material::{Material, TextureBinding, TextureSlot};
+use crate::math::aabb::Aabb;
+use crate::math::frustum::Frustum;
 use nalgebra_glm as glm;
 
+/// `mat4[]` uniform that opts a shader into batching. Consecutive commands that
+/// [`RenderCommand::can_batch_with`] each other are drawn as one instanced draw of up to
+/// [`MAX_BATCH_INSTANCES`], with their transforms uploaded here; the shader reads its model
+/// matrix as `u_InstanceModels[gl_InstanceID]` instead of `model`.
+pub const INSTANCE_MODELS_UNIFORM: &str = "u_InstanceModels";
+
+/// Size a shader should declare [`INSTANCE_MODELS_UNIFORM`] with, and the most commands one
+/// batch draws.
+pub const MAX_BATCH_INSTANCES: usize = 64;
+
 /// A shader uniform value.
+#[derive(PartialEq)]
 pub enum UniformValue {
     Float(f32),
     Int(i32),
@@ -11,9 +24,15 @@ pub enum UniformValue {
     Vec3(glm::Vec3),
     Vec4(glm::Vec4),
     Mat4(glm::Mat4),
+    UInt(u32),
+    IVec3(glm::IVec3),
+    UVec3(glm::UVec3),
+    Vec3Array(Vec<glm::Vec3>),
+    Mat4Array(Vec<glm::Mat4>),
 }
 
 /// A named shader uniform to set before drawing.
+#[derive(PartialEq)]
 pub struct Uniform {
     /// The uniform variable name in the shader.
     pub name: &'static str,
@@ -33,10 +52,18 @@ pub struct RenderCommand {
     pub uniforms: Vec<Uniform>,
     /// Per-draw texture bindings (e.g. per-chunk lightmaps).
     pub textures: Vec<TextureSlot>,
+    /// Number of instances to draw from the mesh's instance buffer; `None` for a plain draw.
+    pub instances: Option<i32>,
+    /// World-space bounding box used for frustum culling; `None` always draws.
+    pub bounds: Option<Aabb>,
+    /// Clip rectangle `(x, y, width, height)` in framebuffer pixels with a bottom-left
+    /// origin (Y up, as `gl::Scissor` takes it); `None` draws unclipped.
+    pub scissor: Option<(i32, i32, i32, i32)>,
 }
 
 impl RenderCommand {
-    /// Creates a render command with no extra uniforms.
+    /// Creates a render command with no extra uniforms. Its empty uniform and texture lists
+    /// don't allocate until the first `with_uniform`/`with_texture`.
     pub fn new(mesh: Handle<GpuMesh>, material: Handle<Material>, transform: glm::Mat4) -> Self {
         Self {
             mesh,
@@ -44,6 +71,9 @@ impl RenderCommand {
             transform,
             uniforms: Vec::new(),
             textures: Vec::new(),
+            instances: None,
+            bounds: None,
+            scissor: None,
         }
     }
 
@@ -58,4 +88,54 @@ impl RenderCommand {
         self.textures.push(TextureSlot { slot, uniform_name, binding });
         self
     }
+
+    /// Draws `count` instances using the mesh's instance buffer (builder pattern).
+    /// See `GpuMesh::set_instance_buffer`.
+    pub fn with_instances(mut self, count: i32) -> Self {
+        self.instances = Some(count);
+        self
+    }
+
+    /// Sets the world-space bounding box used for frustum culling (builder pattern).
+    pub fn with_bounds(mut self, min: glm::Vec3, max: glm::Vec3) -> Self {
+        self.bounds = Some(Aabb::new(min, max));
+        self
+    }
+
+    /// Clips the draw to a framebuffer-pixel rectangle, e.g. a scrollable UI panel (builder pattern).
+    /// `y` counts up from the bottom of the framebuffer.
+    pub fn with_scissor(mut self, x: i32, y: i32, width: i32, height: i32) -> Self {
+        self.scissor = Some((x, y, width, height));
+        self
+    }
+
+    /// Returns `true` if `other` draws the same mesh with the same material, uniforms, textures
+    /// and clip, differing at most in transform and bounds, so both can share one instanced
+    /// draw (see [`INSTANCE_MODELS_UNIFORM`]). Commands with their own instance count never batch.
+    pub fn can_batch_with(&self, other: &RenderCommand) -> bool {
+        self.instances.is_none()
+            && other.instances.is_none()
+            && self.mesh == other.mesh
+            && self.material == other.material
+            && self.scissor == other.scissor
+            && self.uniforms == other.uniforms
+            && self.textures == other.textures
+    }
+
+    /// Returns `true` if the command has no bounds or its bounds intersect `frustum`.
+    pub fn is_visible(&self, frustum: &Frustum) -> bool {
+        match &self.bounds {
+            Some(bounds) => frustum.intersects_aabb(bounds),
+            None => true,
+        }
+    }
+
+    /// Returns the point used for depth sorting: the bounds center, or the transform's
+    /// translation when the command has no bounds.
+    pub fn sort_position(&self) -> glm::Vec3 {
+        match &self.bounds {
+            Some(bounds) => bounds.center(),
+            None => glm::vec3(self.transform[(0, 3)], self.transform[(1, 3)], self.transform[(2, 3)]),
+        }
+    }
 }
@@ -2,6 +2,7 @@ use nalgebra_glm as glm;
 use crate::math::frustum::Frustum;
 use crate::render::render_queue::RenderQueue;
 use crate::render::render_environment::{RenderEnvironment};
+use crate::render::oit::TransparencyMode;
 
 /// Per-frame rendering state holding view/projection matrices, frustum, and render queues.
 pub struct RenderContext {
@@ -20,6 +21,8 @@ pub struct RenderContext {
     pub(crate) gui_projection: glm::Mat4,
     /// Global render variables for the scene
     pub environment: RenderEnvironment,
+    /// How `transparent_queue` is drawn (sorted blending by default).
+    pub transparency: TransparencyMode,
 }
 
 impl RenderContext {
@@ -35,6 +38,69 @@ impl RenderContext {
             gui_queue: RenderQueue::new(),
             gui_projection: glm::ortho(0.0, screen_width, screen_height, 0.0, -1.0, 1.0),
             environment,
+            transparency: TransparencyMode::default(),
         }
     }
+
+    /// Returns the camera's world-space position, recovered from the view matrix.
+    pub fn camera_position(&self) -> glm::Vec3 {
+        let inv = glm::inverse(&self.view);
+        glm::vec3(inv[(0, 3)], inv[(1, 3)], inv[(2, 3)])
+    }
+}
+
+/// What the renderer did in one frame, for finding culling and batching problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderStats {
+    /// Commands submitted across all queues.
+    pub commands: u32,
+    /// Draw calls issued (one per drawn command or batch).
+    pub draw_calls: u32,
+    /// Commands drawn as part of an earlier command's batch instead of their own draw call.
+    pub batched: u32,
+    /// Times a different shader program was bound.
+    pub shader_switches: u32,
+    /// Times a different material's textures and state were bound.
+    pub material_switches: u32,
+    /// Vertices processed across all draw calls, counting every instance.
+    pub vertices: u64,
+    /// Commands skipped because their bounds were outside the frustum.
+    pub culled: u32,
+}
+
+/// Clip-plane distances recovered from a projection matrix, uploaded to shaders as
+/// `uNear`, `uFar`, and `uIsOrtho` so they can linearize depth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthRange {
+    /// Near clip plane distance.
+    pub near: f32,
+    /// Far clip plane distance.
+    pub far: f32,
+    /// `true` for orthographic projections (depth is already linear).
+    pub is_orthographic: bool,
 }
+
+impl DepthRange {
+    /// Extracts near/far from an OpenGL-style (`[-1, 1]` clip depth) perspective or orthographic matrix.
+    pub fn from_projection_matrix(projection: &glm::Mat4) -> Self {
+        let a = projection[(2, 2)];
+        let b = projection[(2, 3)];
+        if projection[(3, 3)] == 1.0 {
+            // Ortho: a = -2 / (f - n), b = -(f + n) / (f - n)
+            Self { near: (b + 1.0) / a, far: (b - 1.0) / a, is_orthographic: true }
+        } else {
+            // Perspective: a = -(f + n) / (f - n), b = -2fn / (f - n)
+            Self { near: b / (a - 1.0), far: b / (a + 1.0), is_orthographic: false }
+        }
+    }
+
+    /// Linearizes a window-space depth value in [0, 1] to view distance; shaders use the same formula.
+    pub fn linearize(&self, depth: f32) -> f32 {
+        let ndc = depth * 2.0 - 1.0;
+        if self.is_orthographic {
+            self.near + (ndc + 1.0) * 0.5 * (self.far - self.near)
+        } else {
+            2.0 * self.near * self.far / (self.far + self.near - ndc * (self.far - self.near))
+        }
+    }
+}
\ No newline at end of file
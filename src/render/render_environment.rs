@@ -1,8 +1,79 @@
 use nalgebra_glm as glm;
+use crate::graphics::shader::Shader;
 
-/// Global scene render variables
+/// Global scene render variables. The renderer uploads each one to every shader that
+/// declares the matching `u_Env*` uniform (`u_EnvSkyColor`, `u_EnvFogDensity`, ...).
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RenderEnvironment {
+    /// Sky color, also used as the frame's clear color. Keep in sync with the `sky_color`
+    /// passed to `propagate_sky` so lit terrain and the visible sky match.
     pub sky_color: glm::Vec3,
+    /// Sky light strength multiplier.
     pub sky_intensity: f32,
-    pub ambient: f32,
-}
\ No newline at end of file
+    /// Minimum light applied to every surface, so unlit areas aren't pitch black.
+    pub ambient_color: glm::Vec3,
+    /// Color distant geometry fades towards.
+    pub fog_color: glm::Vec3,
+    /// Exponential fog density; `0.0` disables fog.
+    pub fog_density: f32,
+    /// Normalized world-space direction the sunlight travels in (from the sun towards the scene).
+    pub sun_direction: glm::Vec3,
+    /// Sunlight color and strength.
+    pub sun_color: glm::Vec3,
+    /// Seconds since the engine started, for animated shaders. Set by the engine each frame.
+    pub time: f32,
+}
+
+impl RenderEnvironment {
+    /// Returns the color the frame is cleared to (`sky_color`, opaque).
+    pub fn clear_color(&self) -> glm::Vec4 {
+        glm::vec4(self.sky_color.x, self.sky_color.y, self.sky_color.z, 1.0)
+    }
+
+    /// `vec3` uniforms set by [`Self::upload`], in field order.
+    pub(crate) const VEC3_UNIFORMS: [&'static str; 5] = [
+        "u_EnvSkyColor",
+        "u_EnvAmbientColor",
+        "u_EnvFogColor",
+        "u_EnvSunDirection",
+        "u_EnvSunColor",
+    ];
+
+    /// `float` uniforms set by [`Self::upload`], in field order.
+    pub(crate) const FLOAT_UNIFORMS: [&'static str; 3] = ["u_EnvSkyIntensity", "u_EnvFogDensity", "u_EnvTime"];
+
+    /// Sets every uniform in [`Self::VEC3_UNIFORMS`] and [`Self::FLOAT_UNIFORMS`] on the bound
+    /// `shader`, skipping any it doesn't declare.
+    pub(crate) fn upload(&self, shader: &Shader) {
+        let vec3s = [&self.sky_color, &self.ambient_color, &self.fog_color, &self.sun_direction, &self.sun_color];
+        for (name, value) in Self::VEC3_UNIFORMS.into_iter().zip(vec3s) {
+            if shader.has_uniform(name) {
+                shader.set_vec3(name, value);
+            }
+        }
+
+        let floats = [self.sky_intensity, self.fog_density, self.time];
+        for (name, value) in Self::FLOAT_UNIFORMS.into_iter().zip(floats) {
+            if shader.has_uniform(name) {
+                shader.set_f32(name, value);
+            }
+        }
+    }
+}
+
+impl Default for RenderEnvironment {
+    /// Sky blue at full intensity with a dim ambient term, no fog, and a white sun
+    /// shining steeply down.
+    fn default() -> Self {
+        Self {
+            sky_color: glm::vec3(0.5, 0.7, 1.0),
+            sky_intensity: 1.0,
+            ambient_color: glm::vec3(0.1, 0.1, 0.1),
+            fog_color: glm::vec3(0.5, 0.7, 1.0),
+            fog_density: 0.0,
+            sun_direction: glm::normalize(&glm::vec3(0.3, -1.0, 0.2)),
+            sun_color: glm::vec3(1.0, 1.0, 1.0),
+            time: 0.0,
+        }
+    }
+}
@@ -1,14 +1,20 @@
+use crate::core::handle::Handle;
+use crate::graphics::gpu_mesh::GpuMesh;
+use crate::graphics::material::Material;
+use crate::graphics::shader::Shader;
 use crate::render::render_command::RenderCommand;
+use nalgebra_glm as glm;
 
 /// An ordered list of render commands processed by the renderer.
 pub struct RenderQueue {
     commands: Vec<RenderCommand>,
+    shader_override: Option<Handle<Shader>>,
 }
 
 impl RenderQueue {
     /// Creates an empty render queue.
     pub fn new() -> Self {
-        Self { commands: Vec::new() }
+        Self { commands: Vec::new(), shader_override: None }
     }
 
     /// Adds a render command to the queue.
@@ -16,14 +22,73 @@ impl RenderQueue {
         self.commands.push(cmd);
     }
 
+    /// Adds a command with no extra uniforms or textures, the common case for chunk draws.
+    /// Its empty uniform and texture lists never allocate; together with `clear` keeping the
+    /// queue's capacity, steady-state frames submit without touching the heap.
+    pub fn submit_simple(&mut self, mesh: Handle<GpuMesh>, material: Handle<Material>, transform: glm::Mat4) {
+        self.commands.push(RenderCommand::new(mesh, material, transform));
+    }
+
     /// Removes all commands from the queue.
     pub fn clear(&mut self) {
         self.commands.clear();
     }
 
+    /// Returns the number of queued commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns how many commands the queue can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.commands.capacity()
+    }
+
+    /// Returns `true` if no commands are queued.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
     /// Sorts commands by material handle to minimize GPU state changes.
+    /// Same as [`Self::sort_by_material_then_mesh`].
     pub fn sort_by_material(&mut self) {
-        self.commands.sort_by_key(|cmd| cmd.material.id);
+        self.sort_by_material_then_mesh();
+    }
+
+    /// Stable sort by material, then mesh handle, so draw order is the same every frame
+    /// (no z-fighting flicker on coplanar geometry) and repeated meshes draw back to back,
+    /// where the renderer can batch them (see [`RenderCommand::can_batch_with`]).
+    pub fn sort_by_material_then_mesh(&mut self) {
+        self.commands.sort_by_key(|cmd| (cmd.material.id, cmd.mesh.id));
+    }
+
+    /// Sorts commands farthest-first from `camera_pos`, as blending transparent geometry
+    /// needs. Distance is measured to each command's bounds center, or its transform's
+    /// translation when it has no bounds.
+    pub fn sort_back_to_front(&mut self, camera_pos: &glm::Vec3) {
+        self.commands.sort_by(|a, b| {
+            let da = glm::distance2(&a.sort_position(), camera_pos);
+            let db = glm::distance2(&b.sort_position(), camera_pos);
+            db.total_cmp(&da)
+        });
+    }
+
+    /// Draws every command in this queue with `shader` instead of its material's shader
+    /// (e.g. depth, normal, or picking passes). Mesh, transform, and textures are still
+    /// taken from each command, so the shader must accept the vertex layouts in use.
+    /// Pass `None` to go back to per-material shaders.
+    pub fn set_shader_override(&mut self, shader: Option<Handle<Shader>>) {
+        self.shader_override = shader;
+    }
+
+    /// Returns the shader override, if one is set.
+    pub fn shader_override(&self) -> Option<Handle<Shader>> {
+        self.shader_override
+    }
+
+    /// Returns the shader a command using `material` is drawn with, honoring the override.
+    pub fn resolve_shader(&self, material: &Material) -> Handle<Shader> {
+        self.shader_override.unwrap_or(material.shader)
     }
 
     /// Returns an iterator over the queued commands.
@@ -1,64 +1,156 @@
 use nalgebra_glm as glm;
-use crate::render::render_command::UniformValue;
-use crate::render::render_context::RenderContext;
+use crate::render::render_command::{UniformValue, INSTANCE_MODELS_UNIFORM, MAX_BATCH_INSTANCES};
+use crate::render::render_context::{DepthRange, RenderContext, RenderStats};
+use crate::math::frustum::Frustum;
 use crate::render::render_queue::RenderQueue;
 use crate::resource::resource_manager::ResourceAccess;
 use crate::graphics::material::TextureBinding;
+use crate::graphics::render_state::RenderState;
 use crate::render::render_environment::{RenderEnvironment};
+use crate::render::oit::{OitTargets, TransparencyMode};
+use crate::core::handle::Handle;
+use crate::graphics::shader::Shader;
 
-pub struct Renderer;
+/// Per-pass inputs to [`Renderer::render_queue`].
+struct Pass<'a> {
+    view: &'a glm::Mat4,
+    projection: &'a glm::Mat4,
+    /// Commands with bounds outside this frustum are skipped; `None` disables culling.
+    frustum: Option<&'a Frustum>,
+    /// GL state applied for the pass, see [`Renderer::render_queue`].
+    state: Option<RenderState>,
+}
+
+pub struct Renderer {
+    /// Built-in accumulation and composite shaders for weighted blended OIT.
+    oit_shaders: Option<(Handle<Shader>, Handle<Shader>)>,
+    /// Lazily created on the first weighted blended frame.
+    oit_targets: Option<OitTargets>,
+    /// Counts from the latest `render` call.
+    stats: RenderStats,
+}
 
 impl Renderer {
     pub fn new() -> Self {
-        Self
+        Self { oit_shaders: None, oit_targets: None, stats: RenderStats::default() }
     }
 
-    pub fn render(&self, ctx: &mut RenderContext, resources: &impl ResourceAccess) {
+    /// Sets the built-in shaders used by [`TransparencyMode::WeightedBlended`].
+    pub fn set_oit_shaders(&mut self, accum: Handle<Shader>, composite: Handle<Shader>) {
+        self.oit_shaders = Some((accum, composite));
+    }
+
+    /// Draws all three queues and returns what it took (draw calls, binds, vertices, culling).
+    pub fn render(&mut self, ctx: &mut RenderContext, resources: &impl ResourceAccess) -> RenderStats {
+        self.stats = RenderStats {
+            commands: (ctx.opaque_queue.len() + ctx.transparent_queue.len() + ctx.gui_queue.len()) as u32,
+            ..RenderStats::default()
+        };
+
         // Opaque pass
-        ctx.opaque_queue.sort_by_material();
-        self.render_queue(&ctx.opaque_queue, &ctx.view, &ctx.projection, resources, &ctx.environment);
-
-        // Transparent pass (blend on, depth writes off to avoid transparent-on-transparent occlusion)
-        unsafe {
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-            gl::DepthMask(gl::FALSE);
-        }
-        ctx.transparent_queue.sort_by_material();
-        self.render_queue(&ctx.transparent_queue, &ctx.view, &ctx.projection, resources, &ctx.environment);
-        unsafe {
-            gl::DepthMask(gl::TRUE);
-        }
+        ctx.opaque_queue.sort_by_material_then_mesh();
+        let pass = Pass { view: &ctx.view, projection: &ctx.projection, frustum: Some(&ctx.frustum), state: Some(RenderState::OPAQUE) };
+        self.render_queue(&ctx.opaque_queue, pass, resources, &ctx.environment);
 
-        // GUI pass (blend already on, disable depth test)
-        unsafe {
-            gl::Disable(gl::DEPTH_TEST);
+        match (ctx.transparency, self.oit_shaders) {
+            (TransparencyMode::WeightedBlended, Some((accum, composite))) => {
+                self.render_transparent_oit(ctx, resources, accum, composite);
+            }
+            _ => {
+                // Blending needs far-to-near order; material order doesn't matter here
+                let camera_pos = ctx.camera_position();
+                ctx.transparent_queue.sort_back_to_front(&camera_pos);
+                let pass = Pass { view: &ctx.view, projection: &ctx.projection, frustum: Some(&ctx.frustum), state: Some(RenderState::TRANSPARENT) };
+                self.render_queue(&ctx.transparent_queue, pass, resources, &ctx.environment);
+            }
         }
+
+        // GUI pass (screen space, so never frustum-culled)
         let identity = glm::identity::<f32, 4>();
-        ctx.gui_queue.sort_by_material();
-        self.render_queue(&ctx.gui_queue, &identity, &ctx.gui_projection, resources, &ctx.environment);
+        ctx.gui_queue.sort_by_material_then_mesh();
+        let pass = Pass { view: &identity, projection: &ctx.gui_projection, frustum: None, state: Some(RenderState::GUI) };
+        self.render_queue(&ctx.gui_queue, pass, resources, &ctx.environment);
         // NOTE: Blend stays enabled and depth test stays disabled here.
         // The engine restores GL state after render_ui() so that immediate-mode
         // GUI drawing (crosshair, text) also benefits from alpha blending.
+
+        self.stats
     }
 
+    /// Weighted blended OIT: accumulate the transparent queue off-screen, then composite
+    /// over the opaque scene with a full-screen pass. Leaves blending enabled like the sorted path.
+    fn render_transparent_oit(
+        &mut self,
+        ctx: &mut RenderContext,
+        resources: &impl ResourceAccess,
+        accum_shader: Handle<Shader>,
+        composite_shader: Handle<Shader>,
+    ) {
+        let mut viewport = [0i32; 4];
+        unsafe { gl::GetIntegerv(gl::VIEWPORT, viewport.as_mut_ptr()) };
+        let (width, height) = (viewport[2].max(1) as u32, viewport[3].max(1) as u32);
+
+        let targets = self.oit_targets.get_or_insert_with(|| OitTargets::new(width, height));
+        targets.resize(width, height);
+        targets.begin_accumulation();
+
+        // Materials' own shaders write a single output; accumulate with the built-in
+        // unless the game supplied an OIT-aware override. Material render states are
+        // ignored here since accumulation needs its own blend functions.
+        let previous_override = ctx.transparent_queue.shader_override();
+        ctx.transparent_queue.set_shader_override(Some(previous_override.unwrap_or(accum_shader)));
+        let pass = Pass { view: &ctx.view, projection: &ctx.projection, frustum: Some(&ctx.frustum), state: None };
+        self.render_queue(&ctx.transparent_queue, pass, resources, &ctx.environment);
+        ctx.transparent_queue.set_shader_override(previous_override);
+
+        let Some(shader) = resources.get(composite_shader) else { return };
+        let targets = self.oit_targets.as_ref().unwrap();
+        targets.begin_composite(0, 1);
+        shader.use_program();
+        shader.set_int("u_Accum", 0);
+        shader.set_int("u_Revealage", 1);
+        targets.draw_fullscreen();
+    }
+
+    /// Draws `queue`, skipping commands outside `pass.frustum` and batching runs of commands
+    /// for shaders that declare [`INSTANCE_MODELS_UNIFORM`]. With a `pass.state`, that
+    /// state is applied up front, each material's own [`RenderState`] is applied when the
+    /// material changes, and the pass state is restored at the end. With `None` the GL
+    /// state is left to the caller.
     fn render_queue(
-        &self,
+        &mut self,
         queue: &RenderQueue,
-        view: &glm::Mat4,
-        projection: &glm::Mat4,
+        pass: Pass,
         resources: &impl ResourceAccess,
         globals: &RenderEnvironment,
     ) {
+        let Pass { view, projection, frustum, state: pass_state } = pass;
         let mut last_shader_id: u32 = 0;
         let mut last_material_id: u32 = u32::MAX;
+        let mut last_mesh_id: u32 = u32::MAX;
+        let depth_range = DepthRange::from_projection_matrix(projection);
+        if let Some(state) = pass_state {
+            state.apply();
+        }
+        let mut current_state = pass_state;
+        // Scissor test is assumed off between queues; only touched when a command's clip changes
+        let mut current_scissor: Option<(i32, i32, i32, i32)> = None;
+        let mut batch: Vec<glm::Mat4> = Vec::new();
+        let mut commands = queue.iter().peekable();
+
+        while let Some(cmd) = commands.next() {
+            if let Some(frustum) = frustum {
+                if !cmd.is_visible(frustum) {
+                    self.stats.culled += 1;
+                    continue;
+                }
+            }
 
-        for cmd in queue {
             let material = match resources.get(cmd.material) {
                 Some(m) => m,
                 None => continue,
             };
-            let shader = match resources.get(material.shader) {
+            let shader = match resources.get(queue.resolve_shader(material)) {
                 Some(s) => s,
                 None => continue,
             };
@@ -69,17 +161,36 @@ impl Renderer {
                 shader.set_mat4("view", view);
                 shader.set_mat4("projection", projection);
 
-                // General Environmental Uniforms
-                shader.set_vec3("u_SkyColor", &globals.sky_color);
-                shader.set_f32("u_Ambient", globals.ambient);
+                // Scene-wide environment (only for shaders that declare them)
+                globals.upload(shader);
+
+                // Depth linearization (only for shaders that declare them)
+                if shader.has_uniform("uNear") {
+                    shader.set_f32("uNear", depth_range.near);
+                }
+                if shader.has_uniform("uFar") {
+                    shader.set_f32("uFar", depth_range.far);
+                }
+                if shader.has_uniform("uIsOrtho") {
+                    shader.set_int("uIsOrtho", depth_range.is_orthographic as i32);
+                }
 
                 last_shader_id = shader.id;
+                self.stats.shader_switches += 1;
                 // Force material rebind since shader changed
                 last_material_id = u32::MAX;
             }
 
             // Only rebind textures if material changed
             if cmd.material.id != last_material_id {
+                if let Some(pass) = pass_state {
+                    let wanted = material.render_state.unwrap_or(pass);
+                    if current_state != Some(wanted) {
+                        wanted.apply();
+                        current_state = Some(wanted);
+                    }
+                }
+
                 for tex_slot in &material.textures {
                     shader.set_int(tex_slot.uniform_name, tex_slot.slot as i32);
 
@@ -102,6 +213,7 @@ impl Renderer {
                     }
                 }
                 last_material_id = cmd.material.id;
+                self.stats.material_switches += 1;
             }
 
             // Standard per-draw uniforms
@@ -116,6 +228,11 @@ impl Renderer {
                     UniformValue::Vec3(v) => shader.set_vec3(uniform.name, v),
                     UniformValue::Vec4(v) => shader.set_vec4(uniform.name, v),
                     UniformValue::Mat4(v) => shader.set_mat4(uniform.name, v),
+                    UniformValue::UInt(v) => shader.set_uint(uniform.name, *v),
+                    UniformValue::IVec3(v) => shader.set_ivec3(uniform.name, v),
+                    UniformValue::UVec3(v) => shader.set_uvec3(uniform.name, v),
+                    UniformValue::Vec3Array(v) => shader.set_vec3_array(uniform.name, v),
+                    UniformValue::Mat4Array(v) => shader.set_mat4_array(uniform.name, v),
                 }
             }
 
@@ -142,10 +259,67 @@ impl Renderer {
                 }
             }
 
+            if cmd.scissor != current_scissor {
+                set_scissor(cmd.scissor);
+                current_scissor = cmd.scissor;
+            }
+
             // Draw
             if let Some(mesh) = resources.get(cmd.mesh) {
-                mesh.draw();
+                let instances = if cmd.instances.is_none() && shader.has_uniform(INSTANCE_MODELS_UNIFORM) {
+                    batch.clear();
+                    batch.push(cmd.transform);
+                    while batch.len() < MAX_BATCH_INSTANCES {
+                        let Some(next) = commands.next_if(|next| cmd.can_batch_with(next)) else { break };
+                        if frustum.is_some_and(|frustum| !next.is_visible(frustum)) {
+                            self.stats.culled += 1;
+                            continue;
+                        }
+                        batch.push(next.transform);
+                    }
+                    shader.set_mat4_array(INSTANCE_MODELS_UNIFORM, &batch);
+                    self.stats.batched += batch.len() as u32 - 1;
+                    Some(batch.len() as i32)
+                } else {
+                    cmd.instances
+                };
+                if !mesh.is_empty() {
+                    let count = instances.unwrap_or(1).max(0) as u64;
+                    self.stats.draw_calls += 1;
+                    self.stats.vertices += mesh.element_count() as u64 * count;
+                }
+                // Only rebind the VAO if the mesh changed
+                if cmd.mesh.id != last_mesh_id {
+                    mesh.bind();
+                    last_mesh_id = cmd.mesh.id;
+                }
+                match instances {
+                    Some(count) => mesh.draw_instanced_no_bind(count),
+                    None => mesh.draw_no_bind(),
+                }
+            }
+        }
+
+        if current_scissor.is_some() {
+            set_scissor(None);
+        }
+        if let Some(pass) = pass_state {
+            if current_state != pass_state {
+                pass.apply();
+            }
+        }
+    }
+}
+
+/// Enables the scissor test with the given `(x, y, width, height)` rectangle, or disables it.
+fn set_scissor(scissor: Option<(i32, i32, i32, i32)>) {
+    unsafe {
+        match scissor {
+            Some((x, y, width, height)) => {
+                gl::Enable(gl::SCISSOR_TEST);
+                gl::Scissor(x, y, width.max(0), height.max(0));
             }
+            None => gl::Disable(gl::SCISSOR_TEST),
         }
     }
 }
@@ -0,0 +1,4 @@
+pub mod render_queue_tests;
+pub mod oit_tests;
+pub mod render_context_tests;
+pub mod render_environment_tests;
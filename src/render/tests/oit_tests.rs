@@ -0,0 +1,71 @@
+use nalgebra_glm as glm;
+use crate::render::oit::{
+    oit_weight, OitAccumulator, OIT_EPSILON, WEIGHT_FAR, WEIGHT_NEAR, WEIGHT_RANGE, WEIGHT_SCALE,
+};
+
+fn approx_eq(a: glm::Vec3, b: glm::Vec3) -> bool {
+    (a - b).abs().max() < 1e-4
+}
+
+#[test]
+fn test_composite_two_overlapping_fragments() {
+    let background = glm::vec3(0.0, 1.0, 0.0);
+    let mut pixel = OitAccumulator::new();
+    pixel.add(glm::vec4(1.0, 0.0, 0.0, 0.5), 1.0);
+    pixel.add(glm::vec4(0.0, 0.0, 1.0, 0.5), 1.0);
+
+    // Equal weights: average color (0.5, 0, 0.5) covering 1 - 0.5 * 0.5 = 75% of the background
+    assert!((pixel.revealage - 0.25).abs() < 1e-6);
+    let result = pixel.composite(background);
+    assert!(approx_eq(result, glm::vec3(0.375, 0.25, 0.375)));
+
+    // Order independent
+    let mut reversed = OitAccumulator::new();
+    reversed.add(glm::vec4(0.0, 0.0, 1.0, 0.5), 1.0);
+    reversed.add(glm::vec4(1.0, 0.0, 0.0, 0.5), 1.0);
+    assert!(approx_eq(reversed.composite(background), result));
+}
+
+#[test]
+fn test_empty_pixel_shows_background() {
+    let background = glm::vec3(0.2, 0.3, 0.4);
+    assert!(approx_eq(OitAccumulator::new().composite(background), background));
+}
+
+#[test]
+fn test_weight_favors_nearer_fragments() {
+    assert!(oit_weight(2.0, 0.5) > oit_weight(50.0, 0.5));
+    assert!(oit_weight(10.0, 0.5) > oit_weight(10.0, 0.01));
+}
+
+/// Source of a built-in shader with whitespace runs collapsed to single spaces.
+fn shader_source(name: &str) -> String {
+    let path = format!("{}/assets/shaders/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(path).unwrap().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[test]
+fn test_accum_shader_matches_cpu_reference() {
+    let source = shader_source("oit_accum_fragment.glsl");
+
+    // Same weight formula and constants as `oit_weight`
+    let weight = format!(
+        "return alpha * clamp({WEIGHT_SCALE:?} / ({OIT_EPSILON:e} + pow(z / {WEIGHT_NEAR:?}, 2.0) + pow(z / {WEIGHT_FAR:?}, 6.0)), {:e}, {:e});",
+        WEIGHT_RANGE.0, WEIGHT_RANGE.1,
+    );
+    assert!(source.contains(&weight), "oitWeight differs from oit_weight: expected `{weight}`");
+
+    // Same outputs as `OitAccumulator::add` (revealage is multiplied in by the blend function)
+    assert!(source.contains("accum = vec4(color * alpha, alpha) * w;"));
+    assert!(source.contains("revealage = alpha;"));
+}
+
+#[test]
+fn test_composite_shader_matches_cpu_reference() {
+    let source = shader_source("oit_composite_fragment.glsl");
+
+    // Same as `OitAccumulator::composite`, blended with (SRC_ALPHA, ONE_MINUS_SRC_ALPHA)
+    assert!(source.contains("if (revealage >= 1.0) { discard; }"));
+    assert!(source.contains(&format!("vec3 averageColor = accum.rgb / max(accum.a, {OIT_EPSILON:e});")));
+    assert!(source.contains("fragColor = vec4(averageColor, 1.0 - revealage);"));
+}
@@ -0,0 +1,71 @@
+use nalgebra_glm as glm;
+use crate::camera::{Camera, Projection};
+use crate::render::render_context::{DepthRange, RenderContext};
+use crate::render::render_environment::RenderEnvironment;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() <= 1e-3 * b.abs().max(1.0)
+}
+
+#[test]
+fn test_depth_range_from_perspective_camera() {
+    let mut camera = Camera::new(glm::vec3(0.0, 0.0, 0.0));
+    camera.set_projection(Projection::Perspective { fov: 70.0f32.to_radians(), near: 0.5, far: 250.0 });
+
+    let range = DepthRange::from_projection_matrix(&camera.projection_matrix(16.0 / 9.0));
+
+    assert!(!range.is_orthographic);
+    assert!(approx(range.near, 0.5));
+    assert!(approx(range.far, 250.0));
+}
+
+#[test]
+fn test_depth_range_from_orthographic_camera() {
+    let mut camera = Camera::new(glm::vec3(0.0, 0.0, 0.0));
+    camera.set_projection(Projection::Orthographic {
+        left: -10.0, right: 10.0, bottom: -10.0, top: 10.0, near: 1.0, far: 50.0,
+    });
+
+    let range = DepthRange::from_projection_matrix(&camera.projection_matrix(1.0));
+
+    assert!(range.is_orthographic);
+    assert!(approx(range.near, 1.0));
+    assert!(approx(range.far, 50.0));
+}
+
+#[test]
+fn test_linearize_endpoints() {
+    let range = DepthRange { near: 0.1, far: 100.0, is_orthographic: false };
+    assert!(approx(range.linearize(0.0), 0.1));
+    assert!(approx(range.linearize(1.0), 100.0));
+}
+
+#[test]
+fn test_default_environment_clears_to_sky_blue() {
+    let environment = RenderEnvironment::default();
+    assert_eq!(environment.clear_color(), glm::vec4(0.5, 0.7, 1.0, 1.0));
+}
+
+#[test]
+fn test_clear_color_follows_sky_color() {
+    let environment = RenderEnvironment { sky_color: glm::vec3(0.9, 0.4, 0.2), ..Default::default() };
+    assert_eq!(environment.clear_color(), glm::vec4(0.9, 0.4, 0.2, 1.0));
+}
+
+#[test]
+fn test_default_environment_has_unit_sun_and_no_fog() {
+    let environment = RenderEnvironment::default();
+    assert!(approx(glm::length(&environment.sun_direction), 1.0));
+    assert!(environment.sun_direction.y < 0.0);
+    assert_eq!(environment.fog_density, 0.0);
+}
+
+#[test]
+fn test_camera_position_from_view() {
+    let eye = glm::vec3(3.0, 4.0, 5.0);
+    let view = glm::look_at(&eye, &glm::vec3(0.0, 0.0, 0.0), &glm::vec3(0.0, 1.0, 0.0));
+    let ctx = RenderContext::new(view, glm::identity(), 800.0, 600.0, RenderEnvironment::default());
+
+    let pos = ctx.camera_position();
+    assert!(approx(pos.x, eye.x) && approx(pos.y, eye.y) && approx(pos.z, eye.z));
+}
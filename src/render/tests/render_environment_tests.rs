@@ -0,0 +1,48 @@
+use crate::render::render_environment::RenderEnvironment;
+
+const SHADERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/shaders");
+
+/// Returns `(type, name)` for every `uniform <type> u_Env...;` declaration in `source`.
+fn env_uniforms(source: &str) -> Vec<(String, String)> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            if words.next()? != "uniform" {
+                return None;
+            }
+            let ty = words.next()?;
+            let name = words.next()?.trim_end_matches(';');
+            name.starts_with("u_Env").then(|| (ty.to_string(), name.to_string()))
+        })
+        .collect()
+}
+
+fn read_shader(name: &str) -> String {
+    std::fs::read_to_string(format!("{SHADERS_DIR}/{name}")).unwrap()
+}
+
+#[test]
+fn test_builtin_shaders_only_declare_uploaded_env_uniforms() {
+    for entry in std::fs::read_dir(SHADERS_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let source = std::fs::read_to_string(&path).unwrap();
+        for (ty, name) in env_uniforms(&source) {
+            let uploaded = match ty.as_str() {
+                "vec3" => RenderEnvironment::VEC3_UNIFORMS.contains(&name.as_str()),
+                "float" => RenderEnvironment::FLOAT_UNIFORMS.contains(&name.as_str()),
+                _ => false,
+            };
+            assert!(uploaded, "{} declares `{ty} {name}`, which RenderEnvironment doesn't set", path.display());
+        }
+    }
+}
+
+#[test]
+fn test_oit_accum_lighting_uniforms_match_fragment() {
+    let mut opaque = env_uniforms(&read_shader("fragment.glsl"));
+    let mut transparent = env_uniforms(&read_shader("oit_accum_fragment.glsl"));
+    opaque.sort();
+    transparent.sort();
+    assert_eq!(opaque, transparent);
+}
@@ -0,0 +1,160 @@
+use crate::core::handle::Handle;
+use crate::graphics::material::Material;
+use crate::math::frustum::Frustum;
+use crate::render::render_command::{RenderCommand, UniformValue};
+use crate::render::render_queue::RenderQueue;
+use nalgebra_glm as glm;
+
+#[test]
+fn test_resolve_shader_without_override() {
+    let queue = RenderQueue::new();
+    let material = Material::new(Handle::new(3));
+
+    assert!(queue.resolve_shader(&material) == Handle::new(3));
+}
+
+#[test]
+fn test_shader_override_applies_to_all_commands() {
+    let mut queue = RenderQueue::new();
+    let materials = [
+        Material::new(Handle::new(1)),
+        Material::new(Handle::new(2)),
+        Material::new(Handle::new(3)),
+    ];
+    for i in 0..materials.len() {
+        queue.submit(RenderCommand::new(Handle::new(i as u32), Handle::new(i as u32), glm::identity()));
+    }
+
+    let depth_shader = Handle::new(42);
+    queue.set_shader_override(Some(depth_shader));
+
+    for cmd in &queue {
+        let material = &materials[cmd.material.id as usize];
+        assert!(queue.resolve_shader(material) == depth_shader);
+    }
+
+    queue.set_shader_override(None);
+    for cmd in &queue {
+        let material = &materials[cmd.material.id as usize];
+        assert!(queue.resolve_shader(material) == material.shader);
+    }
+}
+
+#[test]
+fn test_render_command_instances() {
+    let plain = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity());
+    assert_eq!(plain.instances, None);
+
+    let instanced = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity()).with_instances(500);
+    assert_eq!(instanced.instances, Some(500));
+}
+
+#[test]
+fn test_render_command_can_batch_with() {
+    let at = |x: f32| RenderCommand::new(Handle::new(1), Handle::new(2), glm::translation(&glm::vec3(x, 0.0, 0.0)));
+    assert!(at(0.0).can_batch_with(&at(5.0).with_bounds(glm::vec3(4.0, 0.0, 0.0), glm::vec3(6.0, 1.0, 1.0))));
+
+    let other_mesh = RenderCommand::new(Handle::new(3), Handle::new(2), glm::identity());
+    assert!(!at(0.0).can_batch_with(&other_mesh));
+    let other_material = RenderCommand::new(Handle::new(1), Handle::new(3), glm::identity());
+    assert!(!at(0.0).can_batch_with(&other_material));
+
+    let tinted = |v: f32| at(0.0).with_uniform("u_Tint", UniformValue::Float(v));
+    assert!(tinted(0.5).can_batch_with(&tinted(0.5)));
+    assert!(!tinted(0.5).can_batch_with(&tinted(1.0)));
+
+    assert!(!at(0.0).can_batch_with(&at(0.0).with_scissor(0, 0, 10, 10)));
+    assert!(!at(0.0).with_instances(4).can_batch_with(&at(0.0).with_instances(4)));
+}
+
+#[test]
+fn test_render_command_bounds_visibility() {
+    let view = glm::look_at(&glm::vec3(0.0, 0.0, 0.0), &glm::vec3(0.0, 0.0, -1.0), &glm::vec3(0.0, 1.0, 0.0));
+    let projection = glm::perspective(1.0, 45.0f32.to_radians(), 0.1, 100.0);
+    let frustum = Frustum::from_matrix(&(projection * view));
+
+    let unbounded = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity());
+    assert!(unbounded.is_visible(&frustum));
+
+    let ahead = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity())
+        .with_bounds(glm::vec3(-1.0, -1.0, -11.0), glm::vec3(1.0, 1.0, -9.0));
+    assert!(ahead.is_visible(&frustum));
+
+    let behind = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity())
+        .with_bounds(glm::vec3(-1.0, -1.0, 9.0), glm::vec3(1.0, 1.0, 11.0));
+    assert!(!behind.is_visible(&frustum));
+}
+
+#[test]
+fn test_sort_back_to_front() {
+    let mut queue = RenderQueue::new();
+    queue.submit(RenderCommand::new(Handle::new(0), Handle::new(0), glm::translation(&glm::vec3(0.0, 0.0, -2.0))));
+    queue.submit(RenderCommand::new(Handle::new(1), Handle::new(0), glm::translation(&glm::vec3(0.0, 0.0, -10.0))));
+    queue.submit(
+        RenderCommand::new(Handle::new(2), Handle::new(0), glm::identity())
+            .with_bounds(glm::vec3(4.0, 0.0, 0.0), glm::vec3(6.0, 0.0, 0.0)),
+    );
+
+    queue.sort_back_to_front(&glm::vec3(0.0, 0.0, 0.0));
+
+    let order: Vec<u32> = queue.iter().map(|cmd| cmd.mesh.id).collect();
+    assert_eq!(order, vec![1, 2, 0]);
+}
+
+#[test]
+fn test_queue_len_and_clear() {
+    let mut queue = RenderQueue::new();
+    assert!(queue.is_empty());
+
+    for i in 0..3 {
+        queue.submit(RenderCommand::new(Handle::new(i), Handle::new(0), glm::identity()));
+    }
+    assert_eq!(queue.len(), 3);
+
+    queue.clear();
+    assert_eq!(queue.len(), 0);
+}
+
+#[test]
+fn test_submit_simple_does_not_allocate_per_command_lists() {
+    let mut queue = RenderQueue::new();
+    queue.submit_simple(Handle::new(1), Handle::new(2), glm::identity());
+
+    let cmd = queue.iter().next().unwrap();
+    assert!(cmd.mesh == Handle::new(1));
+    assert_eq!(cmd.uniforms.capacity(), 0);
+    assert_eq!(cmd.textures.capacity(), 0);
+}
+
+#[test]
+fn test_clear_keeps_queue_capacity() {
+    let mut queue = RenderQueue::new();
+    for i in 0..100 {
+        queue.submit_simple(Handle::new(i), Handle::new(0), glm::identity());
+    }
+    let capacity = queue.capacity();
+    queue.clear();
+    assert_eq!(queue.capacity(), capacity);
+}
+
+#[test]
+fn test_sort_by_material_then_mesh() {
+    let mut queue = RenderQueue::new();
+    for (mesh, material) in [(5, 2), (3, 1), (1, 2), (4, 1), (1, 2)] {
+        queue.submit(RenderCommand::new(Handle::new(mesh), Handle::new(material), glm::identity()));
+    }
+
+    queue.sort_by_material_then_mesh();
+
+    let order: Vec<(u32, u32)> = queue.iter().map(|cmd| (cmd.material.id, cmd.mesh.id)).collect();
+    assert_eq!(order, vec![(1, 3), (1, 4), (2, 1), (2, 1), (2, 5)]);
+}
+
+#[test]
+fn test_render_command_scissor() {
+    let cmd = RenderCommand::new(Handle::new(0), Handle::new(0), glm::identity());
+    assert_eq!(cmd.scissor, None);
+
+    let clipped = cmd.with_scissor(10, 20, 300, 150);
+    assert_eq!(clipped.scissor, Some((10, 20, 300, 150)));
+}
@@ -1,13 +1,19 @@
 use std::marker::PhantomData;
 use std::path::PathBuf;
 
-/// A resource type that can be loaded from a file path.
+/// A resource type that can be loaded from a file path or from raw bytes
+/// (archives, network, `include_bytes!`).
 pub trait Asset: Sized + 'static {
-    /// Error type returned when loading fails.
-    type Error;
-
-    /// Loads the asset from the given file path.
-    fn load(path: PathBuf) -> Result<Self, Self::Error>;
-}
+    /// Error type returned when loading fails; must be able to carry file read errors.
+    type Error: From<std::io::Error>;
 
+    /// Decodes the asset from the raw contents of its file.
+    fn load_from_bytes(bytes: &[u8]) -> Result<Self, Self::Error>;
 
+    /// Loads the asset from the given file path. Defaults to reading the whole file and
+    /// calling [`Self::load_from_bytes`]; override for formats that need the path itself.
+    fn load(path: PathBuf) -> Result<Self, Self::Error> {
+        let bytes = std::fs::read(path)?;
+        Self::load_from_bytes(&bytes)
+    }
+}
@@ -1,2 +1,5 @@
 pub mod resource_manager;
-pub mod asset;
\ No newline at end of file
+pub mod asset;
+
+#[cfg(test)]
+mod tests;
@@ -1,13 +1,76 @@
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use crate::core::handle::Handle;
+use crate::files::FileError;
 use crate::files::file_manager::FileManager;
 use crate::files::path::LogicalPath;
 use crate::resource::asset::{Asset};
 
+/// One storage slot. `generation` is bumped every time the slot is emptied, so handles
+/// to the previous occupant no longer match once the slot is reused.
+struct Slot {
+    generation: u32,
+    asset: Option<Box<dyn Any>>,
+}
+
+#[derive(Default)]
 struct AssetStorage {
-    next_id: u32,
-    assets: HashMap<u32, Box<dyn Any>>,
+    slots: Vec<Slot>,
+    /// Ids of empty slots, reused before growing `slots`.
+    free: Vec<u32>,
+}
+
+impl AssetStorage {
+    fn insert(&mut self, asset: Box<dyn Any>) -> (u32, u32) {
+        if let Some(id) = self.free.pop() {
+            let slot = &mut self.slots[id as usize];
+            slot.asset = Some(asset);
+            return (id, slot.generation);
+        }
+        self.slots.push(Slot { generation: 0, asset: Some(asset) });
+        ((self.slots.len() - 1) as u32, 0)
+    }
+
+    /// Returns the slot `handle` refers to, if it is still that handle's generation.
+    fn live_slot<T>(&self, handle: Handle<T>) -> Option<&Slot> {
+        self.slots.get(handle.id as usize).filter(|slot| slot.generation == handle.generation)
+    }
+
+    fn remove(&mut self, id: u32, generation: u32) -> Option<Box<dyn Any>> {
+        let slot = self.slots.get_mut(id as usize).filter(|slot| slot.generation == generation)?;
+        let asset = slot.asset.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id);
+        Some(asset)
+    }
+}
+
+/// Error returned by [`ResourceManager::load`]: either the file couldn't be resolved,
+/// or the asset's own loader failed.
+#[derive(Debug)]
+pub enum LoadError<E> {
+    /// The logical path didn't resolve to a file.
+    File(FileError),
+    /// [`Asset::load`] failed.
+    Asset(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for LoadError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::File(e) => write!(f, "Failed to resolve asset file: {e}"),
+            LoadError::Asset(e) => write!(f, "Failed to load asset: {e}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for LoadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::File(e) => Some(e),
+            LoadError::Asset(e) => Some(e),
+        }
+    }
 }
 
 /// Type-erased storage for all game resources, keyed by [`Handle`].
@@ -26,62 +89,88 @@ impl<P: LogicalPath> ResourceManager<P> {
     }
 
     /// Loads an asset from disk via the [`Asset`] trait and returns a handle to it.
+    /// A missing file is a recoverable [`LoadError::File`], not a panic.
     pub fn load<A: Asset>(
         &mut self,
         path: P,
         file: &str,
-    ) -> Result<Handle<A>, A::Error> {
-        let full_path = self.fs.resolve(path, file)
-            .map_err(|_| panic!("File resolution failed"))?;
-
-        let asset = A::load(full_path)?;
-
-        let type_id = TypeId::of::<A>();
-        let storage = self.storages
-            .entry(type_id)
-            .or_insert_with(|| AssetStorage {
-                next_id: 0,
-                assets: HashMap::new(),
-            });
+    ) -> Result<Handle<A>, LoadError<A::Error>> {
+        let full_path = self.fs.resolve(path, file).map_err(LoadError::File)?;
 
-        let id = storage.next_id;
-        storage.next_id += 1;
+        let asset = A::load(full_path).map_err(LoadError::Asset)?;
 
-        storage.assets.insert(id, Box::new(asset));
+        Ok(self.insert(asset))
+    }
 
-        Ok(Handle::new(id))
+    /// Decodes an asset from in-memory bytes via [`Asset::load_from_bytes`] and returns a
+    /// handle to it, e.g. for embedded or archived data that never touches the file system.
+    pub fn load_bytes<A: Asset>(&mut self, bytes: &[u8]) -> Result<Handle<A>, A::Error> {
+        let asset = A::load_from_bytes(bytes)?;
+        Ok(self.insert(asset))
     }
 
     /// Stores a value directly (no file loading) and returns a handle to it.
+    /// Slots freed by [`Self::remove`] are reused under a new generation, so stale handles
+    /// never alias the new resource.
     pub fn insert<T: 'static>(&mut self, value: T) -> Handle<T> {
-        let type_id = TypeId::of::<T>();
-        let storage = self.storages
-            .entry(type_id)
-            .or_insert_with(|| AssetStorage {
-                next_id: 0,
-                assets: HashMap::new(),
-            });
-
-        let id = storage.next_id;
-        storage.next_id += 1;
-        storage.assets.insert(id, Box::new(value));
-
-        Handle::new(id)
+        let storage = self.storages.entry(TypeId::of::<T>()).or_default();
+        let (id, generation) = storage.insert(Box::new(value));
+        Handle::with_generation(id, generation)
     }
 
-    /// Retrieves a reference to the resource behind `handle`, or `None` if missing.
+    /// Retrieves a reference to the resource behind `handle`, or `None` if it was removed
+    /// (see [`Self::is_stale`]) or never existed.
     pub fn get<T: 'static>(&self, handle: Handle<T>) -> Option<&T> {
         let storage = self.storages.get(&TypeId::of::<T>())?;
-        storage.assets
-            .get(&handle.id)?
+        storage.live_slot(handle)?
+            .asset.as_ref()?
             .downcast_ref::<T>()
     }
 
-    /// Removes and returns the resource behind `handle`, or `None` if missing.
+    /// Retrieves a mutable reference to the resource behind `handle`, for editing it in place
+    /// (e.g. re-uploading a `GpuMesh`) without changing its handle.
+    pub fn get_mut<T: 'static>(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        let storage = self.storages.get_mut(&TypeId::of::<T>())?;
+        storage.slots
+            .get_mut(handle.id as usize)
+            .filter(|slot| slot.generation == handle.generation)?
+            .asset.as_mut()?
+            .downcast_mut::<T>()
+    }
+
+    /// Returns `true` if `handle` refers to a resource that has since been removed,
+    /// to tell use-after-remove apart from a handle that was never valid.
+    pub fn is_stale<T: 'static>(&self, handle: Handle<T>) -> bool {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .and_then(|storage| storage.slots.get(handle.id as usize))
+            .is_some_and(|slot| slot.generation != handle.generation || slot.asset.is_none())
+    }
+
+    /// Iterates over every stored resource of type `T` with its handle, in slot order.
+    pub fn iter<T: 'static>(&self) -> impl Iterator<Item = (Handle<T>, &T)> {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .into_iter()
+            .flat_map(|storage| storage.slots.iter().enumerate())
+            .filter_map(|(id, slot)| {
+                let asset = slot.asset.as_ref()?.downcast_ref::<T>()?;
+                Some((Handle::with_generation(id as u32, slot.generation), asset))
+            })
+    }
+
+    /// Returns how many resources of type `T` are stored.
+    pub fn count<T: 'static>(&self) -> usize {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .map_or(0, |storage| storage.slots.len() - storage.free.len())
+    }
+
+    /// Removes and returns the resource behind `handle`, or `None` if missing or stale.
     /// The returned value will be dropped by the caller, triggering GPU cleanup for types like `GpuMesh` or `Shader`.
     pub fn remove<T: 'static>(&mut self, handle: Handle<T>) -> Option<T> {
         let storage = self.storages.get_mut(&TypeId::of::<T>())?;
-        storage.assets.remove(&handle.id)?
+        storage.remove(handle.id, handle.generation)?
             .downcast::<T>().ok().map(|b| *b)
     }
 }
@@ -98,10 +187,12 @@ impl<P: LogicalPath> ResourceAccess for ResourceManager<P> {
     }
 }
 
-/// Mutable access to resources; extends [`ResourceAccess`] with insert and remove.
+/// Mutable access to resources; extends [`ResourceAccess`] with insert, in-place edits, and remove.
 pub trait ResourceStore: ResourceAccess {
     /// Stores a value and returns a handle to it.
     fn insert<T: 'static>(&mut self, value: T) -> Handle<T>;
+    /// Retrieves a mutable reference to the resource behind `handle`, or `None` if missing.
+    fn get_mut<T: 'static>(&mut self, handle: Handle<T>) -> Option<&mut T>;
     /// Removes and returns the resource behind `handle`, or `None` if missing.
     fn remove<T: 'static>(&mut self, handle: Handle<T>) -> Option<T>;
 }
@@ -110,6 +201,9 @@ impl<P: LogicalPath> ResourceStore for ResourceManager<P> {
     fn insert<T: 'static>(&mut self, value: T) -> Handle<T> {
         self.insert(value)
     }
+    fn get_mut<T: 'static>(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        self.get_mut(handle)
+    }
     fn remove<T: 'static>(&mut self, handle: Handle<T>) -> Option<T> {
         self.remove(handle)
     }
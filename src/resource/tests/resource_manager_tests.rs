@@ -0,0 +1,150 @@
+use std::path::PathBuf;
+use crate::files::FileError;
+use crate::files::file_manager::{FileManager, Mount};
+use crate::files::path::{DirPolicy, LogicalPath, ResourcePath};
+use crate::resource::asset::Asset;
+use crate::core::handle::Handle;
+use crate::resource::resource_manager::{LoadError, ResourceManager, ResourceStore};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Data;
+
+impl LogicalPath for Data {
+    fn resource_path(&self) -> ResourcePath {
+        ResourcePath {
+            mount: Mount::Game,
+            policy: DirPolicy::Optional,
+            relative_path: PathBuf::new(),
+        }
+    }
+}
+
+/// A text file that must not be empty.
+struct Note(String);
+
+#[derive(Debug, PartialEq)]
+enum NoteError {
+    Io,
+    Empty,
+    NotUtf8,
+}
+
+impl From<std::io::Error> for NoteError {
+    fn from(_: std::io::Error) -> Self {
+        NoteError::Io
+    }
+}
+
+impl Asset for Note {
+    type Error = NoteError;
+
+    fn load_from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let text = std::str::from_utf8(bytes).map_err(|_| NoteError::NotUtf8)?;
+        if text.is_empty() { Err(NoteError::Empty) } else { Ok(Note(text.to_string())) }
+    }
+}
+
+fn manager(name: &str) -> (PathBuf, ResourceManager<Data>) {
+    let root = std::env::temp_dir().join(format!("voxxel_rm_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    let fs = FileManager::with_roots(root.clone(), root.clone(), root.clone());
+    (root, ResourceManager::new(fs))
+}
+
+#[test]
+fn test_load_missing_file_returns_error() {
+    let (_, mut resources) = manager("missing");
+
+    let result = resources.load::<Note>(Data, "nope.txt");
+    assert!(matches!(result, Err(LoadError::File(FileError::NotFound))));
+}
+
+#[test]
+fn test_load_reports_asset_error() {
+    let (root, mut resources) = manager("asset_error");
+    std::fs::write(root.join("empty.txt"), b"").unwrap();
+
+    let result = resources.load::<Note>(Data, "empty.txt");
+    assert!(matches!(result, Err(LoadError::Asset(NoteError::Empty))));
+}
+
+#[test]
+fn test_load_stores_asset() {
+    let (root, mut resources) = manager("ok");
+    std::fs::write(root.join("hello.txt"), b"hello").unwrap();
+
+    let handle = resources.load::<Note>(Data, "hello.txt").ok().unwrap();
+    assert_eq!(resources.get(handle).map(|n| n.0.as_str()), Some("hello"));
+}
+
+#[test]
+fn test_iter_and_count_by_type() {
+    let (_, mut resources) = manager("iter");
+    let a = resources.insert(10u32);
+    let b = resources.insert(20u32);
+    resources.insert("other type");
+    resources.remove(a);
+    let c = resources.insert(30u32);
+
+    assert_eq!(resources.count::<u32>(), 2);
+    assert_eq!(resources.count::<&str>(), 1);
+    assert_eq!(resources.count::<f64>(), 0);
+
+    let mut values: Vec<u32> = resources.iter::<u32>().map(|(_, v)| *v).collect();
+    values.sort();
+    assert_eq!(values, vec![20, 30]);
+
+    for (handle, value) in resources.iter::<u32>() {
+        assert!(handle == b || handle == c);
+        assert_eq!(resources.get(handle), Some(value));
+    }
+}
+
+#[test]
+fn test_stale_handle_does_not_alias_reused_slot() {
+    let (_, mut resources) = manager("generations");
+    let old = resources.insert(1u32);
+    assert!(!resources.is_stale(old));
+
+    assert_eq!(resources.remove(old), Some(1));
+    assert!(resources.is_stale(old));
+
+    // The freed slot is reused under a new generation
+    let new = resources.insert(2u32);
+    assert_eq!(new.id, old.id);
+    assert!(new != old);
+    assert_eq!(resources.get(old), None);
+    assert_eq!(resources.get(new), Some(&2));
+    assert!(resources.is_stale(old));
+    assert_eq!(resources.remove(old), None);
+    assert_eq!(resources.count::<u32>(), 1);
+}
+
+#[test]
+fn test_get_mut_edits_in_place() {
+    let (_, mut resources) = manager("get_mut");
+    let handle = resources.insert(vec![1u8, 2]);
+
+    resources.get_mut(handle).unwrap().push(3);
+    assert_eq!(resources.get(handle), Some(&vec![1, 2, 3]));
+
+    // Through the trait, as engine code sees it
+    fn bump(store: &mut impl ResourceStore, handle: Handle<Vec<u8>>) {
+        store.get_mut(handle).unwrap()[0] = 9;
+    }
+    bump(&mut resources, handle);
+    assert_eq!(resources.get(handle), Some(&vec![9, 2, 3]));
+
+    resources.remove(handle);
+    assert!(resources.get_mut(handle).is_none());
+}
+
+#[test]
+fn test_load_bytes_without_file_system() {
+    let (_, mut resources) = manager("bytes");
+
+    let handle = resources.load_bytes::<Note>(b"embedded").ok().unwrap();
+    assert_eq!(resources.get(handle).map(|n| n.0.as_str()), Some("embedded"));
+    assert!(matches!(resources.load_bytes::<Note>(&[0xff, 0xfe]), Err(NoteError::NotUtf8)));
+}
@@ -0,0 +1,150 @@
+//! Derive macros for `voxxel_engine`, enabled through its `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitInt, Type};
+
+/// Implements `voxxel_engine::graphics::vertex::Vertex` for a `#[repr(C)]` struct with named
+/// fields, one vertex attribute per field. Offsets come from `offset_of!`, so they always match
+/// the struct's real layout.
+///
+/// Supported field types are `f32`, `u32`, `i32`, `u16`, `i16`, `u8`, `i8` and `[T; 1..=4]` of them.
+/// Each field can be annotated with `#[attr(location = N, normalized)]`:
+/// - `location` defaults to the field's index.
+/// - Float fields are passed as floats. Integer fields use the integer attribute path
+///   (`glVertexAttribIPointer`) unless marked `normalized`, which converts them to floats
+///   in `[0, 1]` (or `[-1, 1]` for signed types).
+#[proc_macro_derive(Vertex, attributes(attr))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !has_repr_c(input) {
+        return Err(Error::new_spanned(&input.ident, "#[derive(Vertex)] requires #[repr(C)]"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "#[derive(Vertex)] needs named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "#[derive(Vertex)] only supports structs")),
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "#[derive(Vertex)] doesn't support generic structs"));
+    }
+
+    let name = &input.ident;
+    let vertex = quote!(::voxxel_engine::graphics::vertex);
+    let mut attributes = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let ident = field.ident.as_ref().unwrap();
+        let (scalar, size) = component_type(&field.ty)?;
+        let options = FieldOptions::parse(field, index as u32)?;
+
+        let location = options.location;
+        let normalized = options.normalized;
+        let is_integer = scalar.is_integer && !normalized;
+        let gl_type = syn::Ident::new(scalar.gl_name, Span::call_site());
+        attributes.push(quote! {
+            #vertex::VertexAttribute {
+                location: #location,
+                size: #size,
+                gl_type: #vertex::#gl_type,
+                normalized: #normalized,
+                is_integer: #is_integer,
+                offset: ::core::mem::offset_of!(#name, #ident),
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #vertex::Vertex for #name {
+            fn layout() -> #vertex::VertexLayout {
+                const ATTRIBUTES: &[#vertex::VertexAttribute] = &[#(#attributes),*];
+                #vertex::VertexLayout {
+                    stride: ::core::mem::size_of::<Self>(),
+                    attributes: ATTRIBUTES,
+                }
+            }
+        }
+    })
+}
+
+fn has_repr_c(input: &DeriveInput) -> bool {
+    input.attrs.iter().filter(|a| a.path().is_ident("repr")).any(|a| {
+        let mut found = false;
+        let _ = a.parse_nested_meta(|meta| {
+            found |= meta.path.is_ident("C");
+            Ok(())
+        });
+        found
+    })
+}
+
+/// A scalar vertex component type and the name of its GL type constant in `graphics::vertex`.
+struct Scalar {
+    gl_name: &'static str,
+    is_integer: bool,
+}
+
+/// Returns the scalar type and component count (1-4) of a field type.
+fn component_type(ty: &Type) -> syn::Result<(Scalar, i32)> {
+    match ty {
+        Type::Array(array) => {
+            let (scalar, _) = component_type(&array.elem)?;
+            let len: i32 = match &array.len {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(n), .. }) => n.base10_parse()?,
+                len => return Err(Error::new_spanned(len, "array length must be a literal")),
+            };
+            if !(1..=4).contains(&len) {
+                return Err(Error::new_spanned(ty, "vertex attributes have 1 to 4 components"));
+            }
+            Ok((scalar, len))
+        }
+        Type::Path(path) => {
+            let name = path.path.get_ident().map(|i| i.to_string()).unwrap_or_default();
+            let (gl_name, is_integer) = match name.as_str() {
+                "f32" => ("FLOAT", false),
+                "u32" => ("UNSIGNED_INT", true),
+                "i32" => ("INT", true),
+                "u16" => ("UNSIGNED_SHORT", true),
+                "i16" => ("SHORT", true),
+                "u8" => ("UNSIGNED_BYTE", true),
+                "i8" => ("BYTE", true),
+                _ => return Err(Error::new_spanned(ty, "unsupported vertex attribute type")),
+            };
+            Ok((Scalar { gl_name, is_integer }, 1))
+        }
+        _ => Err(Error::new_spanned(ty, "unsupported vertex attribute type")),
+    }
+}
+
+/// Parsed `#[attr(...)]` options of one field.
+struct FieldOptions {
+    location: u32,
+    normalized: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &syn::Field, index: u32) -> syn::Result<Self> {
+        let mut options = Self { location: index, normalized: false };
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("attr")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("location") {
+                    options.location = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                    Ok(())
+                } else if meta.path.is_ident("normalized") {
+                    options.normalized = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `location = N` or `normalized`"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}